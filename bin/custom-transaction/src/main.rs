@@ -11,6 +11,7 @@ use codec::Encode;
 
 use node_template_runtime::app_tag_ext::{TagApp, AppTag};
 use node_template_runtime::{Runtime, Call, Address, AccountId, Signature, Hash};
+use node_template_runtime::deip_account::DeipAccountId;
 
 use pallet_deip_dao::{Call as DeipDaoCall, dao::{DaoId, InputAuthority}};
 
@@ -65,7 +66,7 @@ fn main() {
     
     let function = Call::DeipDao(DeipDaoCall::create(
         name,
-        InputAuthority { signatories: vec![account.clone()], threshold: 0 },
+        InputAuthority { signatories: vec![DeipAccountId::Native(account.clone())], threshold: 0 },
         None
     ));
     
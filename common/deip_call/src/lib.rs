@@ -148,6 +148,62 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            add_project_domain(project_id, domain) => CallObject {
+                module: "deip",
+                call: "add_project_domain",
+                args: &DeipProjectDomainCallArgs { project_id, domain },
+            }
+            .serialize(serializer),
+
+            remove_project_domain(project_id, domain) => CallObject {
+                module: "deip",
+                call: "remove_project_domain",
+                args: &DeipProjectDomainCallArgs { project_id, domain },
+            }
+            .serialize(serializer),
+
+            archive_project(project_id) => CallObject {
+                module: "deip",
+                call: "archive_project",
+                args: &DeipProjectIdCallArgs { project_id },
+            }
+            .serialize(serializer),
+
+            unarchive_project(project_id) => CallObject {
+                module: "deip",
+                call: "unarchive_project",
+                args: &DeipProjectIdCallArgs { project_id },
+            }
+            .serialize(serializer),
+
+            remove_project(project_id) => CallObject {
+                module: "deip",
+                call: "remove_project",
+                args: &DeipProjectIdCallArgs { project_id },
+            }
+            .serialize(serializer),
+
+            accept_project_handover(project_id) => CallObject {
+                module: "deip",
+                call: "accept_project_handover",
+                args: &DeipProjectIdCallArgs { project_id },
+            }
+            .serialize(serializer),
+
+            cancel_project_handover(project_id) => CallObject {
+                module: "deip",
+                call: "cancel_project_handover",
+                args: &DeipProjectIdCallArgs { project_id },
+            }
+            .serialize(serializer),
+
+            handover_project(project_id, to) => CallObject {
+                module: "deip",
+                call: "handover_project",
+                args: &DeipHandoverProjectCallArgs { project_id, to },
+            }
+            .serialize(serializer),
+
             create_project_content(
                 external_id,
                 project_external_id,
@@ -173,6 +229,50 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            amend_project_content(
+                external_id,
+                previous_version,
+                description,
+                content,
+                authors,
+                references,
+                reason,
+            ) => CallObject {
+                module: "deip",
+                call: "amend_project_content",
+                args: &DeipAmendProjectContentCallArgs {
+                    external_id,
+                    previous_version,
+                    description,
+                    content,
+                    authors,
+                    references,
+                    reason,
+                },
+            }
+            .serialize(serializer),
+
+            confirm_project_content_authorship(content_id) => CallObject {
+                module: "deip",
+                call: "confirm_project_content_authorship",
+                args: &DeipProjectContentIdCallArgs { content_id },
+            }
+            .serialize(serializer),
+
+            decline_project_content_authorship(content_id) => CallObject {
+                module: "deip",
+                call: "decline_project_content_authorship",
+                args: &DeipProjectContentIdCallArgs { content_id },
+            }
+            .serialize(serializer),
+
+            finalize_project_content(content_id) => CallObject {
+                module: "deip",
+                call: "finalize_project_content",
+                args: &DeipProjectContentIdCallArgs { content_id },
+            }
+            .serialize(serializer),
+
             create_project_nda(
                 external_id,
                 end_date,
@@ -266,6 +366,20 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            add_review_domain(review_id, domain) => CallObject {
+                module: "deip",
+                call: "add_review_domain",
+                args: &DeipReviewDomainCallArgs { review_id, domain },
+            }
+            .serialize(serializer),
+
+            remove_review_domain(review_id, domain) => CallObject {
+                module: "deip",
+                call: "remove_review_domain",
+                args: &DeipReviewDomainCallArgs { review_id, domain },
+            }
+            .serialize(serializer),
+
             add_domain(domain) => CallObject {
                 module: "deip",
                 call: "add_domain",
@@ -273,6 +387,27 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            update_domain(domain_id, metadata) => CallObject {
+                module: "deip",
+                call: "update_domain",
+                args: &DeipUpdateDomainCallArgs { domain_id, metadata },
+            }
+            .serialize(serializer),
+
+            deprecate_domain(domain_id) => CallObject {
+                module: "deip",
+                call: "deprecate_domain",
+                args: &DeipDomainIdCallArgs { domain_id },
+            }
+            .serialize(serializer),
+
+            merge_domain(domain_id, into) => CallObject {
+                module: "deip",
+                call: "merge_domain",
+                args: &DeipMergeDomainCallArgs { domain_id, into },
+            }
+            .serialize(serializer),
+
             create_contract_agreement(id, creator, parties, hash, activation_time, expiration_time, terms) => {
                 CallObject {
                     module: "deip",
@@ -318,12 +453,16 @@ impl WrappedCall<Call> {
         use pallet_deip_proposal::Call::*;
 
         match deip_proposal_call {
-            propose(batch, external_id) => CallObject {
+            propose(batch, external_id, approval_policy, execute_after, ttl, execution_mode) => CallObject {
                 module: "deip_proposal",
                 call: "propose",
                 args: &DeipProposalProposeCallArgs {
                     batch: &wrap_input_batch(batch),
                     external_id,
+                    approval_policy,
+                    execute_after,
+                    ttl,
+                    execution_mode,
                 },
             }
             .serialize(serializer),
@@ -345,6 +484,35 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            cancel(proposal_id) => CallObject {
+                module: "deip_proposal",
+                call: "cancel",
+                args: &DeipProposalCancelCallArgs { proposal_id },
+            }
+            .serialize(serializer),
+
+            amend(proposal_id, batch, approval_policy) => CallObject {
+                module: "deip_proposal",
+                call: "amend",
+                args: &DeipProposalAmendCallArgs {
+                    proposal_id,
+                    batch: &wrap_input_batch(batch),
+                    approval_policy,
+                },
+            }
+            .serialize(serializer),
+
+            decide_as_dao(proposal_id, dao, decision) => CallObject {
+                module: "deip_proposal",
+                call: "decide_as_dao",
+                args: &DeipProposalDecideAsDaoCallArgs {
+                    proposal_id,
+                    dao,
+                    decision,
+                },
+            }
+            .serialize(serializer),
+
             __Ignore(..) => unreachable!(),
         }
     }
@@ -384,6 +552,34 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            set_metadata_record(record) => CallObject {
+                module: "deip_dao",
+                call: "set_metadata_record",
+                args: &DeipDaoSetMetadataRecordCallArgs { record },
+            }
+            .serialize(serializer),
+
+            set_handle(handle) => CallObject {
+                module: "deip_dao",
+                call: "set_handle",
+                args: &DeipDaoHandleCallArgs { handle },
+            }
+            .serialize(serializer),
+
+            reserve_handle(handle) => CallObject {
+                module: "deip_dao",
+                call: "reserve_handle",
+                args: &DeipDaoHandleCallArgs { handle },
+            }
+            .serialize(serializer),
+
+            release_handle(handle) => CallObject {
+                module: "deip_dao",
+                call: "release_handle",
+                args: &DeipDaoHandleCallArgs { handle },
+            }
+            .serialize(serializer),
+
             on_behalf(name, call) => CallObject {
                 module: "deip_dao",
                 call: "on_behalf",
@@ -394,6 +590,125 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            set_role(role_id, filters) => CallObject {
+                module: "deip_dao",
+                call: "set_role",
+                args: &DeipDaoSetRoleCallArgs { role_id, filters },
+            }
+            .serialize(serializer),
+
+            remove_role(role_id) => CallObject {
+                module: "deip_dao",
+                call: "remove_role",
+                args: &DeipDaoRemoveRoleCallArgs { role_id },
+            }
+            .serialize(serializer),
+
+            assign_role(role_id, member) => CallObject {
+                module: "deip_dao",
+                call: "assign_role",
+                args: &DeipDaoRoleMemberCallArgs { role_id, member },
+            }
+            .serialize(serializer),
+
+            revoke_role(role_id, member) => CallObject {
+                module: "deip_dao",
+                call: "revoke_role",
+                args: &DeipDaoRoleMemberCallArgs { role_id, member },
+            }
+            .serialize(serializer),
+
+            set_budget(budget_id, spender, asset, limit, period) => CallObject {
+                module: "deip_dao",
+                call: "set_budget",
+                args: &DeipDaoSetBudgetCallArgs {
+                    budget_id,
+                    spender,
+                    asset,
+                    limit,
+                    period,
+                },
+            }
+            .serialize(serializer),
+
+            remove_budget(budget_id) => CallObject {
+                module: "deip_dao",
+                call: "remove_budget",
+                args: &DeipDaoRemoveBudgetCallArgs { budget_id },
+            }
+            .serialize(serializer),
+
+            set_governance(governance) => CallObject {
+                module: "deip_dao",
+                call: "set_governance",
+                args: &DeipDaoSetGovernanceCallArgs { governance },
+            }
+            .serialize(serializer),
+
+            propose_motion(name, motion_id, call) => CallObject {
+                module: "deip_dao",
+                call: "propose_motion",
+                args: &DeipDaoProposeMotionCallArgs {
+                    name,
+                    motion_id,
+                    call: &WrappedCall::wrap(call.borrow()),
+                },
+            }
+            .serialize(serializer),
+
+            vote_motion(name, motion_id, aye) => CallObject {
+                module: "deip_dao",
+                call: "vote_motion",
+                args: &DeipDaoVoteMotionCallArgs {
+                    name,
+                    motion_id,
+                    aye,
+                },
+            }
+            .serialize(serializer),
+
+            close_motion(name, motion_id) => CallObject {
+                module: "deip_dao",
+                call: "close_motion",
+                args: &DeipDaoCloseMotionCallArgs { name, motion_id },
+            }
+            .serialize(serializer),
+
+            invite(member, expires_at) => CallObject {
+                module: "deip_dao",
+                call: "invite",
+                args: &DeipDaoInviteCallArgs { member, expires_at },
+            }
+            .serialize(serializer),
+
+            cancel_invitation(member) => CallObject {
+                module: "deip_dao",
+                call: "cancel_invitation",
+                args: &DeipDaoCancelInvitationCallArgs { member },
+            }
+            .serialize(serializer),
+
+            accept_invitation(name) => CallObject {
+                module: "deip_dao",
+                call: "accept_invitation",
+                args: &DeipDaoInvitationCallArgs { name },
+            }
+            .serialize(serializer),
+
+            decline_invitation(name) => CallObject {
+                module: "deip_dao",
+                call: "decline_invitation",
+                args: &DeipDaoInvitationCallArgs { name },
+            }
+            .serialize(serializer),
+
+            dissolve(beneficiaries, assets_witness) => CallObject {
+                module: "deip_dao",
+                call: "dissolve",
+                args: &DeipDaoDissolveCallArgs { beneficiaries, assets_witness },
+            }
+            .serialize(serializer),
+
             __Ignore(..) => unreachable!(),
         }
     }
@@ -630,6 +945,16 @@ struct DeipDaoUpdateCallArgs<A> {
     metadata: A,
 }
 
+#[derive(Serialize)]
+struct DeipDaoSetMetadataRecordCallArgs<A> {
+    record: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoHandleCallArgs<A> {
+    handle: A,
+}
+
 #[derive(Serialize)]
 struct DeipDaoCreateCallArgs<A, B, C> {
     name: A,
@@ -637,21 +962,122 @@ struct DeipDaoCreateCallArgs<A, B, C> {
     metadata: C,
 }
 
+#[derive(Serialize)]
+struct DeipDaoSetRoleCallArgs<A, B> {
+    role_id: A,
+    filters: B,
+}
+
+#[derive(Serialize)]
+struct DeipDaoRemoveRoleCallArgs<A> {
+    role_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoRoleMemberCallArgs<A, B> {
+    role_id: A,
+    member: B,
+}
+
+#[derive(Serialize)]
+struct DeipDaoSetBudgetCallArgs<A, B, C, D, E> {
+    budget_id: A,
+    spender: B,
+    asset: C,
+    limit: D,
+    period: E,
+}
+
+#[derive(Serialize)]
+struct DeipDaoRemoveBudgetCallArgs<A> {
+    budget_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoSetGovernanceCallArgs<A> {
+    governance: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoProposeMotionCallArgs<A, B, C> {
+    name: A,
+    motion_id: B,
+    call: C,
+}
+
+#[derive(Serialize)]
+struct DeipDaoVoteMotionCallArgs<A, B, C> {
+    name: A,
+    motion_id: B,
+    aye: C,
+}
+
+#[derive(Serialize)]
+struct DeipDaoCloseMotionCallArgs<A, B> {
+    name: A,
+    motion_id: B,
+}
+
+#[derive(Serialize)]
+struct DeipDaoInviteCallArgs<A, B> {
+    member: A,
+    expires_at: B,
+}
+
+#[derive(Serialize)]
+struct DeipDaoCancelInvitationCallArgs<A> {
+    member: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoInvitationCallArgs<A> {
+    name: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoDissolveCallArgs<A, B> {
+    beneficiaries: A,
+    assets_witness: B,
+}
+
 #[derive(Serialize)]
 struct DeipProposalDecideCallArgs<A, B> {
     proposal_id: A,
     decision: B,
 }
 
+#[derive(Serialize)]
+struct DeipProposalDecideAsDaoCallArgs<A, B, C> {
+    proposal_id: A,
+    dao: B,
+    decision: C,
+}
+
 #[derive(Serialize)]
 struct DeipProposalExpireCallArgs<A> {
     proposal_id: A,
 }
 
 #[derive(Serialize)]
-struct DeipProposalProposeCallArgs<A, B> {
+struct DeipProposalCancelCallArgs<A> {
+    proposal_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipProposalAmendCallArgs<A, B, C> {
+    proposal_id: A,
+    batch: B,
+    approval_policy: C,
+}
+
+#[derive(Serialize)]
+struct DeipProposalProposeCallArgs<A, B, C, D, E, F> {
     batch: A,
     external_id: B,
+    approval_policy: C,
+    execute_after: D,
+    ttl: E,
+    execution_mode: F,
 }
 
 #[derive(Serialize)]
@@ -659,6 +1085,23 @@ struct DeipAddDomainCallArgs<A> {
     domain: A,
 }
 
+#[derive(Serialize)]
+struct DeipUpdateDomainCallArgs<A, B> {
+    domain_id: A,
+    metadata: B,
+}
+
+#[derive(Serialize)]
+struct DeipDomainIdCallArgs<A> {
+    domain_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipMergeDomainCallArgs<A, B> {
+    domain_id: A,
+    into: B,
+}
+
 #[derive(Serialize)]
 struct DeipCreateContractAgreementCallArgs<A, B, C, D, E, F, G> {
     id: A,
@@ -699,6 +1142,12 @@ struct DeipUpvoteReviewCallArgs<A, B> {
     domain_id: B,
 }
 
+#[derive(Serialize)]
+struct DeipReviewDomainCallArgs<A, B> {
+    review_id: A,
+    domain: B,
+}
+
 #[derive(Serialize)]
 struct DeipRejectNdaAccessRequestCallArgs<A> {
     external_id: A,
@@ -741,6 +1190,22 @@ struct DeipCreateProjectContentCallArgs<A, B, C, D, E, F, G, H> {
     references: H,
 }
 
+#[derive(Serialize)]
+struct DeipProjectContentIdCallArgs<A> {
+    content_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipAmendProjectContentCallArgs<A, B, C, D, E, F, G> {
+    external_id: A,
+    previous_version: B,
+    description: C,
+    content: D,
+    authors: E,
+    references: F,
+    reason: G,
+}
+
 #[derive(Serialize)]
 struct DeipInvestCallArgs<A, B> {
     id: A,
@@ -754,6 +1219,23 @@ struct DeipUpdateProjectCallArgs<A, B, C> {
     is_private: C,
 }
 
+#[derive(Serialize)]
+struct DeipProjectIdCallArgs<A> {
+    project_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipProjectDomainCallArgs<A, B> {
+    project_id: A,
+    domain: B,
+}
+
+#[derive(Serialize)]
+struct DeipHandoverProjectCallArgs<A, B> {
+    project_id: A,
+    to: B,
+}
+
 #[derive(Serialize)]
 struct DeipFinishCrowdfundingCallArgs<A> {
     sale_id: A,
@@ -41,6 +41,17 @@ pub enum Error {
     NdaApiGetFailed = 32,
     NdaIdDecodeFailed = 33,
     NdaDecodeFailed = 34,
+    ProposalApiDryRunFailed = 35,
+    ProposalBatchDecodeFailed = 36,
+    ArchivedProposalDecodeFailed = 37,
+    DaoApiGetBudgetFailed = 38,
+    DaoApiGetBudgetListFailed = 39,
+    DaoApiGetInvitationListFailed = 40,
+    DaoApiGetMetadataHistoryFailed = 41,
+    DaoApiGetIdByHandleFailed = 42,
+    ProjectApiGetListByDomainTreeFailed = 43,
+    ReviewApiGetListByDomainTreeFailed = 44,
+    ProjectContentApiGetRevisionsFailed = 45,
 }
 
 impl Into<RpcErrorCode> for Error {
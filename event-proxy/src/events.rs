@@ -97,21 +97,43 @@ impl<T: DeipProposal + Deip + DeipDao + DeipAssets> Serialize for DomainEventDat
             // =============== DeipProposal:
             ProposalProposed(e) => e.serialize(serializer),
             ProposalApproved(e) => e.serialize(serializer),
+            ProposalRejected(e) => e.serialize(serializer),
             ProposalRevokedApproval(e) => e.serialize(serializer),
             ProposalResolved(e) => e.serialize(serializer),
+            ProposalScheduled(e) => e.serialize(serializer),
+            ProposalExecuted(e) => e.serialize(serializer),
             ProposalExpired(e) => e.serialize(serializer),
+            ProposalCancelled(e) => e.serialize(serializer),
+            ProposalAmended(e) => e.serialize(serializer),
+            ProposalDaoMemberDecided(e) => e.serialize(serializer),
             // =============== Deip:
             ProjectCreated(e) => e.serialize(serializer),
             ProjectRemoved(e) => e.serialize(serializer),
             ProjectUpdated(e) => e.serialize(serializer),
+            ProjectArchived(e) => e.serialize(serializer),
+            ProjectUnarchived(e) => e.serialize(serializer),
+            ProjectHandoverProposed(e) => e.serialize(serializer),
+            ProjectHandoverCancelled(e) => e.serialize(serializer),
+            ProjectHandedOver(e) => e.serialize(serializer),
+            ProjectDomainAdded(e) => e.serialize(serializer),
+            ProjectDomainRemoved(e) => e.serialize(serializer),
             ProjectContentCreated(e) => e.serialize(serializer),
+            ProjectContentAmended(e) => e.serialize(serializer),
+            ProjectContentAuthorConfirmed(e) => e.serialize(serializer),
+            ProjectContentAuthorDeclined(e) => e.serialize(serializer),
+            ProjectContentFinalized(e) => e.serialize(serializer),
             NdaCreated(e) => e.serialize(serializer),
             NdaAccessRequestCreated(e) => e.serialize(serializer),
             NdaAccessRequestFulfilled(e) => e.serialize(serializer),
             NdaAccessRequestRejected(e) => e.serialize(serializer),
             DomainAdded(e) => e.serialize(serializer),
+            DomainUpdated(e) => e.serialize(serializer),
+            DomainDeprecated(e) => e.serialize(serializer),
+            DomainMerged(e) => e.serialize(serializer),
             ReviewCreated(e) => e.serialize(serializer),
             ReviewUpvoted(e) => e.serialize(serializer),
+            ReviewDomainAdded(e) => e.serialize(serializer),
+            ReviewDomainRemoved(e) => e.serialize(serializer),
             SimpleCrowdfundingCreated(e) => e.serialize(serializer),
             SimpleCrowdfundingActivated(e) => e.serialize(serializer),
             SimpleCrowdfundingFinished(e) => e.serialize(serializer),
@@ -125,6 +147,26 @@ impl<T: DeipProposal + Deip + DeipDao + DeipAssets> Serialize for DomainEventDat
             DaoCreate(e) => e.serialize(serializer),
             DaoAlterAuthority(e) => e.serialize(serializer),
             DaoMetadataUpdated(e) => e.serialize(serializer),
+            DaoRoleSet(e) => e.serialize(serializer),
+            DaoRoleRemoved(e) => e.serialize(serializer),
+            DaoRoleAssigned(e) => e.serialize(serializer),
+            DaoRoleRevoked(e) => e.serialize(serializer),
+            DaoBudgetSet(e) => e.serialize(serializer),
+            DaoBudgetRemoved(e) => e.serialize(serializer),
+            DaoBudgetExhausted(e) => e.serialize(serializer),
+            DaoGovernanceSet(e) => e.serialize(serializer),
+            DaoMotionProposed(e) => e.serialize(serializer),
+            DaoMotionVoted(e) => e.serialize(serializer),
+            DaoMotionExecuted(e) => e.serialize(serializer),
+            DaoMotionRejected(e) => e.serialize(serializer),
+            DaoMemberInvited(e) => e.serialize(serializer),
+            DaoInvitationCancelled(e) => e.serialize(serializer),
+            DaoInvitationAccepted(e) => e.serialize(serializer),
+            DaoInvitationDeclined(e) => e.serialize(serializer),
+            DaoDissolved(e) => e.serialize(serializer),
+            DaoHandleSet(e) => e.serialize(serializer),
+            DaoHandleReserved(e) => e.serialize(serializer),
+            DaoHandleReleased(e) => e.serialize(serializer),
             // =============== DeipAssets:
             AssetClassCreated(e) => e.serialize(serializer),
             AssetIssued(e) => e.serialize(serializer),
@@ -152,21 +194,43 @@ pub enum DomainEventData<T: DeipProposal + Deip + DeipDao + DeipAssets> {
     // DeipProposal:
     ProposalProposed(deip_proposal::ProposedEvent<T>),
     ProposalApproved(deip_proposal::ApprovedEvent<T>),
+    ProposalRejected(deip_proposal::RejectedEvent<T>),
     ProposalRevokedApproval(deip_proposal::RevokedApprovalEvent<T>),
     ProposalResolved(deip_proposal::ResolvedEvent<T>),
+    ProposalScheduled(deip_proposal::ScheduledEvent<T>),
+    ProposalExecuted(deip_proposal::ExecutedEvent<T>),
     ProposalExpired(deip_proposal::ExpiredEvent<T>),
+    ProposalCancelled(deip_proposal::CancelledEvent<T>),
+    ProposalAmended(deip_proposal::AmendedEvent<T>),
+    ProposalDaoMemberDecided(deip_proposal::DaoMemberDecidedEvent<T>),
     // Deip:
     ProjectCreated(deip::ProjectCreatedEvent<T>),
     ProjectRemoved(deip::ProjectRemovedEvent<T>),
     ProjectUpdated(deip::ProjectUpdatedEvent<T>),
+    ProjectArchived(deip::ProjectArchivedEvent<T>),
+    ProjectUnarchived(deip::ProjectUnarchivedEvent<T>),
+    ProjectHandoverProposed(deip::ProjectHandoverProposedEvent<T>),
+    ProjectHandoverCancelled(deip::ProjectHandoverCancelledEvent<T>),
+    ProjectHandedOver(deip::ProjectHandedOverEvent<T>),
+    ProjectDomainAdded(deip::ProjectDomainAddedEvent<T>),
+    ProjectDomainRemoved(deip::ProjectDomainRemovedEvent<T>),
     ProjectContentCreated(deip::ProjectContentCreatedEvent<T>),
+    ProjectContentAmended(deip::ProjectContentAmendedEvent<T>),
+    ProjectContentAuthorConfirmed(deip::ProjectContentAuthorConfirmedEvent<T>),
+    ProjectContentAuthorDeclined(deip::ProjectContentAuthorDeclinedEvent<T>),
+    ProjectContentFinalized(deip::ProjectContentFinalizedEvent<T>),
     NdaCreated(deip::NdaCreatedEvent<T>),
     NdaAccessRequestCreated(deip::NdaAccessRequestCreatedEvent<T>),
     NdaAccessRequestFulfilled(deip::NdaAccessRequestFulfilledEvent<T>),
     NdaAccessRequestRejected(deip::NdaAccessRequestRejectedEvent<T>),
     DomainAdded(deip::DomainAddedEvent<T>),
+    DomainUpdated(deip::DomainUpdatedEvent<T>),
+    DomainDeprecated(deip::DomainDeprecatedEvent<T>),
+    DomainMerged(deip::DomainMergedEvent<T>),
     ReviewCreated(deip::ReviewCreatedEvent<T>),
     ReviewUpvoted(deip::ReviewUpvotedEvent<T>),
+    ReviewDomainAdded(deip::ReviewDomainAddedEvent<T>),
+    ReviewDomainRemoved(deip::ReviewDomainRemovedEvent<T>),
     SimpleCrowdfundingCreated(deip::SimpleCrowdfundingCreatedEvent<T>),
     SimpleCrowdfundingActivated(deip::SimpleCrowdfundingActivatedEvent<T>),
     SimpleCrowdfundingFinished(deip::SimpleCrowdfundingFinishedEvent<T>),
@@ -180,6 +244,26 @@ pub enum DomainEventData<T: DeipProposal + Deip + DeipDao + DeipAssets> {
     DaoCreate(deip_dao::DaoCreateEvent<T>),
     DaoAlterAuthority(deip_dao::DaoAlterAuthorityEvent<T>),
     DaoMetadataUpdated(deip_dao::DaoMetadataUpdatedEvent<T>),
+    DaoRoleSet(deip_dao::DaoRoleSetEvent<T>),
+    DaoRoleRemoved(deip_dao::DaoRoleRemovedEvent<T>),
+    DaoRoleAssigned(deip_dao::DaoRoleAssignedEvent<T>),
+    DaoRoleRevoked(deip_dao::DaoRoleRevokedEvent<T>),
+    DaoBudgetSet(deip_dao::DaoBudgetSetEvent<T>),
+    DaoBudgetRemoved(deip_dao::DaoBudgetRemovedEvent<T>),
+    DaoBudgetExhausted(deip_dao::DaoBudgetExhaustedEvent<T>),
+    DaoGovernanceSet(deip_dao::DaoGovernanceSetEvent<T>),
+    DaoMotionProposed(deip_dao::DaoMotionProposedEvent<T>),
+    DaoMotionVoted(deip_dao::DaoMotionVotedEvent<T>),
+    DaoMotionExecuted(deip_dao::DaoMotionExecutedEvent<T>),
+    DaoMotionRejected(deip_dao::DaoMotionRejectedEvent<T>),
+    DaoMemberInvited(deip_dao::DaoMemberInvitedEvent<T>),
+    DaoInvitationCancelled(deip_dao::DaoInvitationCancelledEvent<T>),
+    DaoInvitationAccepted(deip_dao::DaoInvitationAcceptedEvent<T>),
+    DaoInvitationDeclined(deip_dao::DaoInvitationDeclinedEvent<T>),
+    DaoDissolved(deip_dao::DaoDissolvedEvent<T>),
+    DaoHandleSet(deip_dao::DaoHandleSetEvent<T>),
+    DaoHandleReserved(deip_dao::DaoHandleReservedEvent<T>),
+    DaoHandleReleased(deip_dao::DaoHandleReleasedEvent<T>),
     // DeipAssets:
     AssetClassCreated(deip_assets::CreatedEvent<T>),
     AssetIssued(deip_assets::IssuedEvent<T>),
@@ -227,6 +311,14 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipDao + DeipAssets + Debug
             data: decode_event_data(raw).map(ProposalApproved)?,
             meta,
         },
+        (
+            deip_proposal::RejectedEvent::<T>::MODULE,
+            deip_proposal::RejectedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "proposal_rejected".to_string(),
+            data: decode_event_data(raw).map(ProposalRejected)?,
+            meta,
+        },
         (
             deip_proposal::RevokedApprovalEvent::<T>::MODULE,
             deip_proposal::RevokedApprovalEvent::<T>::EVENT
@@ -243,6 +335,22 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipDao + DeipAssets + Debug
             data: decode_event_data(raw).map(ProposalResolved)?,
             meta,
         },
+        (
+            deip_proposal::ScheduledEvent::<T>::MODULE,
+            deip_proposal::ScheduledEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "proposal_scheduled".to_string(),
+            data: decode_event_data(raw).map(ProposalScheduled)?,
+            meta,
+        },
+        (
+            deip_proposal::ExecutedEvent::<T>::MODULE,
+            deip_proposal::ExecutedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "proposal_executed".to_string(),
+            data: decode_event_data(raw).map(ProposalExecuted)?,
+            meta,
+        },
         (
             deip_proposal::ExpiredEvent::<T>::MODULE,
             deip_proposal::ExpiredEvent::<T>::EVENT
@@ -251,6 +359,30 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipDao + DeipAssets + Debug
             data: decode_event_data(raw).map(ProposalExpired)?,
             meta,
         },
+        (
+            deip_proposal::CancelledEvent::<T>::MODULE,
+            deip_proposal::CancelledEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "proposal_cancelled".to_string(),
+            data: decode_event_data(raw).map(ProposalCancelled)?,
+            meta,
+        },
+        (
+            deip_proposal::AmendedEvent::<T>::MODULE,
+            deip_proposal::AmendedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "proposal_amended".to_string(),
+            data: decode_event_data(raw).map(ProposalAmended)?,
+            meta,
+        },
+        (
+            deip_proposal::DaoMemberDecidedEvent::<T>::MODULE,
+            deip_proposal::DaoMemberDecidedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "proposal_daoMemberDecided".to_string(),
+            data: decode_event_data(raw).map(ProposalDaoMemberDecided)?,
+            meta,
+        },
         // =========== Deip:
         (
             deip::ProjectCreatedEvent::<T>::MODULE,
@@ -276,6 +408,62 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipDao + DeipAssets + Debug
             data: decode_event_data(raw).map(ProjectUpdated)?,
             meta,
         },
+        (
+            deip::ProjectArchivedEvent::<T>::MODULE,
+            deip::ProjectArchivedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_archived".to_string(),
+            data: decode_event_data(raw).map(ProjectArchived)?,
+            meta,
+        },
+        (
+            deip::ProjectUnarchivedEvent::<T>::MODULE,
+            deip::ProjectUnarchivedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_unarchived".to_string(),
+            data: decode_event_data(raw).map(ProjectUnarchived)?,
+            meta,
+        },
+        (
+            deip::ProjectHandoverProposedEvent::<T>::MODULE,
+            deip::ProjectHandoverProposedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_handoverProposed".to_string(),
+            data: decode_event_data(raw).map(ProjectHandoverProposed)?,
+            meta,
+        },
+        (
+            deip::ProjectHandoverCancelledEvent::<T>::MODULE,
+            deip::ProjectHandoverCancelledEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_handoverCancelled".to_string(),
+            data: decode_event_data(raw).map(ProjectHandoverCancelled)?,
+            meta,
+        },
+        (
+            deip::ProjectHandedOverEvent::<T>::MODULE,
+            deip::ProjectHandedOverEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_handedOver".to_string(),
+            data: decode_event_data(raw).map(ProjectHandedOver)?,
+            meta,
+        },
+        (
+            deip::ProjectDomainAddedEvent::<T>::MODULE,
+            deip::ProjectDomainAddedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_projectDomainAdded".to_string(),
+            data: decode_event_data(raw).map(ProjectDomainAdded)?,
+            meta,
+        },
+        (
+            deip::ProjectDomainRemovedEvent::<T>::MODULE,
+            deip::ProjectDomainRemovedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_projectDomainRemoved".to_string(),
+            data: decode_event_data(raw).map(ProjectDomainRemoved)?,
+            meta,
+        },
         (                               
             deip::ProjectContentCreatedEvent::<T>::MODULE,
             deip::ProjectContentCreatedEvent::<T>::EVENT
@@ -284,6 +472,38 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipDao + DeipAssets + Debug
             data: decode_event_data(raw).map(ProjectContentCreated)?,
             meta,
         },
+        (
+            deip::ProjectContentAmendedEvent::<T>::MODULE,
+            deip::ProjectContentAmendedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_contentAmended".to_string(),
+            data: decode_event_data(raw).map(ProjectContentAmended)?,
+            meta,
+        },
+        (
+            deip::ProjectContentAuthorConfirmedEvent::<T>::MODULE,
+            deip::ProjectContentAuthorConfirmedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_contentAuthorConfirmed".to_string(),
+            data: decode_event_data(raw).map(ProjectContentAuthorConfirmed)?,
+            meta,
+        },
+        (
+            deip::ProjectContentAuthorDeclinedEvent::<T>::MODULE,
+            deip::ProjectContentAuthorDeclinedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_contentAuthorDeclined".to_string(),
+            data: decode_event_data(raw).map(ProjectContentAuthorDeclined)?,
+            meta,
+        },
+        (
+            deip::ProjectContentFinalizedEvent::<T>::MODULE,
+            deip::ProjectContentFinalizedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_contentFinalized".to_string(),
+            data: decode_event_data(raw).map(ProjectContentFinalized)?,
+            meta,
+        },
         (                               
             deip::NdaCreatedEvent::<T>::MODULE,
             deip::NdaCreatedEvent::<T>::EVENT
@@ -324,6 +544,30 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipDao + DeipAssets + Debug
             data: decode_event_data(raw).map(DomainAdded)?,
             meta,
         },
+        (
+            deip::DomainUpdatedEvent::<T>::MODULE,
+            deip::DomainUpdatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_domainUpdated".to_string(),
+            data: decode_event_data(raw).map(DomainUpdated)?,
+            meta,
+        },
+        (
+            deip::DomainDeprecatedEvent::<T>::MODULE,
+            deip::DomainDeprecatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_domainDeprecated".to_string(),
+            data: decode_event_data(raw).map(DomainDeprecated)?,
+            meta,
+        },
+        (
+            deip::DomainMergedEvent::<T>::MODULE,
+            deip::DomainMergedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_domainMerged".to_string(),
+            data: decode_event_data(raw).map(DomainMerged)?,
+            meta,
+        },
         (                               
             deip::ReviewCreatedEvent::<T>::MODULE,
             deip::ReviewCreatedEvent::<T>::EVENT
@@ -340,6 +584,22 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipDao + DeipAssets + Debug
             data: decode_event_data(raw).map(ReviewUpvoted)?,
             meta,
         },
+        (
+            deip::ReviewDomainAddedEvent::<T>::MODULE,
+            deip::ReviewDomainAddedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_reviewDomainAdded".to_string(),
+            data: decode_event_data(raw).map(ReviewDomainAdded)?,
+            meta,
+        },
+        (
+            deip::ReviewDomainRemovedEvent::<T>::MODULE,
+            deip::ReviewDomainRemovedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_reviewDomainRemoved".to_string(),
+            data: decode_event_data(raw).map(ReviewDomainRemoved)?,
+            meta,
+        },
         (                               
             deip::SimpleCrowdfundingCreatedEvent::<T>::MODULE,
             deip::SimpleCrowdfundingCreatedEvent::<T>::EVENT
@@ -437,6 +697,166 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipDao + DeipAssets + Debug
             data: decode_event_data(raw).map(DaoMetadataUpdated)?,
             meta,
         },
+        (
+            deip_dao::DaoRoleSetEvent::<T>::MODULE,
+            deip_dao::DaoRoleSetEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_roleSet".to_string(),
+            data: decode_event_data(raw).map(DaoRoleSet)?,
+            meta,
+        },
+        (
+            deip_dao::DaoRoleRemovedEvent::<T>::MODULE,
+            deip_dao::DaoRoleRemovedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_roleRemoved".to_string(),
+            data: decode_event_data(raw).map(DaoRoleRemoved)?,
+            meta,
+        },
+        (
+            deip_dao::DaoRoleAssignedEvent::<T>::MODULE,
+            deip_dao::DaoRoleAssignedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_roleAssigned".to_string(),
+            data: decode_event_data(raw).map(DaoRoleAssigned)?,
+            meta,
+        },
+        (
+            deip_dao::DaoRoleRevokedEvent::<T>::MODULE,
+            deip_dao::DaoRoleRevokedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_roleRevoked".to_string(),
+            data: decode_event_data(raw).map(DaoRoleRevoked)?,
+            meta,
+        },
+        (
+            deip_dao::DaoBudgetSetEvent::<T>::MODULE,
+            deip_dao::DaoBudgetSetEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_budgetSet".to_string(),
+            data: decode_event_data(raw).map(DaoBudgetSet)?,
+            meta,
+        },
+        (
+            deip_dao::DaoBudgetRemovedEvent::<T>::MODULE,
+            deip_dao::DaoBudgetRemovedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_budgetRemoved".to_string(),
+            data: decode_event_data(raw).map(DaoBudgetRemoved)?,
+            meta,
+        },
+        (
+            deip_dao::DaoBudgetExhaustedEvent::<T>::MODULE,
+            deip_dao::DaoBudgetExhaustedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_budgetExhausted".to_string(),
+            data: decode_event_data(raw).map(DaoBudgetExhausted)?,
+            meta,
+        },
+        (
+            deip_dao::DaoGovernanceSetEvent::<T>::MODULE,
+            deip_dao::DaoGovernanceSetEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_governanceSet".to_string(),
+            data: decode_event_data(raw).map(DaoGovernanceSet)?,
+            meta,
+        },
+        (
+            deip_dao::DaoMotionProposedEvent::<T>::MODULE,
+            deip_dao::DaoMotionProposedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_motionProposed".to_string(),
+            data: decode_event_data(raw).map(DaoMotionProposed)?,
+            meta,
+        },
+        (
+            deip_dao::DaoMotionVotedEvent::<T>::MODULE,
+            deip_dao::DaoMotionVotedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_motionVoted".to_string(),
+            data: decode_event_data(raw).map(DaoMotionVoted)?,
+            meta,
+        },
+        (
+            deip_dao::DaoMotionExecutedEvent::<T>::MODULE,
+            deip_dao::DaoMotionExecutedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_motionExecuted".to_string(),
+            data: decode_event_data(raw).map(DaoMotionExecuted)?,
+            meta,
+        },
+        (
+            deip_dao::DaoMotionRejectedEvent::<T>::MODULE,
+            deip_dao::DaoMotionRejectedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_motionRejected".to_string(),
+            data: decode_event_data(raw).map(DaoMotionRejected)?,
+            meta,
+        },
+        (
+            deip_dao::DaoMemberInvitedEvent::<T>::MODULE,
+            deip_dao::DaoMemberInvitedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_memberInvited".to_string(),
+            data: decode_event_data(raw).map(DaoMemberInvited)?,
+            meta,
+        },
+        (
+            deip_dao::DaoInvitationCancelledEvent::<T>::MODULE,
+            deip_dao::DaoInvitationCancelledEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_invitationCancelled".to_string(),
+            data: decode_event_data(raw).map(DaoInvitationCancelled)?,
+            meta,
+        },
+        (
+            deip_dao::DaoInvitationAcceptedEvent::<T>::MODULE,
+            deip_dao::DaoInvitationAcceptedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_invitationAccepted".to_string(),
+            data: decode_event_data(raw).map(DaoInvitationAccepted)?,
+            meta,
+        },
+        (
+            deip_dao::DaoInvitationDeclinedEvent::<T>::MODULE,
+            deip_dao::DaoInvitationDeclinedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_invitationDeclined".to_string(),
+            data: decode_event_data(raw).map(DaoInvitationDeclined)?,
+            meta,
+        },
+        (
+            deip_dao::DaoDissolvedEvent::<T>::MODULE,
+            deip_dao::DaoDissolvedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_dissolved".to_string(),
+            data: decode_event_data(raw).map(DaoDissolved)?,
+            meta,
+        },
+        (
+            deip_dao::DaoHandleSetEvent::<T>::MODULE,
+            deip_dao::DaoHandleSetEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_handleSet".to_string(),
+            data: decode_event_data(raw).map(DaoHandleSet)?,
+            meta,
+        },
+        (
+            deip_dao::DaoHandleReservedEvent::<T>::MODULE,
+            deip_dao::DaoHandleReservedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_handleReserved".to_string(),
+            data: decode_event_data(raw).map(DaoHandleReserved)?,
+            meta,
+        },
+        (
+            deip_dao::DaoHandleReleasedEvent::<T>::MODULE,
+            deip_dao::DaoHandleReleasedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "dao_handleReleased".to_string(),
+            data: decode_event_data(raw).map(DaoHandleReleased)?,
+            meta,
+        },
         // =========== DeipAssets:
         (                               
             deip_assets::CreatedEvent::<T>::MODULE,
@@ -63,6 +63,86 @@ where
             /* deip_dao::DaoMetadataUpdatedEvent */
             unimplemented!()
         }
+        DaoRoleSet(..) => {
+            /* deip_dao::DaoRoleSetEvent */
+            unimplemented!()
+        }
+        DaoRoleRemoved(..) => {
+            /* deip_dao::DaoRoleRemovedEvent */
+            unimplemented!()
+        }
+        DaoRoleAssigned(..) => {
+            /* deip_dao::DaoRoleAssignedEvent */
+            unimplemented!()
+        }
+        DaoRoleRevoked(..) => {
+            /* deip_dao::DaoRoleRevokedEvent */
+            unimplemented!()
+        }
+        DaoBudgetSet(..) => {
+            /* deip_dao::DaoBudgetSetEvent */
+            unimplemented!()
+        }
+        DaoBudgetRemoved(..) => {
+            /* deip_dao::DaoBudgetRemovedEvent */
+            unimplemented!()
+        }
+        DaoBudgetExhausted(..) => {
+            /* deip_dao::DaoBudgetExhaustedEvent */
+            unimplemented!()
+        }
+        DaoGovernanceSet(..) => {
+            /* deip_dao::DaoGovernanceSetEvent */
+            unimplemented!()
+        }
+        DaoMotionProposed(..) => {
+            /* deip_dao::DaoMotionProposedEvent */
+            unimplemented!()
+        }
+        DaoMotionVoted(..) => {
+            /* deip_dao::DaoMotionVotedEvent */
+            unimplemented!()
+        }
+        DaoMotionExecuted(..) => {
+            /* deip_dao::DaoMotionExecutedEvent */
+            unimplemented!()
+        }
+        DaoMotionRejected(..) => {
+            /* deip_dao::DaoMotionRejectedEvent */
+            unimplemented!()
+        }
+        DaoMemberInvited(..) => {
+            /* deip_dao::DaoMemberInvitedEvent */
+            unimplemented!()
+        }
+        DaoInvitationCancelled(..) => {
+            /* deip_dao::DaoInvitationCancelledEvent */
+            unimplemented!()
+        }
+        DaoInvitationAccepted(..) => {
+            /* deip_dao::DaoInvitationAcceptedEvent */
+            unimplemented!()
+        }
+        DaoInvitationDeclined(..) => {
+            /* deip_dao::DaoInvitationDeclinedEvent */
+            unimplemented!()
+        }
+        DaoDissolved(..) => {
+            /* deip_dao::DaoDissolvedEvent */
+            unimplemented!()
+        }
+        DaoHandleSet(..) => {
+            /* deip_dao::DaoHandleSetEvent */
+            unimplemented!()
+        }
+        DaoHandleReserved(..) => {
+            /* deip_dao::DaoHandleReservedEvent */
+            unimplemented!()
+        }
+        DaoHandleReleased(..) => {
+            /* deip_dao::DaoHandleReleasedEvent */
+            unimplemented!()
+        }
         __Ignore(..) => unreachable!(),
     }
 }
@@ -82,6 +162,10 @@ where
             /* deip_proposal::ApprovedEvent */
             unimplemented!()
         }
+        Rejected { .. } => {
+            /* deip_proposal::RejectedEvent */
+            unimplemented!()
+        }
         RevokedApproval { .. } => {
             /* deip_proposal::RevokedApprovalEvent */
             unimplemented!()
@@ -90,10 +174,30 @@ where
             /* deip_proposal::ResolvedEvent */
             unimplemented!()
         }
+        Scheduled { .. } => {
+            /* deip_proposal::ScheduledEvent */
+            unimplemented!()
+        }
+        Executed { .. } => {
+            /* deip_proposal::ExecutedEvent */
+            unimplemented!()
+        }
         Expired { .. } => {
             /* deip_proposal::ExpiredEvent */
             unimplemented!()
         }
+        Cancelled { .. } => {
+            /* deip_proposal::CancelledEvent */
+            unimplemented!()
+        }
+        Amended { .. } => {
+            /* deip_proposal::AmendedEvent */
+            unimplemented!()
+        }
+        DaoMemberDecided { .. } => {
+            /* deip_proposal::DaoMemberDecidedEvent */
+            unimplemented!()
+        }
         __Ignore(..) => unreachable!(),
     }
 }
@@ -190,10 +294,54 @@ where
             /* deip::ProjectUpdatedEvent */
             unimplemented!()
         }
+        ProjectArchived(..) => {
+            /* deip::ProjectArchivedEvent */
+            unimplemented!()
+        }
+        ProjectUnarchived(..) => {
+            /* deip::ProjectUnarchivedEvent */
+            unimplemented!()
+        }
+        ProjectHandoverProposed(..) => {
+            /* deip::ProjectHandoverProposedEvent */
+            unimplemented!()
+        }
+        ProjectHandoverCancelled(..) => {
+            /* deip::ProjectHandoverCancelledEvent */
+            unimplemented!()
+        }
+        ProjectHandedOver(..) => {
+            /* deip::ProjectHandedOverEvent */
+            unimplemented!()
+        }
+        ProjectDomainAdded(..) => {
+            /* deip::ProjectDomainAddedEvent */
+            unimplemented!()
+        }
+        ProjectDomainRemoved(..) => {
+            /* deip::ProjectDomainRemovedEvent */
+            unimplemented!()
+        }
         ProjectContnetCreated(..) => {
             /* deip::ProjectContentCreatedEvent */
             unimplemented!()
         }
+        ProjectContentAmended(..) => {
+            /* deip::ProjectContentAmendedEvent */
+            unimplemented!()
+        }
+        ProjectContentAuthorConfirmed(..) => {
+            /* deip::ProjectContentAuthorConfirmedEvent */
+            unimplemented!()
+        }
+        ProjectContentAuthorDeclined(..) => {
+            /* deip::ProjectContentAuthorDeclinedEvent */
+            unimplemented!()
+        }
+        ProjectContentFinalized(..) => {
+            /* deip::ProjectContentFinalizedEvent */
+            unimplemented!()
+        }
         NdaCreated(..) => {
             /* deip::NdaCreatedEvent */
             unimplemented!()
@@ -214,6 +362,18 @@ where
             /* deip::DomainAddedEvent */
             unimplemented!()
         }
+        DomainUpdated(..) => {
+            /* deip::DomainUpdatedEvent */
+            unimplemented!()
+        }
+        DomainDeprecated(..) => {
+            /* deip::DomainDeprecatedEvent */
+            unimplemented!()
+        }
+        DomainMerged(..) => {
+            /* deip::DomainMergedEvent */
+            unimplemented!()
+        }
         ReviewCreated(..) => {
             /* deip::ReviewCreatedEvent */
             unimplemented!()
@@ -222,6 +382,14 @@ where
             /* deip::ReviewUpvotedEvent */
             unimplemented!()
         }
+        ReviewDomainAdded(..) => {
+            /* deip::ReviewDomainAddedEvent */
+            unimplemented!()
+        }
+        ReviewDomainRemoved(..) => {
+            /* deip::ReviewDomainRemovedEvent */
+            unimplemented!()
+        }
         SimpleCrowdfundingCreated(..) => {
             /* deip::SimpleCrowdfundingCreatedEvent */
             unimplemented!()
@@ -66,6 +66,101 @@ impl<T: Deip> Serialize for ProjectUpdatedEvent<T> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectArchivedEvent<T: Deip>(T::AccountId, T::ProjectId);
+impl<T: Deip> Serialize for ProjectArchivedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectArchivedEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectUnarchivedEvent<T: Deip>(T::AccountId, T::ProjectId);
+impl<T: Deip> Serialize for ProjectUnarchivedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectUnarchivedEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectHandoverProposedEvent<T: Deip>(T::AccountId, T::ProjectId, T::AccountId);
+impl<T: Deip> Serialize for ProjectHandoverProposedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectHandoverProposedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.serialize_field("to", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectHandoverCancelledEvent<T: Deip>(T::AccountId, T::ProjectId);
+impl<T: Deip> Serialize for ProjectHandoverCancelledEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectHandoverCancelledEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectHandedOverEvent<T: Deip>(T::AccountId, T::ProjectId, T::AccountId);
+impl<T: Deip> Serialize for ProjectHandedOverEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectHandedOverEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.serialize_field("to", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectDomainAddedEvent<T: Deip>(T::AccountId, T::ProjectId, T::DomainId);
+impl<T: Deip> Serialize for ProjectDomainAddedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectDomainAddedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.serialize_field("domain_id", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectDomainRemovedEvent<T: Deip>(T::AccountId, T::ProjectId, T::DomainId);
+impl<T: Deip> Serialize for ProjectDomainRemovedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectDomainRemovedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.serialize_field("domain_id", &self.2)?;
+        s.end()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct ProjectContentCreatedEvent<T: Deip>(T::AccountId, T::ProjectContentId);
 impl<T: Deip> Serialize for ProjectContentCreatedEvent<T> {
@@ -79,6 +174,58 @@ impl<T: Deip> Serialize for ProjectContentCreatedEvent<T> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectContentAmendedEvent<T: Deip>(T::AccountId, T::ProjectContentId, T::ProjectContentId);
+impl<T: Deip> Serialize for ProjectContentAmendedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectContentAmendedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("content_id", &self.1)?;
+        s.serialize_field("previous_version", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectContentAuthorConfirmedEvent<T: Deip>(T::AccountId, T::ProjectContentId);
+impl<T: Deip> Serialize for ProjectContentAuthorConfirmedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectContentAuthorConfirmedEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("content_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectContentAuthorDeclinedEvent<T: Deip>(T::AccountId, T::ProjectContentId);
+impl<T: Deip> Serialize for ProjectContentAuthorDeclinedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectContentAuthorDeclinedEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("content_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectContentFinalizedEvent<T: Deip>(T::ProjectContentId);
+impl<T: Deip> Serialize for ProjectContentFinalizedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectContentFinalizedEvent", 1)?;
+        s.serialize_field("content_id", &self.0)?;
+        s.end()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct NdaCreatedEvent<T: Deip>(T::AccountId, T::NdaId);
 impl<T: Deip> Serialize for NdaCreatedEvent<T> {
@@ -132,14 +279,50 @@ impl<T: Deip> Serialize for NdaAccessRequestRejectedEvent<T> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
-pub struct DomainAddedEvent<T: Deip>(T::AccountId, T::DomainId);
+pub struct DomainAddedEvent<T: Deip>(T::DomainId);
 impl<T: Deip> Serialize for DomainAddedEvent<T> {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
         where S: Serializer
     {
-        let mut s = serializer.serialize_struct("DomainAddedEvent", 2)?;
-        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
-        s.serialize_field("domain_id", &self.1)?;
+        let mut s = serializer.serialize_struct("DomainAddedEvent", 1)?;
+        s.serialize_field("domain_id", &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DomainUpdatedEvent<T: Deip>(T::DomainId);
+impl<T: Deip> Serialize for DomainUpdatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DomainUpdatedEvent", 1)?;
+        s.serialize_field("domain_id", &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DomainDeprecatedEvent<T: Deip>(T::DomainId);
+impl<T: Deip> Serialize for DomainDeprecatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DomainDeprecatedEvent", 1)?;
+        s.serialize_field("domain_id", &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DomainMergedEvent<T: Deip>(T::DomainId, T::DomainId);
+impl<T: Deip> Serialize for DomainMergedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DomainMergedEvent", 2)?;
+        s.serialize_field("domain_id", &self.0)?;
+        s.serialize_field("into", &self.1)?;
         s.end()
     }
 }
@@ -171,6 +354,34 @@ impl<T: Deip> Serialize for ReviewUpvotedEvent<T> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ReviewDomainAddedEvent<T: Deip>(T::AccountId, T::ReviewId, T::DomainId);
+impl<T: Deip> Serialize for ReviewDomainAddedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ReviewDomainAddedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("review_id", &self.1)?;
+        s.serialize_field("domain_id", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ReviewDomainRemovedEvent<T: Deip>(T::AccountId, T::ReviewId, T::DomainId);
+impl<T: Deip> Serialize for ReviewDomainRemovedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ReviewDomainRemovedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("review_id", &self.1)?;
+        s.serialize_field("domain_id", &self.2)?;
+        s.end()
+    }
+}
+
 const INVESTMENT_ID_KEY: &str = "investment_id";
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -12,6 +12,12 @@ use serde::{Serialize, ser::{Serializer, SerializeStruct}};
 #[module]
 pub trait DeipDao: System {
     type Dao: Parameter + Member + Serialize;
+    type DaoId: Parameter + Member + Serialize;
+    type RoleId: Parameter + Member + Serialize;
+    type BudgetId: Parameter + Member + Serialize;
+    type MotionId: Parameter + Member + Serialize;
+    type DispatchResult: Parameter + Member + Serialize;
+    type DaoHandle: Parameter + Member + Serialize;
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -49,3 +55,266 @@ impl<T: DeipDao> Serialize for DaoMetadataUpdatedEvent<T> {
         s.end()
     }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoRoleSetEvent<T: DeipDao>(T::DaoId, T::RoleId);
+impl<T: DeipDao> Serialize for DaoRoleSetEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoRoleSetEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("role_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoRoleRemovedEvent<T: DeipDao>(T::DaoId, T::RoleId);
+impl<T: DeipDao> Serialize for DaoRoleRemovedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoRoleRemovedEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("role_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoRoleAssignedEvent<T: DeipDao>(T::DaoId, T::RoleId, <T as System>::AccountId);
+impl<T: DeipDao> Serialize for DaoRoleAssignedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoRoleAssignedEvent", 3)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("role_id", &self.1)?;
+        s.serialize_field("member", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoRoleRevokedEvent<T: DeipDao>(T::DaoId, T::RoleId, <T as System>::AccountId);
+impl<T: DeipDao> Serialize for DaoRoleRevokedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoRoleRevokedEvent", 3)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("role_id", &self.1)?;
+        s.serialize_field("member", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoBudgetSetEvent<T: DeipDao>(T::DaoId, T::BudgetId);
+impl<T: DeipDao> Serialize for DaoBudgetSetEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoBudgetSetEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("budget_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoBudgetRemovedEvent<T: DeipDao>(T::DaoId, T::BudgetId);
+impl<T: DeipDao> Serialize for DaoBudgetRemovedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoBudgetRemovedEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("budget_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoBudgetExhaustedEvent<T: DeipDao>(T::DaoId, T::BudgetId);
+impl<T: DeipDao> Serialize for DaoBudgetExhaustedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoBudgetExhaustedEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("budget_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoGovernanceSetEvent<T: DeipDao>(T::DaoId);
+impl<T: DeipDao> Serialize for DaoGovernanceSetEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoGovernanceSetEvent", 1)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoMotionProposedEvent<T: DeipDao>(T::DaoId, T::MotionId, <T as System>::AccountId);
+impl<T: DeipDao> Serialize for DaoMotionProposedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoMotionProposedEvent", 3)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("motion_id", &self.1)?;
+        s.serialize_field("proposer", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoMotionVotedEvent<T: DeipDao>(T::DaoId, T::MotionId, <T as System>::AccountId, bool);
+impl<T: DeipDao> Serialize for DaoMotionVotedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoMotionVotedEvent", 4)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("motion_id", &self.1)?;
+        s.serialize_field("voter", &self.2)?;
+        s.serialize_field("aye", &self.3)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoMotionExecutedEvent<T: DeipDao>(T::DaoId, T::MotionId, T::DispatchResult);
+impl<T: DeipDao> Serialize for DaoMotionExecutedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoMotionExecutedEvent", 3)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("motion_id", &self.1)?;
+        s.serialize_field("result", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoMotionRejectedEvent<T: DeipDao>(T::DaoId, T::MotionId);
+impl<T: DeipDao> Serialize for DaoMotionRejectedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoMotionRejectedEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("motion_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoMemberInvitedEvent<T: DeipDao>(T::DaoId, <T as System>::AccountId, <T as System>::BlockNumber);
+impl<T: DeipDao> Serialize for DaoMemberInvitedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoMemberInvitedEvent", 3)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("member", &self.1)?;
+        s.serialize_field("expires_at", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoInvitationCancelledEvent<T: DeipDao>(T::DaoId, <T as System>::AccountId);
+impl<T: DeipDao> Serialize for DaoInvitationCancelledEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoInvitationCancelledEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("member", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoInvitationAcceptedEvent<T: DeipDao>(T::DaoId, <T as System>::AccountId);
+impl<T: DeipDao> Serialize for DaoInvitationAcceptedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoInvitationAcceptedEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("member", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoInvitationDeclinedEvent<T: DeipDao>(T::DaoId, <T as System>::AccountId);
+impl<T: DeipDao> Serialize for DaoInvitationDeclinedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoInvitationDeclinedEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("member", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoDissolvedEvent<T: DeipDao>(T::DaoId);
+impl<T: DeipDao> Serialize for DaoDissolvedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoDissolvedEvent", 1)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoHandleSetEvent<T: DeipDao>(T::DaoId, T::DaoHandle);
+impl<T: DeipDao> Serialize for DaoHandleSetEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoHandleSetEvent", 2)?;
+        s.serialize_field("dao_id", &self.0)?;
+        s.serialize_field("handle", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoHandleReservedEvent<T: DeipDao>(T::DaoHandle);
+impl<T: DeipDao> Serialize for DaoHandleReservedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoHandleReservedEvent", 1)?;
+        s.serialize_field("handle", &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoHandleReleasedEvent<T: DeipDao>(T::DaoHandle);
+impl<T: DeipDao> Serialize for DaoHandleReleasedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoHandleReleasedEvent", 1)?;
+        s.serialize_field("handle", &self.0)?;
+        s.end()
+    }
+}
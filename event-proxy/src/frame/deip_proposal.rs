@@ -17,6 +17,10 @@ pub trait DeipProposal: System {
     type Call: Parameter + Member;
     type BatchItem: Parameter + Member;
     type ProposalState: Parameter + Member + Serialize;
+    type ApprovalTally: Parameter + Member + Serialize;
+    type Moment: Parameter + Member + Serialize;
+    type ProposalMemberDecision: Parameter + Member + Serialize;
+    type BatchResults: Parameter + Member + Serialize;
     /// Wrapper type to perform data transformations before serialization
     type WrappedBatch: Parameter + Member + Serialize;
     /// Wrapper type to perform data transformations before serialization
@@ -63,6 +67,22 @@ impl<T: DeipProposal> Serialize for ApprovedEvent<T> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct RejectedEvent<T: DeipProposal> {
+    pub member: <T as System>::AccountId,
+    pub proposal_id: T::ProposalId,
+}
+impl<T: DeipProposal> Serialize for RejectedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("RejectedEvent", 2)?;
+        s.serialize_field("member", &self.member)?;
+        s.serialize_field("proposal_id", &self.proposal_id)?;
+        s.end()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct RevokedApprovalEvent<T: DeipProposal> {
     pub member: <T as System>::AccountId,
@@ -83,16 +103,92 @@ impl<T: DeipProposal> Serialize for RevokedApprovalEvent<T> {
 pub struct ResolvedEvent<T: DeipProposal> {
     pub member: <T as System>::AccountId,
     pub proposal_id: T::ProposalId,
-    pub state: T::ProposalState
+    pub state: T::ProposalState,
+    pub tally: T::ApprovalTally,
+    pub batch_results: T::BatchResults,
 }
 impl<T: DeipProposal> Serialize for ResolvedEvent<T> {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
         where S: Serializer
     {
-        let mut s = serializer.serialize_struct("ResolvedEvent", 3)?;
+        let mut s = serializer.serialize_struct("ResolvedEvent", 5)?;
         s.serialize_field("member", &self.member)?;
         s.serialize_field("proposal_id", &self.proposal_id)?;
         s.serialize_field("state", &self.state)?;
+        s.serialize_field("tally", &self.tally)?;
+        s.serialize_field("batch_results", &self.batch_results)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct CancelledEvent<T: DeipProposal> {
+    pub author: <T as System>::AccountId,
+    pub proposal_id: T::ProposalId,
+}
+impl<T: DeipProposal> Serialize for CancelledEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("CancelledEvent", 2)?;
+        s.serialize_field("author", &self.author)?;
+        s.serialize_field("proposal_id", &self.proposal_id)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct AmendedEvent<T: DeipProposal> {
+    pub author: <T as System>::AccountId,
+    pub batch: T::ProposalBatch,
+    pub proposal_id: T::ProposalId,
+}
+impl<T: DeipProposal> Serialize for AmendedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("AmendedEvent", 3)?;
+        s.serialize_field("author", &self.author)?;
+        s.serialize_field("batch", &T::wrap_batch::<T::WrappedBatch>(&self.batch))?;
+        s.serialize_field("proposal_id", &self.proposal_id)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ScheduledEvent<T: DeipProposal> {
+    pub member: <T as System>::AccountId,
+    pub proposal_id: T::ProposalId,
+    pub execute_after: T::Moment,
+    pub tally: T::ApprovalTally,
+}
+impl<T: DeipProposal> Serialize for ScheduledEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ScheduledEvent", 4)?;
+        s.serialize_field("member", &self.member)?;
+        s.serialize_field("proposal_id", &self.proposal_id)?;
+        s.serialize_field("execute_after", &self.execute_after)?;
+        s.serialize_field("tally", &self.tally)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ExecutedEvent<T: DeipProposal> {
+    pub proposal_id: T::ProposalId,
+    pub state: T::ProposalState,
+    pub batch_results: T::BatchResults,
+}
+impl<T: DeipProposal> Serialize for ExecutedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ExecutedEvent", 3)?;
+        s.serialize_field("proposal_id", &self.proposal_id)?;
+        s.serialize_field("state", &self.state)?;
+        s.serialize_field("batch_results", &self.batch_results)?;
         s.end()
     }
 }
@@ -110,3 +206,23 @@ impl<T: DeipProposal> Serialize for ExpiredEvent<T> {
         s.end()
     }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DaoMemberDecidedEvent<T: DeipProposal> {
+    pub member: <T as System>::AccountId,
+    pub dao: <T as System>::AccountId,
+    pub proposal_id: T::ProposalId,
+    pub decision: T::ProposalMemberDecision,
+}
+impl<T: DeipProposal> Serialize for DaoMemberDecidedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DaoMemberDecidedEvent", 4)?;
+        s.serialize_field("member", &self.member)?;
+        s.serialize_field("dao", &self.dao)?;
+        s.serialize_field("proposal_id", &self.proposal_id)?;
+        s.serialize_field("decision", &self.decision)?;
+        s.end()
+    }
+}
@@ -13,6 +13,10 @@ impl frame::deip_proposal::DeipProposal for RuntimeT {
     type Call = node_template_runtime::Call;
     type BatchItem = pallet_deip_proposal::proposal::ProposalBatchItemOf<RealRuntime>;
     type ProposalState = pallet_deip_proposal::proposal::ProposalState;
+    type ApprovalTally = pallet_deip_proposal::proposal::ApprovalTally;
+    type Moment = node_template_runtime::Moment;
+    type ProposalMemberDecision = pallet_deip_proposal::proposal::ProposalMemberDecision;
+    type BatchResults = pallet_deip_proposal::proposal::BatchResults;
     type WrappedBatch = Vec<pallet_deip_proposal::proposal::BatchItem<
         node_template_runtime::AccountId, Self::WrappedCall>>;
     type WrappedInputBatch = Vec<pallet_deip_proposal::proposal::BatchItem<
@@ -51,6 +55,12 @@ impl frame::deip::Deip for RuntimeT {
 
 impl frame::deip_dao::DeipDao for RuntimeT {
     type Dao = pallet_deip_dao::dao::DaoOf<RealRuntime>;
+    type DaoId = pallet_deip_dao::dao::DaoId;
+    type RoleId = pallet_deip_dao::role::RoleId;
+    type BudgetId = pallet_deip_dao::budget::BudgetId;
+    type MotionId = pallet_deip_dao::governance::MotionId;
+    type DispatchResult = sp_runtime::DispatchResult;
+    type DaoHandle = pallet_deip_dao::handle::DaoHandle;
 }
 
 type AssetId = <RealRuntime as pallet_assets::Config>::AssetId;
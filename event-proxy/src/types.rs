@@ -10,10 +10,15 @@ pub fn register_types<T: Runtime>(c: ClientBuilder<T>) -> ClientBuilder<T>
     c
         // System:
         .register_type_size::<<T as System>::AccountId>("T::AccountId")
+        .register_type_size::<<T as System>::BlockNumber>("T::BlockNumber")
         // DeipProposal:
         .register_type_size::<<T as DeipProposal>::ProposalBatch>("ProposalBatch<T>")
         .register_type_size::<<T as DeipProposal>::ProposalId>("ProposalId")
         .register_type_size::<<T as DeipProposal>::ProposalState>("ProposalState")
+        .register_type_size::<<T as DeipProposal>::ApprovalTally>("ApprovalTally")
+        .register_type_size::<<T as DeipProposal>::Moment>("T::Moment")
+        .register_type_size::<<T as DeipProposal>::ProposalMemberDecision>("ProposalMemberDecision")
+        .register_type_size::<<T as DeipProposal>::BatchResults>("BatchResults")
         // Deip:               
         .register_type_size::<<T as Deip>::DomainId>("DomainId")
         .register_type_size::<<T as Deip>::ProjectId>("ProjectId")
@@ -29,4 +34,9 @@ pub fn register_types<T: Runtime>(c: ClientBuilder<T>) -> ClientBuilder<T>
         .register_type_size::<<T as Deip>::ContractAgreementTerms>("ContractAgreementTerms")
         // DeipDao:
         .register_type_size::<<T as DeipDao>::Dao>("DaoOf<T>")
+        .register_type_size::<<T as DeipDao>::DaoId>("DaoId")
+        .register_type_size::<<T as DeipDao>::RoleId>("RoleId")
+        .register_type_size::<<T as DeipDao>::BudgetId>("BudgetId")
+        .register_type_size::<<T as DeipDao>::MotionId>("MotionId")
+        .register_type_size::<<T as DeipDao>::DispatchResult>("DispatchResult")
 }
@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use node_template_runtime::{opaque::Block, AccountId, AssetBalance, AssetId, Balance, Index, Moment, Hash};
+use node_template_runtime::{opaque::Block, AccountId, AssetBalance, AssetId, Balance, BlockNumber, Index, Moment, Hash};
 pub use sc_rpc_api::DenyUnsafe;
 use sp_api::{CallApiAt, Metadata, ProvideRuntimeApi};
 use sp_block_builder::BlockBuilder;
@@ -45,7 +45,13 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: deip_rpc::DeipStorageRuntimeApi<Block, AccountId, Moment, AssetId, AssetBalance, Hash>,
-    C::Api: deip_dao_rpc::DeipDaoRuntimeApi<Block, AccountId>,
+    C::Api: deip_dao_rpc::DeipDaoRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber, Moment>,
+    C::Api: deip_proposal_rpc::DeipProposalRuntimeApi<
+        Block,
+        node_template_runtime::deip_account::DeipAccountId<AccountId>,
+        node_template_runtime::Call,
+        node_template_runtime::Event,
+    >,
     C::Api: BlockBuilder<Block>,
     C::Api: Metadata<Block, Error = BlockChainError>,
     P: TransactionPool + 'static,
@@ -108,12 +114,23 @@ where
         <Block as traits::Block>::Hash,
         AccountId,
         Moment,
+        BlockNumber,
         deip_proposal_rpc::Call<node_template_runtime::Call>
     >::to_delegate(deip_proposal_rpc::DeipProposalRpcApiObj::<
         sc_rpc::state::State<Block, C>,
         Block,
     >::new(state)));
 
+    io.extend_with(deip_proposal_rpc::DeipProposalDryRunApi::to_delegate(
+        deip_proposal_rpc::DeipProposalDryRunApiObj::<
+            C,
+            Block,
+            node_template_runtime::deip_account::DeipAccountId<AccountId>,
+            node_template_runtime::Call,
+            node_template_runtime::Event,
+        >::new(client.clone()),
+    ));
+
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
     // to call into the runtime.
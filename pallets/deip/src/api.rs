@@ -19,7 +19,13 @@ sp_api::decl_runtime_apis! {
     {
         fn get_project(project_id: &ProjectId) -> Option<Project<Hash, AccountId>>;
         fn get_domain(domain_id: &DomainId) -> Option<Domain>;
+        /// Projects of the domain and all its descendants ordered by id, starting after `start_id`
+        fn get_project_list_by_domain_tree(domain_id: &DomainId, count: u32, start_id: Option<ProjectId>) -> Vec<(ProjectId, Project<Hash, AccountId>)>;
+        /// Reviews of the domain and all its descendants ordered by id, starting after `start_id`
+        fn get_review_list_by_domain_tree(domain_id: &DomainId, count: u32, start_id: Option<ReviewId>) -> Vec<(ReviewId, Review<Hash, AccountId>)>;
         fn get_project_content(id: &ProjectContentId) -> Option<ProjectContent<Hash, AccountId>>;
+        /// All revisions of the project content from the original one
+        fn get_project_content_revisions(id: &ProjectContentId) -> Vec<ProjectContent<Hash, AccountId>>;
         fn get_nda(nda_id: &NdaId) -> Option<Nda<Hash, AccountId, Moment>>;
         fn get_review(review_id: &ReviewId) -> Option<Review<Hash, AccountId>>;
         fn get_investment_opportunity(id: &InvestmentId) -> Option<SimpleCrowdfunding<Moment, AssetId, AssetBalance>>;
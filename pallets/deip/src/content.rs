@@ -0,0 +1,201 @@
+use crate::*;
+use frame_support::traits::Get;
+
+/// Project content lifecycle
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum Status {
+    /// Waiting for the listed authors to confirm or decline the authorship
+    #[default]
+    PendingAuthors,
+    /// Authors of the content are final
+    Finalized,
+}
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PendingAuthors<AccountId, Moment> {
+    /// Listed authors who haven't confirmed or declined the authorship yet
+    pub pending: Vec<AccountId>,
+    /// Authors who confirmed the authorship
+    pub confirmed: Vec<AccountId>,
+    /// The content is finalized with the confirmed authors after this moment
+    pub deadline: Moment,
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn ensure_references_exist(references: &Option<Vec<ProjectContentId>>) -> DispatchResult {
+        if let Some(references) = references {
+            let is_all_references_exists = references
+                .iter()
+                .all(|&reference| ProjectContentMap::<T>::contains_key(reference));
+
+            ensure!(is_all_references_exists, Error::<T>::NoSuchReference);
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn amend_project_content_impl(
+        account: AccountIdOf<T>,
+        external_id: ProjectContentId,
+        previous_version: ProjectContentId,
+        description: T::Hash,
+        content: T::Hash,
+        authors: Vec<AccountIdOf<T>>,
+        references: Option<Vec<ProjectContentId>>,
+        reason: T::Hash,
+    ) -> DispatchResult {
+        ensure!(!ProjectContentMap::<T>::contains_key(external_id), Error::<T>::ProjectContentAlreadyExists);
+
+        let mut previous = ProjectContentMap::<T>::try_get(previous_version)
+            .map_err(|_| Error::<T>::NoSuchProjectContent)?;
+        ensure!(previous.is_latest, Error::<T>::ProjectContentNotLatestVersion);
+        ensure!(previous.status == Status::Finalized, Error::<T>::ProjectContentNotFinalized);
+
+        let project = ProjectMap::<T>::try_get(previous.project_external_id)
+            .map_err(|_| Error::<T>::NoSuchProject)?;
+        ensure!(project.team_id == account, Error::<T>::NoPermission);
+        ensure!(!project.is_archived, Error::<T>::ProjectArchived);
+
+        Self::ensure_references_exist(&references)?;
+
+        let mut amended = ProjectContentOf::<T> {
+            external_id,
+            project_external_id: previous.project_external_id,
+            team_id: project.team_id,
+            content_type: previous.content_type.clone(),
+            description,
+            content,
+            authors,
+            references,
+            previous_version: Some(previous_version),
+            version: previous.version.saturating_add(1),
+            amendment_reason: Some(reason),
+            is_latest: true,
+            status: Status::PendingAuthors,
+        };
+        Self::request_authors_confirmation(&mut amended, &account);
+        previous.is_latest = false;
+
+        ContentIdByProjectId::insert(previous.project_external_id, external_id, ());
+        ProjectContentMap::<T>::insert(external_id, amended);
+        ProjectContentMap::<T>::insert(previous_version, previous);
+        NextContentVersion::insert(previous_version, external_id);
+
+        Self::deposit_event(RawEvent::ProjectContentAmended(account, external_id, previous_version));
+
+        Ok(())
+    }
+
+    /// Authorship of the submitter is confirmed by the signature,
+    /// the content is finalized at once if there are no other authors
+    pub(super) fn request_authors_confirmation(content: &mut ProjectContentOf<T>, submitter: &AccountIdOf<T>) {
+        let mut authors = ProjectContentPendingAuthorsOf::<T> {
+            deadline: pallet_timestamp::Module::<T>::get() + T::ContentAuthorsConfirmationPeriod::get(),
+            ..Default::default()
+        };
+        for author in &content.authors {
+            if authors.pending.contains(author) || authors.confirmed.contains(author) {
+                continue;
+            }
+            if author == submitter {
+                ContentIdByAuthorId::<T>::insert(author, content.external_id, ());
+                authors.confirmed.push(author.clone());
+            } else {
+                authors.pending.push(author.clone());
+            }
+        }
+
+        if authors.pending.is_empty() {
+            content.status = Status::Finalized;
+        } else {
+            content.status = Status::PendingAuthors;
+            PendingContentAuthors::<T>::insert(content.external_id, authors);
+        }
+    }
+
+    pub(super) fn decide_project_content_authorship_impl(
+        account: AccountIdOf<T>,
+        content_id: ProjectContentId,
+        confirm: bool,
+    ) -> DispatchResult {
+        let mut authors = PendingContentAuthors::<T>::get(content_id)
+            .ok_or(Error::<T>::ProjectContentNoSuchPendingAuthor)?;
+        let index = authors.pending.iter().position(|a| *a == account)
+            .ok_or(Error::<T>::ProjectContentNoSuchPendingAuthor)?;
+
+        authors.pending.remove(index);
+        if confirm {
+            ContentIdByAuthorId::<T>::insert(&account, content_id, ());
+            authors.confirmed.push(account.clone());
+            Self::deposit_event(RawEvent::ProjectContentAuthorConfirmed(account, content_id));
+        } else {
+            Self::deposit_event(RawEvent::ProjectContentAuthorDeclined(account, content_id));
+        }
+
+        if authors.pending.is_empty() {
+            Self::finalize_authors(content_id, authors);
+        } else {
+            PendingContentAuthors::<T>::insert(content_id, authors);
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn finalize_project_content_impl(content_id: ProjectContentId) -> DispatchResult {
+        let authors = PendingContentAuthors::<T>::get(content_id).ok_or(Error::<T>::NoSuchProjectContent)?;
+        ensure!(
+            authors.deadline <= pallet_timestamp::Module::<T>::get(),
+            Error::<T>::ProjectContentAuthorsConfirmationNotExpired
+        );
+
+        Self::finalize_authors(content_id, authors);
+
+        Ok(())
+    }
+
+    /// Finalize the content with the confirmed authors
+    fn finalize_authors(content_id: ProjectContentId, authors: ProjectContentPendingAuthorsOf<T>) {
+        PendingContentAuthors::<T>::remove(content_id);
+        ProjectContentMap::<T>::mutate(content_id, |content| {
+            content.authors.retain(|a| authors.confirmed.contains(a));
+            content.status = Status::Finalized;
+        });
+
+        Self::deposit_event(RawEvent::ProjectContentFinalized(content_id));
+    }
+
+    pub(super) fn process_pending_content_offchain() {
+        let now = pallet_timestamp::Module::<T>::get();
+        for (id, authors) in PendingContentAuthors::<T>::iter() {
+            if authors.deadline <= now {
+                let call = Call::finalize_project_content(id);
+                let submit =
+                    SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
+                debug!("submit finalize_project_content: {}", submit.is_ok());
+            }
+        }
+    }
+
+    /// All revisions of the project content from the original one
+    pub fn get_project_content_revisions(id: &ProjectContentId) -> Vec<ProjectContentOf<T>> {
+        let mut first = match ProjectContentMap::<T>::try_get(id) {
+            Ok(content) => content,
+            Err(_) => return Vec::new(),
+        };
+        while let Some(previous) = first.previous_version {
+            first = ProjectContentMap::<T>::get(previous);
+        }
+
+        let mut next = NextContentVersion::get(first.external_id);
+        let mut revisions = vec![first];
+        while let Some(id) = next {
+            next = NextContentVersion::get(id);
+            revisions.push(ProjectContentMap::<T>::get(id));
+        }
+        revisions
+    }
+}
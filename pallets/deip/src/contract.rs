@@ -91,6 +91,36 @@ pub enum GeneralContractStatus<AccountId, Hash, Moment> {
 }
 
 impl<T: Config> Module<T> {
+    fn project_licenses(
+        project_id: ProjectId,
+    ) -> impl Iterator<Item = LicenseStatus<AccountIdOf<T>, HashOf<T>, MomentOf<T>, DeipAssetOf<T>>> {
+        LicenseIdByProjectId::iter_prefix(project_id)
+            .filter_map(|(id, _)| match ContractAgreementMap::<T>::get(id) {
+                Agreement::License(status) => Some(status),
+                _ => None,
+            })
+    }
+
+    /// Checks if the project has a license not signed by both parties yet
+    pub(super) fn has_pending_license(project_id: ProjectId) -> bool {
+        Self::project_licenses(project_id).any(|status| {
+            matches!(status, LicenseStatus::Unsigned(_) | LicenseStatus::SignedByLicenser(_))
+        })
+    }
+
+    /// Checks if the project has a pending license or a signed one that is not expired
+    pub(super) fn has_active_license(project_id: ProjectId) -> bool {
+        let now = pallet_timestamp::Module::<T>::get();
+        Self::project_licenses(project_id).any(|status| match status {
+            LicenseStatus::Unsigned(_) | LicenseStatus::SignedByLicenser(_) => true,
+            LicenseStatus::Signed(license) => match license.expiration_time {
+                Some(expiration_time) => now <= expiration_time,
+                None => true,
+            },
+            LicenseStatus::Rejected(_) => false,
+        })
+    }
+
     pub(super) fn create_contract_agreement_impl(
         account: AccountIdOf<T>,
         id: Id,
@@ -169,9 +199,10 @@ impl<T: Config> Module<T> {
 
         let project =
             ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+        ensure!(!project.is_archived, Error::<T>::ProjectArchived);
 
-        let second: AccountIdOf<T> = parties.pop().unwrap().into();
-        let first: AccountIdOf<T> = parties.pop().unwrap().into();
+        let second: AccountIdOf<T> = Self::deip_account(parties.pop().unwrap())?;
+        let first: AccountIdOf<T> = Self::deip_account(parties.pop().unwrap())?;
         let (licenser, licensee) = if first == project.team_id {
             (first, second)
         } else if second == project.team_id {
@@ -194,6 +225,7 @@ impl<T: Config> Module<T> {
 
         ContractAgreementMap::<T>::insert(id, Agreement::License(LicenseStatus::Unsigned(license)));
         ContractAgreementIdByType::insert(IndexTerms::LicenseAgreement, id, ());
+        LicenseIdByProjectId::insert(project_id, id, ());
 
         Self::deposit_event(RawEvent::ContractAgreementCreated(id));
 
@@ -385,7 +417,7 @@ impl<T: Config> Module<T> {
         let contract = GeneralContract {
             id,
             creator,
-            parties: parties.into_iter().map(|p| p.into()).collect(),
+            parties: Self::deip_accounts(parties)?,
             hash,
             activation_time,
             expiration_time,
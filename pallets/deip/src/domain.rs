@@ -0,0 +1,156 @@
+use crate::*;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::ops::Bound;
+
+/// Domain lifecycle. Only active domains may be assigned to projects and reviews
+/// or become a parent of new domains.
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum Status {
+    #[default]
+    Active,
+    /// Domain is kept for existing projects and reviews only
+    Deprecated,
+    /// Domain became a subdomain of the specified one and is kept for existing projects and reviews only
+    MergedInto(DomainId),
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn ensure_active_domain(domain_id: &DomainId) -> DispatchResult {
+        let domain = Domains::try_get(domain_id).map_err(|_| Error::<T>::DomainNotExists)?;
+        ensure!(domain.status == Status::Active, Error::<T>::DomainNotActive);
+        Ok(())
+    }
+
+    /// The domain followed by all its descendants (subdomains and merged domains)
+    pub fn domain_subtree(domain_id: &DomainId) -> Vec<DomainId> {
+        let mut subtree = vec![*domain_id];
+        let mut i = 0;
+        while i < subtree.len() {
+            subtree.extend(DomainIdByParentId::iter_prefix(subtree[i]).map(|(k, _)| k));
+            i += 1;
+        }
+        subtree
+    }
+
+    /// Ordered ids linked to any domain of the subtree, starting after `start_id`
+    fn ids_by_domain_subtree<Id: Ord + Copy, I: Iterator<Item = Id>>(
+        domain_id: &DomainId,
+        count: u32,
+        start_id: Option<Id>,
+        ids_by_domain: impl Fn(DomainId) -> I,
+    ) -> Vec<Id> {
+        let ids: BTreeSet<Id> = Self::domain_subtree(domain_id).into_iter().flat_map(ids_by_domain).collect();
+        let start = match start_id {
+            Some(id) => Bound::Excluded(id),
+            None => Bound::Unbounded,
+        };
+        ids.range((start, Bound::Unbounded)).take(count as usize).copied().collect()
+    }
+
+    pub fn get_project_list_by_domain_tree(
+        domain_id: &DomainId,
+        count: u32,
+        start_id: Option<ProjectId>,
+    ) -> Vec<(ProjectId, ProjectOf<T>)> {
+        Self::ids_by_domain_subtree(domain_id, count, start_id, |d| {
+            ProjectIdByDomainId::iter_prefix(d).map(|(k, _)| k)
+        })
+        .into_iter()
+        .map(|id| (id, ProjectMap::<T>::get(id)))
+        .collect()
+    }
+
+    pub fn get_review_list_by_domain_tree(
+        domain_id: &DomainId,
+        count: u32,
+        start_id: Option<ReviewId>,
+    ) -> Vec<(ReviewId, ReviewOf<T>)> {
+        Self::ids_by_domain_subtree(domain_id, count, start_id, |d| {
+            ReviewIdByDomainId::iter_prefix(d).map(|(k, _)| k)
+        })
+        .into_iter()
+        .map(|id| (id, ReviewMap::<T>::get(id)))
+        .collect()
+    }
+
+    fn is_in_subtree(domain_id: &DomainId, root: &DomainId) -> bool {
+        let mut current = Some(*domain_id);
+        while let Some(id) = current {
+            if id == *root {
+                return true;
+            }
+            current = Domains::get(id).parent;
+        }
+        false
+    }
+
+    pub(super) fn add_domain_impl(domain: Domain) -> DispatchResult {
+        let domain_count = DomainCount::get();
+        ensure!(domain_count < MAX_DOMAINS, Error::<T>::DomianLimitReached);
+
+        let external_id = domain.external_id;
+
+        // We don't want to add duplicate domains, so we check whether the potential new
+        // domain is already present in the list. Because the domains is stored as a hash
+        // map this check is constant time O(1)
+        ensure!(!Domains::contains_key(external_id), Error::<T>::DomainAlreadyExists);
+        ensure!(domain.status == Status::Active, Error::<T>::DomainNotActive);
+        if let Some(parent) = domain.parent {
+            Self::ensure_active_domain(&parent)?;
+            DomainIdByParentId::insert(parent, external_id, ());
+        }
+
+        // Insert the new domin and emit the event
+        Domains::insert(external_id, domain);
+        DomainCount::put(domain_count + 1); // overflow check not necessary because of maximum
+
+        Self::deposit_event(RawEvent::DomainAdded(external_id));
+
+        Ok(())
+    }
+
+    pub(super) fn update_domain_impl(domain_id: DomainId, metadata: Option<H256>) -> DispatchResult {
+        Domains::mutate_exists(domain_id, |maybe_domain| -> DispatchResult {
+            let domain = maybe_domain.as_mut().ok_or(Error::<T>::DomainNotExists)?;
+            domain.metadata = metadata;
+            Ok(())
+        })?;
+
+        Self::deposit_event(RawEvent::DomainUpdated(domain_id));
+
+        Ok(())
+    }
+
+    pub(super) fn deprecate_domain_impl(domain_id: DomainId) -> DispatchResult {
+        let mut domain = Domains::try_get(domain_id).map_err(|_| Error::<T>::DomainNotExists)?;
+        ensure!(domain.status == Status::Active, Error::<T>::DomainNotActive);
+
+        domain.status = Status::Deprecated;
+        Domains::insert(domain_id, domain);
+
+        Self::deposit_event(RawEvent::DomainDeprecated(domain_id));
+
+        Ok(())
+    }
+
+    pub(super) fn merge_domain_impl(domain_id: DomainId, into: DomainId) -> DispatchResult {
+        let mut domain = Domains::try_get(domain_id).map_err(|_| Error::<T>::DomainNotExists)?;
+        ensure!(!matches!(domain.status, Status::MergedInto(_)), Error::<T>::DomainNotActive);
+        Self::ensure_active_domain(&into)?;
+        ensure!(!Self::is_in_subtree(&into, &domain_id), Error::<T>::DomainMergeIntoSubtree);
+
+        if let Some(parent) = domain.parent {
+            DomainIdByParentId::remove(parent, domain_id);
+        }
+        DomainIdByParentId::insert(into, domain_id, ());
+        domain.parent = Some(into);
+        domain.status = Status::MergedInto(into);
+        Domains::insert(domain_id, domain);
+
+        Self::deposit_event(RawEvent::DomainMerged(domain_id, into));
+
+        Ok(())
+    }
+}
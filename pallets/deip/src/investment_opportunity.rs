@@ -68,6 +68,13 @@ pub struct Info<Moment, AssetId, AssetBalance> {
 }
 
 impl<T: Config> Module<T> {
+    /// Checks if tokens of the project are offered by an inactive or active crowdfunding
+    pub(super) fn has_open_crowdfunding(project_id: &ProjectId) -> bool {
+        InvestmentIdByProjectId::iter_prefix(project_id)
+            .filter_map(|(id, _)| SimpleCrowdfundingMap::<T>::try_get(id).ok())
+            .any(|sale| matches!(sale.status, Status::Inactive | Status::Active))
+    }
+
     pub(super) fn create_investment_opportunity_impl(
         account: AccountIdOf<T>,
         external_id: Id,
@@ -179,6 +186,11 @@ impl<T: Config> Module<T> {
             ..Default::default()
         };
 
+        for (asset_id, _) in &new_token_sale.shares {
+            if let Some(project_id) = T::AssetSystem::try_get_tokenized_project(asset_id) {
+                InvestmentIdByProjectId::insert(project_id, external_id, ());
+            }
+        }
         SimpleCrowdfundingMap::<T>::insert(external_id, new_token_sale.clone());
 
         Self::deposit_event(RawEvent::SimpleCrowdfundingCreated(external_id));
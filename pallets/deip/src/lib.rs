@@ -21,17 +21,33 @@
 //! ### Dispatchable Functions
 //!
 //! * `add_domain` - Add cryptographic hash of DomainId
+//! * [`update_domain`](./enum.Call.html#variant.update_domain)
+//! * [`deprecate_domain`](./enum.Call.html#variant.deprecate_domain)
+//! * [`merge_domain`](./enum.Call.html#variant.merge_domain)
 //! * `create_project` - Create Project belongs to Account (Team)
 //! * [`create_investment_opportunity`](./enum.Call.html#variant.create_investment_opportunity)
 //! * [`invest`](./enum.Call.html#variant.invest)
 //! * `update_project` - Update Project info
+//! * [`add_project_domain`](./enum.Call.html#variant.add_project_domain)
+//! * [`remove_project_domain`](./enum.Call.html#variant.remove_project_domain)
+//! * [`archive_project`](./enum.Call.html#variant.archive_project)
+//! * [`unarchive_project`](./enum.Call.html#variant.unarchive_project)
+//! * [`remove_project`](./enum.Call.html#variant.remove_project)
+//! * [`handover_project`](./enum.Call.html#variant.handover_project)
+//! * [`accept_project_handover`](./enum.Call.html#variant.accept_project_handover)
+//! * [`cancel_project_handover`](./enum.Call.html#variant.cancel_project_handover)
 //! * `create_project_content` - Create Project Content (Digital Asset)
+//! * [`amend_project_content`](./enum.Call.html#variant.amend_project_content)
+//! * [`confirm_project_content_authorship`](./enum.Call.html#variant.confirm_project_content_authorship)
+//! * [`decline_project_content_authorship`](./enum.Call.html#variant.decline_project_content_authorship)
 //! * `create_project_nda` - Create NDA contract between sides
 //! * `create_nda_content_access_request` - Some side request access to the data of contract
 //! * `fulfill_nda_content_access_request` - Granter fulfill access request to the data
 //! * `reject_nda_content_access_request` - Granter reject access request to the data
 //! * [`create_review`](./enum.Call.html#variant.create_review)
 //! * [`upvote_review`](./enum.Call.html#variant.upvote_review)
+//! * [`add_review_domain`](./enum.Call.html#variant.add_review_domain)
+//! * [`remove_review_domain`](./enum.Call.html#variant.remove_review_domain)
 //! * [`create_contract_agreement`](./enum.Call.html#variant.create_contract_agreement)
 //! * [`accept_contract_agreement`](./enum.Call.html#variant.accept_contract_agreement)
 //! * [`reject_contract_agreement`](./enum.Call.html#variant.reject_contract_agreement)
@@ -40,6 +56,7 @@
 //! [`Config`]: ./trait.Config.html
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit="256"]
 
 use sp_runtime::traits::ValidateUnsigned;
 use frame_support::{
@@ -48,7 +65,7 @@ use frame_support::{
     StorageMap,
     dispatch::{ DispatchResult, Parameter },
     storage::{ IterableStorageMap, IterableStorageDoubleMap },
-    traits::{Currency, ReservableCurrency},
+    traits::{Currency, ReservableCurrency, EnsureOrigin},
     debug::debug,
     pallet_prelude::*,
 };
@@ -56,6 +73,7 @@ use frame_system::{ self as system, ensure_signed, ensure_none,
     offchain::{SubmitTransaction, SendTransactionTypes}
 };
 use sp_std::vec::Vec;
+use sp_std::convert::TryInto;
 use sp_runtime::{ RuntimeDebug, traits::Member };
 pub use sp_core::{ H160, H256 };
 #[cfg(feature = "std")]
@@ -100,6 +118,20 @@ pub use contract::{
 
 pub mod traits;
 
+mod project;
+
+mod domain;
+
+mod content;
+pub use content::{
+    Status as ProjectContentStatus,
+    PendingAuthors as ProjectContentPendingAuthors,
+};
+pub use domain::Status as DomainStatus;
+
+mod migration;
+use migration::Releases;
+
 /// A maximum number of Domains. When domains reaches this number, no new domains can be added.
 pub const MAX_DOMAINS: u32 = 100;
 
@@ -142,11 +174,17 @@ pub trait Config: frame_system::Config + pallet_timestamp::Config + SendTransact
     /// The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
     
-    type DeipAccountId: Into<Self::AccountId> + Parameter + Member;
+    type DeipAccountId: TryInto<Self::AccountId> + Parameter + Member;
 
     type Currency: ReservableCurrency<Self::AccountId>;
 
     type AssetSystem: traits::DeipAssetSystem<Self::AccountId>;
+
+    /// Origin allowed to add, update, deprecate and merge domains
+    type DomainOrigin: EnsureOrigin<Self::Origin>;
+
+    /// Period for the listed authors to confirm the project content authorship
+    type ContentAuthorsConfirmationPeriod: Get<Self::Moment>;
 }
 
 /// Unique Project ID reference
@@ -168,6 +206,7 @@ pub type ReviewOf<T> = Review<HashOf<T>, AccountIdOf<T>>;
 pub type NdaOf<T> = Nda<HashOf<T>, AccountIdOf<T>, MomentOf<T>>;
 pub type NdaAccessRequestOf<T> = NdaAccessRequest<HashOf<T>, AccountIdOf<T>>;
 pub type ProjectContentOf<T> = ProjectContent<HashOf<T>, AccountIdOf<T>>;
+pub type ProjectContentPendingAuthorsOf<T> = ProjectContentPendingAuthors<AccountIdOf<T>, MomentOf<T>>;
 pub type SimpleCrowdfundingOf<T> = SimpleCrowdfunding<MomentOf<T>, DeipAssetIdOf<T>, DeipAssetBalanceOf<T>>;
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 pub type InvestmentOf<T> = Investment<AccountIdOf<T>, DeipAssetBalanceOf<T>, MomentOf<T>>;
@@ -183,6 +222,11 @@ type DeipReviewVoteOf<T> = DeipReviewVote<AccountIdOf<T>, MomentOf<T>>;
 pub struct Domain {
     /// Reference for external world and uniques control 
     pub external_id: DomainId,
+    /// Parent domain (e.g. discipline of sub-discipline), top-level domain has no parent
+    pub parent: Option<DomainId>,
+    /// Hash of the domain metadata
+    pub metadata: Option<H256>,
+    pub status: DomainStatus,
 }
 
 /// Core entity of pallet. Everything connected to Project. 
@@ -193,6 +237,8 @@ pub struct Domain {
 pub struct Project<Hash, AccountId> {
     /// Determine visible project or not 
     is_private: bool,
+    /// Archived project is read-only: it can't be updated, get new content or licenses
+    is_archived: bool,
     /// Reference for external world and uniques control 
     external_id: ProjectId,
     /// Reference to the Team 
@@ -223,8 +269,17 @@ pub struct ProjectContent<Hash, AccountId> {
     /// Authors of Digital asset
     authors: Vec<AccountId>,
     /// List of References to other digital assets whith will be used in current digital asset.
-    references: Option<Vec<ProjectContentId>>
-    
+    references: Option<Vec<ProjectContentId>>,
+    /// Reference to the revision this one amends
+    previous_version: Option<ProjectContentId>,
+    /// Revision number, the original content is version 1
+    version: u32,
+    /// Hash of the amendment reason
+    amendment_reason: Option<Hash>,
+    /// Only the latest revision can be amended
+    is_latest: bool,
+    /// Content can't be reviewed or amended until the authors are final
+    status: ProjectContentStatus,
 }
 
 /// NDA contract between parties. Usually about dislocating or not dislocating some confidential info
@@ -301,11 +356,33 @@ decl_event! {
         ProjectRemoved(AccountId, Project),
         /// Event emitted when a project is removed by the owner. [BelongsTo, ProjectId]
         ProjectUpdated(AccountId, ProjectId),
+        /// Event emitted when a project has been archived. [BelongsTo, ProjectId]
+        ProjectArchived(AccountId, ProjectId),
+        /// Event emitted when a project has been unarchived. [BelongsTo, ProjectId]
+        ProjectUnarchived(AccountId, ProjectId),
+        /// Event emitted when a project handover has been proposed. [BelongsTo, ProjectId, Recipient]
+        ProjectHandoverProposed(AccountId, ProjectId, AccountId),
+        /// Event emitted when a project handover has been cancelled or declined. [Canceller, ProjectId]
+        ProjectHandoverCancelled(AccountId, ProjectId),
+        /// Event emitted when a project has been handed over to another team. [PreviousTeam, ProjectId, Team]
+        ProjectHandedOver(AccountId, ProjectId, AccountId),
+        /// Event emitted when a domain has been added to a project. [BelongsTo, ProjectId, DomainId]
+        ProjectDomainAdded(AccountId, ProjectId, DomainId),
+        /// Event emitted when a domain has been removed from a project. [BelongsTo, ProjectId, DomainId]
+        ProjectDomainRemoved(AccountId, ProjectId, DomainId),
 
         // ==== Project Content ====
        
         /// Event emitted when a project contnet has been created. [BelongsTo, ProjectContentId]
         ProjectContnetCreated(AccountId, ProjectContentId),
+        /// Event emitted when a project content has been amended. [BelongsTo, ProjectContentId, PreviousVersion]
+        ProjectContentAmended(AccountId, ProjectContentId, ProjectContentId),
+        /// Event emitted when an author has confirmed the project content authorship. [Author, ProjectContentId]
+        ProjectContentAuthorConfirmed(AccountId, ProjectContentId),
+        /// Event emitted when an author has declined the project content authorship. [Author, ProjectContentId]
+        ProjectContentAuthorDeclined(AccountId, ProjectContentId),
+        /// Event emitted when the project content authors became final. [ProjectContentId]
+        ProjectContentFinalized(ProjectContentId),
 
         // ==== NDA ====
        
@@ -318,13 +395,23 @@ decl_event! {
         //  /// Event emitted when a NDA Access request has been rejected. [BelongsTo, NdaAccessRequestId]
         NdaAccessRequestRejected(AccountId, NdaAccessRequestId),
 
-        /// Added a domain. [Creator, DomainId]
-        DomainAdded(AccountId, DomainId),
+        /// Added a domain. [DomainId]
+        DomainAdded(DomainId),
+        /// Domain metadata has been updated. [DomainId]
+        DomainUpdated(DomainId),
+        /// Domain has been deprecated. [DomainId]
+        DomainDeprecated(DomainId),
+        /// Domain has been merged into another one. [DomainId, Into]
+        DomainMerged(DomainId, DomainId),
 
         /// Event emitted when a review has been created. [BelongsTo, Review]
         ReviewCreated(AccountId, Review),
         /// Emitted when a DAO votes for a review
         ReviewUpvoted(ReviewId, AccountId, DomainId),
+        /// Event emitted when a domain has been added to a review. [Author, ReviewId, DomainId]
+        ReviewDomainAdded(AccountId, ReviewId, DomainId),
+        /// Event emitted when a domain has been removed from a review. [Author, ReviewId, DomainId]
+        ReviewDomainRemoved(AccountId, ReviewId, DomainId),
 
         /// Event emitted when a simple crowd funding has been created.
         SimpleCrowdfundingCreated(InvestmentId),
@@ -357,6 +444,28 @@ decl_error! {
         DomainNotExists,
         /// Cannot add a project because a project with this ID is already a exists
         ProjectAlreadyExists,
+        /// The project is archived and can't be changed
+        ProjectArchived,
+        /// The project is not archived
+        ProjectNotArchived,
+        /// The project has content, so it can be archived but not removed
+        ProjectHasContent,
+        /// The project has tokenized assets, so it can be archived but not removed or handed over
+        ProjectHasTokenizedAssets,
+        /// The project has inactive or active crowdfunding of it's tokens
+        ProjectHasOpenCrowdfunding,
+        /// The project has a license which is not signed yet or not expired
+        ProjectHasActiveLicense,
+        /// The project has a license which is not signed by both parties yet
+        ProjectHasPendingLicense,
+        /// The project already belongs to the team
+        ProjectHandoverToSameTeam,
+        /// The project handover does not exist or proposed to another account
+        NoSuchProjectHandover,
+        /// The project already matches the domain
+        ProjectDomainAlreadyAdded,
+        /// The project doesn't match the domain
+        ProjectDomainNotFound,
 
         // ==== Project Content ====
        
@@ -370,6 +479,14 @@ decl_error! {
         NoSuchReference, 
         /// Cannot add a project content because a project with this ID is already a finished
         ProjectAlreadyFinished,
+        /// Only the latest revision of the project content can be amended
+        ProjectContentNotLatestVersion,
+        /// The project content is waiting for the authors confirmation
+        ProjectContentNotFinalized,
+        /// The account isn't a pending author of the project content
+        ProjectContentNoSuchPendingAuthor,
+        /// The project content authors confirmation period isn't over yet
+        ProjectContentAuthorsConfirmationNotExpired,
 
 
         // ==== Domains ====
@@ -378,6 +495,10 @@ decl_error! {
         DomianLimitReached,
         /// Cannot add domain because this domain is already a exists
         DomainAlreadyExists,
+        /// The domain is deprecated or merged into another domain
+        DomainNotActive,
+        /// Domain can't be merged into itself or it's subdomain
+        DomainMergeIntoSubtree,
 
         // ==== NDA ====
         
@@ -410,6 +531,12 @@ decl_error! {
         ReviewVoteNoSuchReview,
         ReviewVoteUnrelatedDomain,
         ReviewAlreadyVotedWithDomain,
+        /// The review does not exist
+        NoSuchReview,
+        /// The domain is already added to the review
+        ReviewDomainAlreadyAdded,
+        /// The review doesn't match the domain
+        ReviewDomainNotFound,
 
         // ==== General =====
 
@@ -460,27 +587,47 @@ decl_error! {
         ContractAgreementPartyIsNotListed,
         ContractAgreementAlreadyAcceptedByParty,
         ContractAgreementRejected,
+
+        /// Account can't be resolved (e.g. DAO is dissolved)
+        DeipAccountIdInvalid,
     }
 }
 
 decl_storage! {
     trait Store for Module<T: Config> as Deip {
+        /// Storage layout version used to run migrations once
+        StorageVersion build(|_| Releases::V2): Releases;
+
         ProjectMap: map hasher(identity) ProjectId => ProjectOf<T>;
 
         ProjectIdByTeamId: double_map hasher(blake2_128_concat) AccountIdOf<T>, hasher(identity) ProjectId => ();
+        ProjectIdByDomainId: double_map hasher(blake2_128_concat) DomainId, hasher(identity) ProjectId => ();
+
+        /// Pending project handovers to the recipient team
+        ProjectHandoverMap get(fn project_handover): map hasher(identity) ProjectId => Option<AccountIdOf<T>>;
 
         SimpleCrowdfundingMap: map hasher(identity) InvestmentId => SimpleCrowdfundingOf<T>;
+        /// Crowdfundings offering tokens of the project
+        InvestmentIdByProjectId: double_map hasher(identity) ProjectId, hasher(identity) InvestmentId => ();
 
         /// Contains various contributions from DAOs
         InvestmentMap: map hasher(identity) InvestmentId => Vec<(T::AccountId, InvestmentOf<T>)>;
 
         ProjectContentMap: map hasher(identity) ProjectContentId => ProjectContentOf<T>;
         ContentIdByProjectId: double_map hasher(identity) ProjectId, hasher(identity) ProjectContentId => ();
+        /// Next revision of the project content
+        NextContentVersion get(fn next_content_version): map hasher(identity) ProjectContentId => Option<ProjectContentId>;
+        /// Authors confirmation of the project content which isn't finalized yet
+        PendingContentAuthors get(fn pending_content_authors): map hasher(identity) ProjectContentId => Option<ProjectContentPendingAuthorsOf<T>>;
+        /// Project content by the authors who confirmed the authorship
+        ContentIdByAuthorId: double_map hasher(blake2_128_concat) AccountIdOf<T>, hasher(identity) ProjectContentId => ();
 
         /// NDA list, guarantees uniquest and provides NDA listing
         Ndas get(fn nda_list): Vec<(ProjectId, T::AccountId)>;
         /// Map to NDA Info
         NdaMap get(fn nda): map hasher(identity) NdaId => NdaOf<T>;
+        /// NDAs involving the project
+        NdaIdByProjectId: double_map hasher(identity) ProjectId, hasher(identity) NdaId => ();
         
         /// NDA Access Requests list, guarantees uniquest and provides NDA Access Requests listing
         NdaAccessRequests get(fn nda_requests): Vec<(NdaAccessRequestId, NdaId, T::AccountId)>;
@@ -492,6 +639,7 @@ decl_storage! {
         ReviewIdByProjectId: double_map hasher(identity) ProjectId, hasher(identity) ReviewId => ();
         ReviewIdByContentId: double_map hasher(identity) ProjectContentId, hasher(identity) ReviewId => ();
         ReviewIdByAccountId: double_map hasher(blake2_128_concat) AccountIdOf<T>, hasher(identity) ReviewId => ();
+        ReviewIdByDomainId: double_map hasher(blake2_128_concat) DomainId, hasher(identity) ReviewId => ();
 
         ReviewVoteMap: map hasher(blake2_128_concat) (ReviewId, AccountIdOf<T>, DomainId) => DeipReviewVoteOf<T>;
 
@@ -503,9 +651,21 @@ decl_storage! {
         // The total number of domains stored in the map.
         // Because the map does not store its size, we must store it separately
         DomainCount get(fn domain_count) config(): u32;
+        DomainIdByParentId: double_map hasher(blake2_128_concat) DomainId, hasher(blake2_128_concat) DomainId => ();
 
         ContractAgreementMap: map hasher(blake2_128_concat) ContractAgreementId => ContractAgreementOf<T>;
         ContractAgreementIdByType: double_map hasher(twox_64_concat) ContractAgreementIndexTerms, hasher(blake2_128_concat) ContractAgreementId => ();
+        /// License agreements of the project
+        LicenseIdByProjectId: double_map hasher(identity) ProjectId, hasher(blake2_128_concat) ContractAgreementId => ();
+    }
+    add_extra_genesis {
+        build(|config| {
+            for (id, domain) in &config.domains {
+                if let Some(parent) = domain.parent {
+                    DomainIdByParentId::insert(parent, id, ());
+                }
+            }
+        })
     }
 }
 
@@ -540,8 +700,9 @@ decl_module! {
 
             let project = ProjectOf::<T> {
                 is_private,
+                is_archived: false,
                 external_id,
-                team_id: team_id.into(),
+                team_id: Self::deip_account(team_id)?,
                 description,
                 domains
             };
@@ -549,13 +710,16 @@ decl_module! {
             ensure!(account == project.team_id, Error::<T>::NoPermission);
 
             for domain in &project.domains {
-                ensure!(Domains::contains_key(&domain), Error::<T>::DomainNotExists);
+                Self::ensure_active_domain(domain)?;
             }
 
             ensure!(!ProjectMap::<T>::contains_key(project.external_id), Error::<T>::ProjectAlreadyExists);
 
             ProjectMap::<T>::insert(project.external_id, project.clone());
             ProjectIdByTeamId::<T>::insert(project.team_id.clone(), project.external_id, ());
+            for domain in &project.domains {
+                ProjectIdByDomainId::insert(domain, project.external_id, ());
+            }
 
             Self::deposit_event(RawEvent::ProjectCreated(account, project));
         }
@@ -576,7 +740,7 @@ decl_module! {
             funding_model: FundingModelOf<T>,
         ) -> DispatchResult {
             let account = ensure_signed(origin)?;
-            Self::create_investment_opportunity_impl(account, external_id, creator.into(), shares, funding_model)
+            Self::create_investment_opportunity_impl(account, external_id, Self::deip_account(creator)?, shares, funding_model)
         }
 
         #[weight = 10_000]
@@ -631,6 +795,7 @@ decl_module! {
                 let project = maybe_project.as_mut().ok_or(Error::<T>::NoSuchProject)?;
 
                 ensure!(project.team_id == account, Error::<T>::NoPermission);
+                ensure!(!project.is_archived, Error::<T>::ProjectArchived);
 
                 // TODO make sure that we don't lose first 2 bytes of the hash
                 if let Some(value) = description  {
@@ -650,6 +815,101 @@ decl_module! {
             Ok(())
         }
 
+        /// Allow the project team to add a domain to the project.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: identifier of the project
+        /// - `domain`: identifier of the existing domain the project matches
+        #[weight = 10_000]
+        fn add_project_domain(origin, project_id: ProjectId, domain: DomainId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::add_project_domain_impl(account, project_id, domain)
+        }
+
+        /// Allow the project team to remove a domain from the project.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: identifier of the project
+        /// - `domain`: identifier of the domain the project no longer matches
+        #[weight = 10_000]
+        fn remove_project_domain(origin, project_id: ProjectId, domain: DomainId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::remove_project_domain_impl(account, project_id, domain)
+        }
+
+        /// Allow the project team to archive the project.
+        /// Archived project can't be updated, get new content or licenses.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: identifier of the project to be archived
+        #[weight = 10_000]
+        fn archive_project(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::archive_project_impl(account, project_id, true)
+        }
+
+        /// Allow the project team to unarchive the project.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: identifier of the project to be unarchived
+        #[weight = 10_000]
+        fn unarchive_project(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::archive_project_impl(account, project_id, false)
+        }
+
+        /// Allow the project team to remove the project.
+        /// Project with content, tokenized assets, open crowdfundings or active licenses
+        /// can't be removed (it may be archived instead). NDAs stop referring to the removed project.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: identifier of the project to be removed
+        #[weight = 10_000]
+        fn remove_project(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::remove_project_impl(account, project_id)
+        }
+
+        /// Allow the project team to propose handover of the project to another team.
+        /// Replaces the pending handover if any.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: identifier of the project to be handed over
+        /// - `to`: the team which should accept the handover
+        #[weight = 10_000]
+        fn handover_project(origin, project_id: ProjectId, to: T::DeipAccountId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::handover_project_impl(account, project_id, Self::deip_account(to)?)
+        }
+
+        /// Allow the recipient of the project handover to accept it and become the project team.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: identifier of the project to be accepted
+        #[weight = 10_000]
+        fn accept_project_handover(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::accept_project_handover_impl(account, project_id)
+        }
+
+        /// Allow the project team to cancel or the recipient to decline the project handover.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: identifier of the project
+        #[weight = 10_000]
+        fn cancel_project_handover(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::cancel_project_handover_impl(account, project_id)
+        }
+
         /// Allow a user to create project content.
         ///
         /// The origin for this call must be _Signed_. 
@@ -671,12 +931,17 @@ decl_module! {
             let content = ProjectContentOf::<T> {
                 external_id,
                 project_external_id,
-                team_id: team_id.into(),
+                team_id: Self::deip_account(team_id)?,
                 content_type,
                 description,
                 content,
-                authors: authors.into_iter().map(Into::into).collect(),
-                references
+                authors: Self::deip_accounts(authors)?,
+                references,
+                previous_version: None,
+                version: 1,
+                amendment_reason: None,
+                is_latest: true,
+                status: ProjectContentStatus::PendingAuthors,
             };
 
             ensure!(!ProjectContentMap::<T>::contains_key(&content.external_id), Error::<T>::ProjectContentAlreadyExists);
@@ -686,14 +951,12 @@ decl_module! {
             ensure!(!project.external_id.is_zero(), Error::<T>::NoSuchProject);
             ensure!(project.team_id == content.team_id, Error::<T>::ProjectNotBelongToTeam);
             ensure!(!Self::is_project_finished(&project.external_id), Error::<T>::ProjectAlreadyFinished);
+            ensure!(!project.is_archived, Error::<T>::ProjectArchived);
 
-            if let Some(references) = &content.references {
-                let is_all_references_exists = references
-                    .iter()
-                    .all(|&reference| ProjectContentMap::<T>::contains_key(reference));
+            Self::ensure_references_exist(&content.references)?;
 
-                ensure!(is_all_references_exists, Error::<T>::NoSuchReference);
-            }
+            let mut content = content;
+            Self::request_authors_confirmation(&mut content, &account);
 
             ProjectContentMap::<T>::insert(content.external_id, content.clone());
             ContentIdByProjectId::insert(content.project_external_id, content.external_id, ());
@@ -701,6 +964,76 @@ decl_module! {
             Self::deposit_event(RawEvent::ProjectContnetCreated(account, content.external_id));
         }
 
+        /// Allow the project team to publish a revision of the project content.
+        /// Reviews stay attached to the revision they were created for.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `external_id`: identifier of the new revision
+        /// - `previous_version`: identifier of the latest revision of the content
+        /// - `description`: hash of the revision description
+        /// - `content`: hash of the revised digital asset
+        /// - `authors`: authors of the revision
+        /// - `references`: references to other digital assets
+        /// - `reason`: hash of the amendment reason
+        #[weight = 10_000]
+        fn amend_project_content(origin,
+            external_id: ProjectContentId,
+            previous_version: ProjectContentId,
+            description: T::Hash,
+            content: T::Hash,
+            authors: Vec<T::DeipAccountId>,
+            references: Option<Vec<ProjectContentId>>,
+            reason: T::Hash,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::amend_project_content_impl(
+                account,
+                external_id,
+                previous_version,
+                description,
+                content,
+                Self::deip_accounts(authors)?,
+                references,
+                reason,
+            )
+        }
+
+        /// Allow a listed author to confirm the project content authorship.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `content_id`: identifier of the project content waiting for the authors confirmation
+        #[weight = 10_000]
+        fn confirm_project_content_authorship(origin, content_id: ProjectContentId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::decide_project_content_authorship_impl(account, content_id, true)
+        }
+
+        /// Allow a listed author to decline the project content authorship.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `content_id`: identifier of the project content waiting for the authors confirmation
+        #[weight = 10_000]
+        fn decline_project_content_authorship(origin, content_id: ProjectContentId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::decide_project_content_authorship_impl(account, content_id, false)
+        }
+
+        /// Finalize the project content authors after the confirmation period.
+        /// Authors that didn't respond are dropped.
+        ///
+        /// The origin for this call must be _None_. It's submitted by the offchain worker
+        /// once the confirmation deadline is passed.
+        ///
+        /// - `content_id`: identifier of the project content waiting for the authors confirmation
+        #[weight = 10_000]
+        fn finalize_project_content(origin, content_id: ProjectContentId) -> DispatchResult {
+            ensure_none(origin)?;
+            Self::finalize_project_content_impl(content_id)
+        }
+
         /// Allow a user to create [NDA](./struct.Nda.html).
         ///
         /// The origin for this call must be _Signed_. 
@@ -731,7 +1064,7 @@ decl_module! {
                 ensure!(end_date > start_date, Error::<T>::NdaStartDateMustBeLessThanEndDate);
             }
             
-            let parties: Vec<T::AccountId> = parties.into_iter().map(Into::into).collect();
+            let parties: Vec<T::AccountId> = Self::deip_accounts(parties)?;
             
             projects.iter()
                 .try_for_each(|id| -> DispatchResult {
@@ -762,6 +1095,9 @@ decl_module! {
             nda_list.insert(index_to_insert_nda, (nda.external_id, contract_creator.clone()));
             Ndas::<T>::put(nda_list);
 
+            for project_id in &nda.projects {
+                NdaIdByProjectId::insert(project_id, nda.external_id, ());
+            }
             NdaMap::<T>::insert(nda.external_id, nda);
 
             // Emit an event that the NDA was created.
@@ -916,32 +1252,80 @@ decl_module! {
             Self::upvote_review_impl(account, review_id, domain_id)
         }
 
-        /// Allow a user to create domains.
+        /// Allow the review author to add a domain to the review.
         ///
-        /// The origin for this call must be _Signed_. 
+        /// The origin for this call must be _Signed_.
         ///
-        /// - `project`: [Domain](./struct.Domain.html) to be created.
+        /// - `review_id`: identifier of the review
+        /// - `domain`: identifier of the active domain the review matches
         #[weight = 10_000]
-        fn add_domain(origin, domain: Domain) {
+        fn add_review_domain(origin, review_id: ReviewId, domain: DomainId) -> DispatchResult {
             let account = ensure_signed(origin)?;
-        
-            let domain_count = DomainCount::get();
-            ensure!(domain_count < MAX_DOMAINS, Error::<T>::DomianLimitReached);
+            Self::add_review_domain_impl(account, review_id, domain)
+        }
 
-            let external_id = domain.external_id;
-        
-            // We don't want to add duplicate domains, so we check whether the potential new
-            // domain is already present in the list. Because the domains is stored as a hash
-            // map this check is constant time O(1)
-            ensure!(!Domains::contains_key(&external_id), Error::<T>::DomainAlreadyExists);
+        /// Allow the review author to remove a domain from the review.
+        /// The review must keep at least one domain.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `review_id`: identifier of the review
+        /// - `domain`: identifier of the domain the review no longer matches
+        #[weight = 10_000]
+        fn remove_review_domain(origin, review_id: ReviewId, domain: DomainId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::remove_review_domain_impl(account, review_id, domain)
+        }
 
-           
-            
-            // Insert the new domin and emit the event
-            Domains::insert(&external_id, domain);
-            DomainCount::put(domain_count + 1); // overflow check not necessary because of maximum
-            
-            Self::deposit_event(RawEvent::DomainAdded(account, external_id));
+        /// Allow the domain curators to create domains.
+        ///
+        /// The origin for this call must satisfy `DomainOrigin`.
+        ///
+        /// - `domain`: active [Domain](./struct.Domain.html) to be created. The parent domain
+        ///     if specified must be active
+        #[weight = 10_000]
+        fn add_domain(origin, domain: Domain) -> DispatchResult {
+            T::DomainOrigin::ensure_origin(origin)?;
+            Self::add_domain_impl(domain)
+        }
+
+        /// Allow the domain curators to update the domain metadata.
+        ///
+        /// The origin for this call must satisfy `DomainOrigin`.
+        ///
+        /// - `domain_id`: identifier of the domain
+        /// - `metadata`: hash of the new metadata
+        #[weight = 10_000]
+        fn update_domain(origin, domain_id: DomainId, metadata: Option<H256>) -> DispatchResult {
+            T::DomainOrigin::ensure_origin(origin)?;
+            Self::update_domain_impl(domain_id, metadata)
+        }
+
+        /// Allow the domain curators to deprecate the domain.
+        /// Deprecated domain can't be assigned to new projects, reviews and subdomains.
+        ///
+        /// The origin for this call must satisfy `DomainOrigin`.
+        ///
+        /// - `domain_id`: identifier of the active domain
+        #[weight = 10_000]
+        fn deprecate_domain(origin, domain_id: DomainId) -> DispatchResult {
+            T::DomainOrigin::ensure_origin(origin)?;
+            Self::deprecate_domain_impl(domain_id)
+        }
+
+        /// Allow the domain curators to merge the domain into another one.
+        /// The merged domain becomes a subdomain of `into`, so projects and reviews
+        /// of the merged domain are listed with descendants of `into`.
+        /// Merged domain can't be assigned to new projects, reviews and subdomains.
+        ///
+        /// The origin for this call must satisfy `DomainOrigin`.
+        ///
+        /// - `domain_id`: identifier of the active or deprecated domain
+        /// - `into`: identifier of the active domain outside of the `domain_id` subtree
+        #[weight = 10_000]
+        fn merge_domain(origin, domain_id: DomainId, into: DomainId) -> DispatchResult {
+            T::DomainOrigin::ensure_origin(origin)?;
+            Self::merge_domain_impl(domain_id, into)
         }
 
         /// Allows DAO to create a contract agreement between parties.
@@ -965,7 +1349,7 @@ decl_module! {
             terms: ContractAgreementTermsOf<T>,
         ) -> DispatchResult {
             let account = ensure_signed(origin)?;
-            Self::create_contract_agreement_impl(account, id, creator.into(), parties, hash, activation_time, expiration_time, terms)
+            Self::create_contract_agreement_impl(account, id, Self::deip_account(creator)?, parties, hash, activation_time, expiration_time, terms)
         }
 
         /// Allows a party to sign the contract agreement created earlier.
@@ -979,7 +1363,7 @@ decl_module! {
             party: T::DeipAccountId,
         ) -> DispatchResult {
             let account = ensure_signed(origin)?;
-            Self::accept_contract_agreement_impl(account, id, party.into())
+            Self::accept_contract_agreement_impl(account, id, Self::deip_account(party)?)
         }
 
         /// Allows a party to reject the contract agreement created earlier.
@@ -995,7 +1379,11 @@ decl_module! {
             party: T::DeipAccountId,
         ) -> DispatchResult {
             let account = ensure_signed(origin)?;
-            Self::reject_contract_agreement_impl(account, id, party.into())
+            Self::reject_contract_agreement_impl(account, id, Self::deip_account(party)?)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            Self::migrate_to_v2()
         }
 
         fn offchain_worker(_n: T::BlockNumber) {
@@ -1004,6 +1392,7 @@ decl_module! {
             }
 
             Self::process_investment_opportunities_offchain();
+            Self::process_pending_content_offchain();
         }
     }
 }
@@ -1064,12 +1453,32 @@ impl<T: Config> ValidateUnsigned for Module<T> {
                     .and_provides((b"finish_crowdfunding", *id))
                     .build()
             },
+            Call::finalize_project_content(ref id) => {
+                let authors = PendingContentAuthors::<T>::get(id).ok_or(InvalidTransaction::Stale)?;
+                if authors.deadline > pallet_timestamp::Module::<T>::get() {
+                    return InvalidTransaction::Stale.into();
+                }
+
+                ValidTransaction::with_tag_prefix("DeipOffchainWorker")
+                    .propagate(false)
+                    .longevity(5)
+                    .and_provides((b"finalize_project_content", *id))
+                    .build()
+            },
             _ => InvalidTransaction::Call.into(),
         }
     }
 }
 
 impl<T: Config> Module<T> {
+    fn deip_account(who: T::DeipAccountId) -> Result<T::AccountId, Error<T>> {
+        who.try_into().map_err(|_| Error::<T>::DeipAccountIdInvalid)
+    }
+
+    fn deip_accounts(accounts: Vec<T::DeipAccountId>) -> Result<Vec<T::AccountId>, Error<T>> {
+        accounts.into_iter().map(Self::deip_account).collect()
+    }
+
     fn is_project_finished(project_id: &ProjectId) -> bool {
         ContentIdByProjectId::iter_prefix(project_id)
             .map(|(k, _)| ProjectContentMap::<T>::get(k))
@@ -0,0 +1,119 @@
+use crate::*;
+
+/// Storage layout versions
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq)]
+pub enum Releases {
+    /// Flat domains, project without archiving,
+    /// project content without revisions and authors confirmation
+    #[default]
+    V1,
+    /// Hierarchical domains with lifecycle, project with archiving,
+    /// project content with revisions and authors confirmation
+    V2,
+}
+
+/// Domain of the [`Releases::V1`] storage layout
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub(crate) struct DomainV1 {
+    pub(crate) external_id: DomainId,
+}
+
+impl From<DomainV1> for Domain {
+    fn from(x: DomainV1) -> Self {
+        Self { external_id: x.external_id, parent: None, metadata: None, status: DomainStatus::Active }
+    }
+}
+
+/// Project of the [`Releases::V1`] storage layout
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub(crate) struct ProjectV1<Hash, AccountId> {
+    pub(crate) is_private: bool,
+    pub(crate) external_id: ProjectId,
+    pub(crate) team_id: AccountId,
+    pub(crate) description: Hash,
+    pub(crate) domains: Vec<DomainId>,
+}
+
+impl<Hash, AccountId> From<ProjectV1<Hash, AccountId>> for Project<Hash, AccountId> {
+    fn from(x: ProjectV1<Hash, AccountId>) -> Self {
+        let ProjectV1 { is_private, external_id, team_id, description, domains } = x;
+        Self { is_private, is_archived: false, external_id, team_id, description, domains }
+    }
+}
+
+/// Project content of the [`Releases::V1`] storage layout
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub(crate) struct ProjectContentV1<Hash, AccountId> {
+    pub(crate) external_id: ProjectContentId,
+    pub(crate) project_external_id: ProjectId,
+    pub(crate) team_id: AccountId,
+    pub(crate) content_type: ProjectContentType,
+    pub(crate) description: Hash,
+    pub(crate) content: Hash,
+    pub(crate) authors: Vec<AccountId>,
+    pub(crate) references: Option<Vec<ProjectContentId>>,
+}
+
+impl<Hash, AccountId> From<ProjectContentV1<Hash, AccountId>> for ProjectContent<Hash, AccountId> {
+    fn from(x: ProjectContentV1<Hash, AccountId>) -> Self {
+        let ProjectContentV1 {
+            external_id,
+            project_external_id,
+            team_id,
+            content_type,
+            description,
+            content,
+            authors,
+            references,
+        } = x;
+        Self {
+            external_id,
+            project_external_id,
+            team_id,
+            content_type,
+            description,
+            content,
+            authors,
+            references,
+            previous_version: None,
+            version: 1,
+            amendment_reason: None,
+            is_latest: true,
+            status: ProjectContentStatus::Finalized,
+        }
+    }
+}
+
+impl<T: Config> Module<T> {
+    /// Migrate stored domains, projects and project content to the [`Releases::V2`] layout.
+    /// Existing domains become active top-level ones, existing projects aren't archived. Existing content becomes the finalized first revision,
+    /// it's authors are indexed as the ones who confirmed the authorship
+    pub(super) fn migrate_to_v2() -> Weight {
+        if StorageVersion::get() != Releases::V1 {
+            return T::DbWeight::get().reads(1);
+        }
+        let mut reads: Weight = 1;
+        let mut writes: Weight = 1;
+        Domains::translate::<DomainV1, _>(|_, old| {
+            reads += 1;
+            writes += 1;
+            Some(old.into())
+        });
+        ProjectMap::<T>::translate::<ProjectV1<T::Hash, AccountIdOf<T>>, _>(|_, old| {
+            reads += 1;
+            writes += 1;
+            Some(old.into())
+        });
+        ProjectContentMap::<T>::translate::<ProjectContentV1<T::Hash, AccountIdOf<T>>, _>(|id, old| {
+            let content: ProjectContentOf<T> = old.into();
+            for author in &content.authors {
+                ContentIdByAuthorId::<T>::insert(author, id, ());
+            }
+            reads += 1;
+            writes += 1 + content.authors.len() as Weight;
+            Some(content)
+        });
+        StorageVersion::put(Releases::V2);
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
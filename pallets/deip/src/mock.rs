@@ -147,11 +147,17 @@ impl pallet_deip::traits::DeipAssetSystem<u64> for Test {
     }
 }
 
+parameter_types! {
+    pub const ContentAuthorsConfirmationPeriod: u64 = 100 * BLOCK_TIME;
+}
+
 impl pallet_deip::Config for Test {
     type Event = Event;
     type DeipAccountId = Self::AccountId;
     type Currency = Balances;
     type AssetSystem = Self;
+    type DomainOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type ContentAuthorsConfirmationPeriod = ContentAuthorsConfirmationPeriod;
 }
 
 parameter_types! {
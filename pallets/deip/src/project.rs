@@ -0,0 +1,164 @@
+use crate::traits::DeipAssetSystem;
+use crate::*;
+
+impl<T: Config> Module<T> {
+    fn team_project(account: &AccountIdOf<T>, project_id: &ProjectId) -> Result<ProjectOf<T>, Error<T>> {
+        let project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+        ensure!(project.team_id == *account, Error::<T>::NoPermission);
+        Ok(project)
+    }
+
+    /// Project may be handed over only if all pending deals are made with the current team
+    fn ensure_can_handover(project_id: &ProjectId) -> DispatchResult {
+        ensure!(!Self::has_open_crowdfunding(project_id), Error::<T>::ProjectHasOpenCrowdfunding);
+        ensure!(!Self::has_pending_license(*project_id), Error::<T>::ProjectHasPendingLicense);
+        // roles of tokenized assets belong to the team and are not handed over with the project
+        ensure!(
+            T::AssetSystem::get_project_nfts(project_id).is_empty(),
+            Error::<T>::ProjectHasTokenizedAssets
+        );
+        Ok(())
+    }
+
+    pub(super) fn add_project_domain_impl(
+        account: AccountIdOf<T>,
+        project_id: ProjectId,
+        domain: DomainId,
+    ) -> DispatchResult {
+        let mut project = Self::team_project(&account, &project_id)?;
+        ensure!(!project.is_archived, Error::<T>::ProjectArchived);
+        Self::ensure_active_domain(&domain)?;
+        ensure!(!project.domains.contains(&domain), Error::<T>::ProjectDomainAlreadyAdded);
+
+        project.domains.push(domain);
+        ProjectMap::<T>::insert(project_id, project);
+        ProjectIdByDomainId::insert(domain, project_id, ());
+
+        Self::deposit_event(RawEvent::ProjectDomainAdded(account, project_id, domain));
+
+        Ok(())
+    }
+
+    pub(super) fn remove_project_domain_impl(
+        account: AccountIdOf<T>,
+        project_id: ProjectId,
+        domain: DomainId,
+    ) -> DispatchResult {
+        let mut project = Self::team_project(&account, &project_id)?;
+        ensure!(!project.is_archived, Error::<T>::ProjectArchived);
+        ensure!(project.domains.contains(&domain), Error::<T>::ProjectDomainNotFound);
+
+        project.domains.retain(|d| *d != domain);
+        ProjectMap::<T>::insert(project_id, project);
+        ProjectIdByDomainId::remove(domain, project_id);
+
+        Self::deposit_event(RawEvent::ProjectDomainRemoved(account, project_id, domain));
+
+        Ok(())
+    }
+
+    pub(super) fn archive_project_impl(
+        account: AccountIdOf<T>,
+        project_id: ProjectId,
+        archive: bool,
+    ) -> DispatchResult {
+        let mut project = Self::team_project(&account, &project_id)?;
+        if archive {
+            ensure!(!project.is_archived, Error::<T>::ProjectArchived);
+        } else {
+            ensure!(project.is_archived, Error::<T>::ProjectNotArchived);
+        }
+
+        project.is_archived = archive;
+        ProjectMap::<T>::insert(project_id, project);
+
+        if archive {
+            Self::deposit_event(RawEvent::ProjectArchived(account, project_id));
+        } else {
+            Self::deposit_event(RawEvent::ProjectUnarchived(account, project_id));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn remove_project_impl(account: AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+        let project = Self::team_project(&account, &project_id)?;
+
+        ensure!(
+            ContentIdByProjectId::iter_prefix(project_id).next().is_none(),
+            Error::<T>::ProjectHasContent
+        );
+        ensure!(
+            T::AssetSystem::get_project_nfts(&project_id).is_empty(),
+            Error::<T>::ProjectHasTokenizedAssets
+        );
+        ensure!(!Self::has_open_crowdfunding(&project_id), Error::<T>::ProjectHasOpenCrowdfunding);
+        ensure!(!Self::has_active_license(project_id), Error::<T>::ProjectHasActiveLicense);
+
+        ProjectMap::<T>::remove(project_id);
+        ProjectIdByTeamId::<T>::remove(&project.team_id, project_id);
+        for domain in &project.domains {
+            ProjectIdByDomainId::remove(domain, project_id);
+        }
+        ProjectHandoverMap::<T>::remove(project_id);
+        // NDAs outlive the project, so they just stop referring to it
+        for (nda_id, _) in NdaIdByProjectId::drain_prefix(project_id) {
+            NdaMap::<T>::mutate(nda_id, |nda| nda.projects.retain(|x| *x != project_id));
+        }
+        InvestmentIdByProjectId::remove_prefix(project_id);
+        LicenseIdByProjectId::remove_prefix(project_id);
+
+        Self::deposit_event(RawEvent::ProjectRemoved(account, project));
+
+        Ok(())
+    }
+
+    pub(super) fn handover_project_impl(
+        account: AccountIdOf<T>,
+        project_id: ProjectId,
+        to: AccountIdOf<T>,
+    ) -> DispatchResult {
+        let project = Self::team_project(&account, &project_id)?;
+        ensure!(project.team_id != to, Error::<T>::ProjectHandoverToSameTeam);
+        Self::ensure_can_handover(&project_id)?;
+
+        ProjectHandoverMap::<T>::insert(project_id, to.clone());
+
+        Self::deposit_event(RawEvent::ProjectHandoverProposed(account, project_id, to));
+
+        Ok(())
+    }
+
+    pub(super) fn accept_project_handover_impl(account: AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+        ensure!(
+            ProjectHandoverMap::<T>::get(project_id).as_ref() == Some(&account),
+            Error::<T>::NoSuchProjectHandover
+        );
+        let mut project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+        // deals could be started after the handover was proposed
+        Self::ensure_can_handover(&project_id)?;
+
+        let previous_team = sp_std::mem::replace(&mut project.team_id, account.clone());
+        ProjectIdByTeamId::<T>::remove(&previous_team, project_id);
+        ProjectIdByTeamId::<T>::insert(&account, project_id, ());
+        ProjectMap::<T>::insert(project_id, project);
+        ProjectHandoverMap::<T>::remove(project_id);
+
+        Self::deposit_event(RawEvent::ProjectHandedOver(previous_team, project_id, account));
+
+        Ok(())
+    }
+
+    pub(super) fn cancel_project_handover_impl(account: AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+        let to = ProjectHandoverMap::<T>::get(project_id).ok_or(Error::<T>::NoSuchProjectHandover)?;
+        if to != account {
+            Self::team_project(&account, &project_id)?;
+        }
+
+        ProjectHandoverMap::<T>::remove(project_id);
+
+        Self::deposit_event(RawEvent::ProjectHandoverCancelled(account, project_id));
+
+        Ok(())
+    }
+}
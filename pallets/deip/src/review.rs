@@ -34,6 +34,12 @@ pub struct Review<Hash, AccountId> {
 }
 
 impl<T: Config> Module<T> {
+    fn author_review(account: &AccountIdOf<T>, review_id: &ReviewId) -> Result<ReviewOf<T>, Error<T>> {
+        let review = ReviewMap::<T>::try_get(review_id).map_err(|_| Error::<T>::NoSuchReview)?;
+        ensure!(review.author == *account, Error::<T>::NoPermission);
+        Ok(review)
+    }
+
     pub(super) fn create_review_impl(
         account: T::AccountId,
         external_id: Id,
@@ -47,12 +53,12 @@ impl<T: Config> Module<T> {
         ensure!(!domains.is_empty(), Error::<T>::ReviewNoDomainSpecified);
 
         for domain in &domains {
-            ensure!(Domains::contains_key(&domain), Error::<T>::DomainNotExists);
+            Self::ensure_active_domain(domain)?;
         }
 
         let review = Review {
             external_id,
-            author: author.into(),
+            author: Self::deip_account(author)?,
             content,
             domains,
             assessment_model,
@@ -64,11 +70,15 @@ impl<T: Config> Module<T> {
 
         let content = ProjectContentMap::<T>::try_get(review.project_content_external_id)
             .map_err(|_| Error::<T>::NoSuchProjectContent)?;
+        ensure!(content.status == ProjectContentStatus::Finalized, Error::<T>::ProjectContentNotFinalized);
 
         ReviewMap::<T>::insert(review.external_id, review.clone());
         ReviewIdByProjectId::insert(content.project_external_id, review.external_id, ());
         ReviewIdByContentId::insert(content.external_id, review.external_id, ());
         ReviewIdByAccountId::<T>::insert(review.author.clone(), review.external_id, ());
+        for domain in &review.domains {
+            ReviewIdByDomainId::insert(domain, review.external_id, ());
+        }
 
         Self::deposit_event(RawEvent::ReviewCreated(account, review));
 
@@ -112,4 +122,40 @@ impl<T: Config> Module<T> {
 
         Ok(())
     }
+
+    pub(super) fn add_review_domain_impl(
+        account: AccountIdOf<T>,
+        review_id: ReviewId,
+        domain: DomainId,
+    ) -> DispatchResult {
+        let mut review = Self::author_review(&account, &review_id)?;
+        Self::ensure_active_domain(&domain)?;
+        ensure!(!review.domains.contains(&domain), Error::<T>::ReviewDomainAlreadyAdded);
+
+        review.domains.push(domain);
+        ReviewMap::<T>::insert(review_id, review);
+        ReviewIdByDomainId::insert(domain, review_id, ());
+
+        Self::deposit_event(RawEvent::ReviewDomainAdded(account, review_id, domain));
+
+        Ok(())
+    }
+
+    pub(super) fn remove_review_domain_impl(
+        account: AccountIdOf<T>,
+        review_id: ReviewId,
+        domain: DomainId,
+    ) -> DispatchResult {
+        let mut review = Self::author_review(&account, &review_id)?;
+        ensure!(review.domains.contains(&domain), Error::<T>::ReviewDomainNotFound);
+        ensure!(review.domains.len() > 1, Error::<T>::ReviewNoDomainSpecified);
+
+        review.domains.retain(|d| *d != domain);
+        ReviewMap::<T>::insert(review_id, review);
+        ReviewIdByDomainId::remove(domain, review_id);
+
+        Self::deposit_event(RawEvent::ReviewDomainRemoved(account, review_id, domain));
+
+        Ok(())
+    }
 }
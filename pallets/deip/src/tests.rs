@@ -19,10 +19,11 @@ fn create_ok_project(maybe_account_id: Option<<Test as system::Config>::AccountI
     let account_id: <Test as system::Config>::AccountId = maybe_account_id.unwrap_or(DEFAULT_ACCOUNT_ID);
     let project_id = ProjectId::random();
 
-    assert_ok!(Deip::add_domain(Origin::signed(account_id), Domain { external_id: domain_id.clone() }));
+    assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: domain_id, ..Default::default() }));
 
     let project = ProjectOf::<Test> {
         is_private: false,
+        is_archived: false,
         external_id: project_id,
         team_id: account_id,
         description: H256::random(),
@@ -172,7 +173,7 @@ fn add_domain() {
     new_test_ext().execute_with(|| {
         let domain_id = DomainId::random();
         // Dispatch a signed add domian extrinsic.
-        assert_ok!(Deip::add_domain(Origin::signed(DEFAULT_ACCOUNT_ID), Domain { external_id: domain_id.clone() }));
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: domain_id, ..Default::default() }));
         
         // Read pallet storage and assert an expected result.
         assert_eq!(Deip::domain_count(), 1);
@@ -189,15 +190,76 @@ fn cant_add_duplicate_domain() {
     new_test_ext().execute_with(|| {
         let domain_id = DomainId::random();
         
-        assert_ok!(Deip::add_domain(Origin::signed(DEFAULT_ACCOUNT_ID), Domain { external_id: domain_id.clone() }));
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: domain_id, ..Default::default() }));
 
         assert_noop!(
-            Deip::add_domain(Origin::signed(DEFAULT_ACCOUNT_ID), Domain { external_id: domain_id.clone() }),
+            Deip::add_domain(Origin::root(), Domain { external_id: domain_id, ..Default::default() }),
             Error::<Test>::DomainAlreadyExists
         );
     })
 }
 
+#[test]
+fn domain_hierarchy() {
+    new_test_ext().execute_with(|| {
+        let discipline = DomainId::random();
+        let subdiscipline = DomainId::random();
+        let other = DomainId::random();
+        assert_noop!(
+            Deip::add_domain(Origin::signed(DEFAULT_ACCOUNT_ID), Domain { external_id: discipline, ..Default::default() }),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: discipline, ..Default::default() }));
+        assert_ok!(Deip::add_domain(Origin::root(), Domain {
+            external_id: subdiscipline,
+            parent: Some(discipline),
+            ..Default::default()
+        }));
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: other, ..Default::default() }));
+        assert_eq!(Deip::domain_subtree(&discipline), vec![discipline, subdiscipline]);
+
+        let metadata = Some(H256::random());
+        assert_ok!(Deip::update_domain(Origin::root(), other, metadata));
+        assert_eq!(Deip::domains(other).metadata, metadata);
+
+        let project_id = ProjectId::random();
+        assert_ok!(Deip::create_project(Origin::signed(DEFAULT_ACCOUNT_ID),
+            false, project_id, DEFAULT_ACCOUNT_ID, H256::random(), vec![other]));
+        assert_eq!(Deip::get_project_list_by_domain_tree(&discipline, 10, None), vec![]);
+
+        assert_noop!(
+            Deip::merge_domain(Origin::root(), discipline, subdiscipline),
+            Error::<Test>::DomainMergeIntoSubtree
+        );
+        assert_ok!(Deip::merge_domain(Origin::root(), other, subdiscipline));
+        assert_eq!(Deip::domains(other).status, DomainStatus::MergedInto(subdiscipline));
+        assert_eq!(Deip::domain_subtree(&discipline), vec![discipline, subdiscipline, other]);
+        let projects = Deip::get_project_list_by_domain_tree(&discipline, 10, None);
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].0, project_id);
+        assert_eq!(Deip::get_project_list_by_domain_tree(&discipline, 10, Some(project_id)), vec![]);
+        assert_noop!(
+            Deip::add_project_domain(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, other),
+            Error::<Test>::DomainNotActive
+        );
+
+        assert_ok!(Deip::deprecate_domain(Origin::root(), subdiscipline));
+        assert_noop!(
+            Deip::add_project_domain(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, subdiscipline),
+            Error::<Test>::DomainNotActive
+        );
+        assert_noop!(
+            Deip::add_domain(Origin::root(), Domain {
+                external_id: DomainId::random(),
+                parent: Some(subdiscipline),
+                ..Default::default()
+            }),
+            Error::<Test>::DomainNotActive
+        );
+        assert_ok!(Deip::add_project_domain(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, discipline));
+    })
+}
+
 #[test]
 fn add_project() {
     new_test_ext().execute_with(|| {
@@ -301,6 +363,227 @@ fn cant_update_not_existed_project() {
     })
 }
 
+fn create_ok_license(project_id: ProjectId, licenser: AccountIdOf<Test>, licensee: AccountIdOf<Test>) -> ContractAgreementId {
+    let id = ContractAgreementId::random();
+    assert_ok!(Deip::create_contract_agreement_impl(
+        licenser,
+        id,
+        licenser,
+        vec![licensee, licenser],
+        HashOf::<Test>::random(),
+        None,
+        None,
+        ContractAgreementTermsOf::<Test>::LicenseAgreement{
+            source: project_id,
+            price: DeipAsset::new(Default::default(), One::one()),
+        }));
+    id
+}
+
+#[test]
+fn archive_project() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+
+        assert_noop!(
+            Deip::archive_project(Origin::signed(BOB_ACCOUNT_ID), project_id),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Deip::archive_project(Origin::signed(account_id), project_id));
+        assert!(ProjectMap::<Test>::get(project_id).is_archived);
+        assert_noop!(
+            Deip::archive_project(Origin::signed(account_id), project_id),
+            Error::<Test>::ProjectArchived
+        );
+
+        assert_noop!(
+            Deip::update_project(Origin::signed(account_id), project_id, Some(H256::random()), None),
+            Error::<Test>::ProjectArchived
+        );
+        assert_noop!(
+            Deip::create_project_content(Origin::signed(account_id),
+                ProjectContentId::random(),
+                project_id,
+                account_id,
+                ProjectContentType::Announcement,
+                H256::random(),
+                H256::random(),
+                vec![account_id],
+                None),
+            Error::<Test>::ProjectArchived
+        );
+        assert_noop!(
+            Deip::create_contract_agreement_impl(
+                account_id,
+                ContractAgreementId::random(),
+                account_id,
+                vec![BOB_ACCOUNT_ID, account_id],
+                HashOf::<Test>::random(),
+                None,
+                None,
+                ContractAgreementTermsOf::<Test>::LicenseAgreement{
+                    source: project_id,
+                    price: DeipAsset::new(Default::default(), One::one()),
+                }),
+            Error::<Test>::ProjectArchived
+        );
+
+        assert_ok!(Deip::unarchive_project(Origin::signed(account_id), project_id));
+        assert_noop!(
+            Deip::unarchive_project(Origin::signed(account_id), project_id),
+            Error::<Test>::ProjectNotArchived
+        );
+        assert_ok!(Deip::update_project(Origin::signed(account_id), project_id, Some(H256::random()), None));
+    })
+}
+
+#[test]
+fn remove_project() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, project, _, account_id) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+        assert_noop!(
+            Deip::remove_project(Origin::signed(BOB_ACCOUNT_ID), project_id),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Deip::remove_project(Origin::signed(account_id), project_id));
+        assert!(!ProjectMap::<Test>::contains_key(project_id));
+        assert!(!ProjectIdByTeamId::<Test>::contains_key(account_id, project_id));
+        assert_eq!(
+            System::events().pop().unwrap().event,
+            mock::Event::pallet_deip(RawEvent::ProjectRemoved(account_id, project))
+        );
+
+        let (project_id, ..) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+        assert_ok!(Deip::create_project_content(Origin::signed(account_id),
+            ProjectContentId::random(),
+            project_id,
+            account_id,
+            ProjectContentType::Announcement,
+            H256::random(),
+            H256::random(),
+            vec![account_id],
+            None));
+        assert_noop!(
+            Deip::remove_project(Origin::signed(account_id), project_id),
+            Error::<Test>::ProjectHasContent
+        );
+
+        let (project_id, ..) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+        create_issue_asset(account_id, 1, 100, Some(project_id));
+        assert_noop!(
+            Deip::remove_project(Origin::signed(account_id), project_id),
+            Error::<Test>::ProjectHasTokenizedAssets
+        );
+
+        let (project_id, ..) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+        let license_id = create_ok_license(project_id, account_id, BOB_ACCOUNT_ID);
+        assert_eq!(LicenseIdByProjectId::iter_prefix(project_id).count(), 1);
+        assert_noop!(
+            Deip::remove_project(Origin::signed(account_id), project_id),
+            Error::<Test>::ProjectHasActiveLicense
+        );
+        assert_ok!(Deip::reject_contract_agreement_impl(BOB_ACCOUNT_ID, license_id, BOB_ACCOUNT_ID));
+        assert_ok!(Deip::remove_project(Origin::signed(account_id), project_id));
+        assert_eq!(LicenseIdByProjectId::iter_prefix(project_id).count(), 0);
+    })
+}
+
+#[test]
+fn remove_project_with_nda() {
+    new_test_ext2().execute_with(|| {
+        let (nda_id, nda) = create_ok_nda();
+        let project_id = nda.projects[0];
+        assert!(NdaIdByProjectId::contains_key(project_id, nda_id));
+
+        assert_ok!(Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id));
+        assert!(Deip::nda(nda_id).projects.is_empty());
+        assert!(!NdaIdByProjectId::contains_key(project_id, nda_id));
+    })
+}
+
+#[test]
+fn handover_project() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+
+        assert_noop!(
+            Deip::handover_project(Origin::signed(account_id), project_id, account_id),
+            Error::<Test>::ProjectHandoverToSameTeam
+        );
+        assert_ok!(Deip::handover_project(Origin::signed(account_id), project_id, BOB_ACCOUNT_ID));
+        assert_noop!(
+            Deip::accept_project_handover(Origin::signed(DEFAULT_ACCOUNT_ID), project_id),
+            Error::<Test>::NoSuchProjectHandover
+        );
+        assert_ok!(Deip::cancel_project_handover(Origin::signed(BOB_ACCOUNT_ID), project_id));
+        assert_noop!(
+            Deip::accept_project_handover(Origin::signed(BOB_ACCOUNT_ID), project_id),
+            Error::<Test>::NoSuchProjectHandover
+        );
+
+        assert_ok!(Deip::handover_project(Origin::signed(account_id), project_id, BOB_ACCOUNT_ID));
+        assert_ok!(Deip::accept_project_handover(Origin::signed(BOB_ACCOUNT_ID), project_id));
+        assert_eq!(ProjectMap::<Test>::get(project_id).team_id, BOB_ACCOUNT_ID);
+        assert!(!ProjectIdByTeamId::<Test>::contains_key(account_id, project_id));
+        assert!(ProjectIdByTeamId::<Test>::contains_key(BOB_ACCOUNT_ID, project_id));
+        assert!(Deip::project_handover(project_id).is_none());
+        assert_noop!(
+            Deip::update_project(Origin::signed(account_id), project_id, Some(H256::random()), None),
+            Error::<Test>::NoPermission
+        );
+
+        create_ok_license(project_id, BOB_ACCOUNT_ID, account_id);
+        assert_noop!(
+            Deip::handover_project(Origin::signed(BOB_ACCOUNT_ID), project_id, account_id),
+            Error::<Test>::ProjectHasPendingLicense
+        );
+    })
+}
+
+#[test]
+fn project_domains() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, _, domain_id, account_id) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+        assert!(ProjectIdByDomainId::contains_key(domain_id, project_id));
+
+        let other_domain_id = DomainId::random();
+        assert_noop!(
+            Deip::add_project_domain(Origin::signed(account_id), project_id, other_domain_id),
+            Error::<Test>::DomainNotExists
+        );
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: other_domain_id, ..Default::default() }));
+        assert_noop!(
+            Deip::add_project_domain(Origin::signed(BOB_ACCOUNT_ID), project_id, other_domain_id),
+            Error::<Test>::NoPermission
+        );
+        assert_noop!(
+            Deip::add_project_domain(Origin::signed(account_id), project_id, domain_id),
+            Error::<Test>::ProjectDomainAlreadyAdded
+        );
+        assert_ok!(Deip::add_project_domain(Origin::signed(account_id), project_id, other_domain_id));
+        assert_eq!(ProjectMap::<Test>::get(project_id).domains, vec![domain_id, other_domain_id]);
+        assert!(ProjectIdByDomainId::contains_key(other_domain_id, project_id));
+
+        assert_ok!(Deip::remove_project_domain(Origin::signed(account_id), project_id, domain_id));
+        assert_eq!(ProjectMap::<Test>::get(project_id).domains, vec![other_domain_id]);
+        assert!(!ProjectIdByDomainId::contains_key(domain_id, project_id));
+        assert_noop!(
+            Deip::remove_project_domain(Origin::signed(account_id), project_id, domain_id),
+            Error::<Test>::ProjectDomainNotFound
+        );
+
+        assert_ok!(Deip::archive_project(Origin::signed(account_id), project_id));
+        assert_noop!(
+            Deip::add_project_domain(Origin::signed(account_id), project_id, domain_id),
+            Error::<Test>::ProjectArchived
+        );
+        assert_ok!(Deip::unarchive_project(Origin::signed(account_id), project_id));
+
+        assert_ok!(Deip::remove_project(Origin::signed(account_id), project_id));
+        assert!(!ProjectIdByDomainId::contains_key(other_domain_id, project_id));
+    })
+}
+
 #[test]
 fn create_project_content() {
     new_test_ext().execute_with(|| {
@@ -332,6 +615,235 @@ fn create_project_content() {
     })
 }
 
+#[test]
+fn amend_project_content() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let original_id = ProjectContentId::random();
+        assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            original_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::MilestoneArticle,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None));
+
+        let revision_id = ProjectContentId::random();
+        assert_noop!(
+            Deip::amend_project_content(Origin::signed(BOB_ACCOUNT_ID),
+                revision_id, original_id, H256::random(), H256::random(), vec![], None, H256::random()),
+            Error::<Test>::NoPermission
+        );
+        assert_noop!(
+            Deip::amend_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+                original_id, original_id, H256::random(), H256::random(), vec![], None, H256::random()),
+            Error::<Test>::ProjectContentAlreadyExists
+        );
+        let reason = H256::random();
+        assert_ok!(Deip::amend_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            revision_id, original_id, H256::random(), H256::random(), vec![DEFAULT_ACCOUNT_ID], None, reason));
+        assert_noop!(
+            Deip::amend_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(), original_id, H256::random(), H256::random(), vec![], None, H256::random()),
+            Error::<Test>::ProjectContentNotLatestVersion
+        );
+
+        let original = ProjectContentMap::<Test>::get(original_id);
+        let revision = ProjectContentMap::<Test>::get(revision_id);
+        assert!(!original.is_latest);
+        assert!(revision.is_latest);
+        assert_eq!(revision.version, 2);
+        assert_eq!(revision.previous_version, Some(original_id));
+        assert_eq!(revision.amendment_reason, Some(reason));
+        assert_eq!(revision.content_type, ProjectContentType::MilestoneArticle);
+        assert!(ContentIdByProjectId::contains_key(project_id, revision_id));
+        assert_eq!(Deip::next_content_version(original_id), Some(revision_id));
+
+        let revisions = vec![original, revision];
+        assert_eq!(Deip::get_project_content_revisions(&original_id), revisions);
+        assert_eq!(Deip::get_project_content_revisions(&revision_id), revisions);
+    })
+}
+
+#[test]
+fn project_content_authors_confirmation() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let content_id = ProjectContentId::random();
+        assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID],
+            None));
+
+        assert_eq!(ProjectContentMap::<Test>::get(content_id).status, ProjectContentStatus::PendingAuthors);
+        let authors = Deip::pending_content_authors(content_id).unwrap();
+        assert_eq!(authors.pending, vec![ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID]);
+        assert_eq!(authors.confirmed, vec![DEFAULT_ACCOUNT_ID]);
+        assert!(ContentIdByAuthorId::<Test>::contains_key(DEFAULT_ACCOUNT_ID, content_id));
+        assert_noop!(
+            Deip::amend_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(), content_id, H256::random(), H256::random(), vec![], None, H256::random()),
+            Error::<Test>::ProjectContentNotFinalized
+        );
+        assert_noop!(
+            Deip::create_review(Origin::signed(ALICE_ACCOUNT_ID),
+                ReviewId::random(), ALICE_ACCOUNT_ID, H256::random(), vec![domain_id], 1, vec![], content_id),
+            Error::<Test>::ProjectContentNotFinalized
+        );
+        assert_noop!(
+            Deip::confirm_project_content_authorship(Origin::signed(DEFAULT_ACCOUNT_ID), content_id),
+            Error::<Test>::ProjectContentNoSuchPendingAuthor
+        );
+
+        assert_ok!(Deip::confirm_project_content_authorship(Origin::signed(ALICE_ACCOUNT_ID), content_id));
+        assert!(ContentIdByAuthorId::<Test>::contains_key(ALICE_ACCOUNT_ID, content_id));
+        assert_ok!(Deip::decline_project_content_authorship(Origin::signed(BOB_ACCOUNT_ID), content_id));
+        assert!(!ContentIdByAuthorId::<Test>::contains_key(BOB_ACCOUNT_ID, content_id));
+
+        let content = ProjectContentMap::<Test>::get(content_id);
+        assert_eq!(content.status, ProjectContentStatus::Finalized);
+        assert_eq!(content.authors, vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID]);
+        assert!(Deip::pending_content_authors(content_id).is_none());
+        assert_eq!(
+            System::events().pop().unwrap().event,
+            mock::Event::pallet_deip(RawEvent::ProjectContentFinalized(content_id))
+        );
+
+        let content_id = ProjectContentId::random();
+        assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement,
+            H256::random(),
+            H256::random(),
+            vec![BOB_ACCOUNT_ID],
+            None));
+        assert_noop!(
+            Deip::finalize_project_content(Origin::none(), content_id),
+            Error::<Test>::ProjectContentAuthorsConfirmationNotExpired
+        );
+        let deadline = Deip::pending_content_authors(content_id).unwrap().deadline;
+        Timestamp::set_timestamp(deadline);
+        assert_ok!(Deip::finalize_project_content(Origin::none(), content_id));
+        let content = ProjectContentMap::<Test>::get(content_id);
+        assert_eq!(content.status, ProjectContentStatus::Finalized);
+        assert!(content.authors.is_empty());
+    })
+}
+
+#[test]
+fn project_content_finalized_by_offchain_worker() {
+    let mut ext = new_test_ext2();
+    let state = offchainify(&mut ext, 1);
+    ext.execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let content_id = ProjectContentId::random();
+        assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID, BOB_ACCOUNT_ID],
+            None));
+
+        let call = crate::Call::<Test>::finalize_project_content(content_id);
+        let validate = |source| <Deip as sp_runtime::traits::ValidateUnsigned>::validate_unsigned(source, &call);
+        Deip::offchain_worker(System::block_number());
+        assert!(state.read().transactions.is_empty());
+        assert_eq!(validate(TransactionSource::Local), InvalidTransaction::Stale.into());
+
+        let deadline = Deip::pending_content_authors(content_id).unwrap().deadline;
+        Timestamp::set_timestamp(deadline);
+        Deip::offchain_worker(System::block_number());
+        assert_eq!(state.read().transactions.len(), 1);
+        assert_eq!(decode_validate_deip_call(&state.read().transactions[0]), call);
+        assert_eq!(validate(TransactionSource::External), InvalidTransaction::Custom(NON_LOCAL).into());
+
+        assert_ok!(call.clone().dispatch_bypass_filter(Origin::none()));
+        assert_eq!(ProjectContentMap::<Test>::get(content_id).status, ProjectContentStatus::Finalized);
+        assert_eq!(validate(TransactionSource::Local), InvalidTransaction::Stale.into());
+    })
+}
+
+#[test]
+fn domain_migrated_to_active_top_level() {
+    new_test_ext().execute_with(|| {
+        let domain_id = DomainId::random();
+        let old = crate::migration::DomainV1 { external_id: domain_id };
+        frame_support::storage::unhashed::put(&Domains::hashed_key_for(domain_id), &old);
+
+        <Deip as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+        let domain = Domains::get(domain_id);
+        assert_eq!(domain, Domain { external_id: domain_id, parent: None, metadata: None, status: DomainStatus::Active });
+        assert_ok!(Deip::ensure_active_domain(&domain_id));
+    })
+}
+
+#[test]
+fn project_migrated_to_not_archived() {
+    new_test_ext().execute_with(|| {
+        let project_id = ProjectId::random();
+        let old = crate::migration::ProjectV1 {
+            is_private: true,
+            external_id: project_id,
+            team_id: DEFAULT_ACCOUNT_ID,
+            description: H256::random(),
+            domains: vec![DomainId::random()],
+        };
+        frame_support::storage::unhashed::put(&ProjectMap::<Test>::hashed_key_for(project_id), &old);
+
+        <Deip as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+        let project = ProjectMap::<Test>::get(project_id);
+        assert!(!project.is_archived);
+        assert!(project.is_private);
+        assert_eq!(project.team_id, DEFAULT_ACCOUNT_ID);
+        assert_eq!(project.domains, old.domains);
+    })
+}
+
+#[test]
+fn project_content_migrated_to_finalized() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let content_id = ProjectContentId::random();
+        let old = crate::migration::ProjectContentV1 {
+            external_id: content_id,
+            project_external_id: project_id,
+            team_id: DEFAULT_ACCOUNT_ID,
+            content_type: ProjectContentType::Announcement,
+            description: H256::random(),
+            content: H256::random(),
+            authors: vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID],
+            references: None,
+        };
+        frame_support::storage::unhashed::put(&ProjectContentMap::<Test>::hashed_key_for(content_id), &old);
+
+        <Deip as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+        assert_eq!(StorageVersion::get(), crate::migration::Releases::V2);
+        let content = ProjectContentMap::<Test>::get(content_id);
+        assert_eq!(content.status, ProjectContentStatus::Finalized);
+        assert_eq!(content.version, 1);
+        assert!(content.is_latest);
+        assert_eq!(content.previous_version, None);
+        assert_eq!(content.authors, old.authors);
+        assert!(ContentIdByAuthorId::<Test>::contains_key(ALICE_ACCOUNT_ID, content_id));
+
+        // runs once
+        <Deip as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+        assert_eq!(ProjectContentMap::<Test>::get(content_id), content);
+    })
+}
+
 #[test]
 fn create_project_content_with_references() {
     new_test_ext().execute_with(|| {
@@ -911,6 +1423,82 @@ fn cant_reject_finalized_nda_content_access_request() {
     })
 }
 
+fn create_ok_review(account_id: AccountIdOf<Test>, domains: Vec<DomainId>) -> ReviewId {
+    let (project_id, ..) = create_ok_project(Some(account_id));
+    let content_id = ProjectContentId::random();
+    assert_ok!(Deip::create_project_content(Origin::signed(account_id),
+        content_id,
+        project_id,
+        account_id,
+        ProjectContentType::Announcement,
+        H256::random(),
+        H256::random(),
+        vec![account_id],
+        None));
+
+    let review_id = ReviewId::random();
+    assert_ok!(Deip::create_review(Origin::signed(account_id),
+        review_id,
+        account_id,
+        H256::random(),
+        domains,
+        1,
+        b"50.00 %".to_vec(),
+        content_id));
+    review_id
+}
+
+#[test]
+fn review_domains() {
+    new_test_ext2().execute_with(|| {
+        let domain_id = DomainId::random();
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: domain_id, ..Default::default() }));
+        let review_id = create_ok_review(ALICE_ACCOUNT_ID, vec![domain_id]);
+        assert!(ReviewIdByDomainId::contains_key(domain_id, review_id));
+
+        let other_domain_id = DomainId::random();
+        assert_noop!(
+            Deip::add_review_domain(Origin::signed(ALICE_ACCOUNT_ID), review_id, other_domain_id),
+            Error::<Test>::DomainNotExists
+        );
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: other_domain_id, ..Default::default() }));
+        assert_noop!(
+            Deip::add_review_domain(Origin::signed(BOB_ACCOUNT_ID), review_id, other_domain_id),
+            Error::<Test>::NoPermission
+        );
+        assert_noop!(
+            Deip::add_review_domain(Origin::signed(ALICE_ACCOUNT_ID), ReviewId::random(), other_domain_id),
+            Error::<Test>::NoSuchReview
+        );
+        assert_noop!(
+            Deip::add_review_domain(Origin::signed(ALICE_ACCOUNT_ID), review_id, domain_id),
+            Error::<Test>::ReviewDomainAlreadyAdded
+        );
+
+        assert_ok!(Deip::add_review_domain(Origin::signed(ALICE_ACCOUNT_ID), review_id, other_domain_id));
+        assert!(ReviewIdByDomainId::contains_key(other_domain_id, review_id));
+        assert_eq!(
+            System::events().pop().unwrap().event,
+            mock::Event::pallet_deip(RawEvent::ReviewDomainAdded(ALICE_ACCOUNT_ID, review_id, other_domain_id))
+        );
+
+        assert_ok!(Deip::remove_review_domain(Origin::signed(ALICE_ACCOUNT_ID), review_id, domain_id));
+        assert!(!ReviewIdByDomainId::contains_key(domain_id, review_id));
+        assert_eq!(
+            System::events().pop().unwrap().event,
+            mock::Event::pallet_deip(RawEvent::ReviewDomainRemoved(ALICE_ACCOUNT_ID, review_id, domain_id))
+        );
+        assert_noop!(
+            Deip::remove_review_domain(Origin::signed(ALICE_ACCOUNT_ID), review_id, domain_id),
+            Error::<Test>::ReviewDomainNotFound
+        );
+        assert_noop!(
+            Deip::remove_review_domain(Origin::signed(ALICE_ACCOUNT_ID), review_id, other_domain_id),
+            Error::<Test>::ReviewNoDomainSpecified
+        );
+    })
+}
+
 #[test]
 fn simple_crowdfunding_create_should_fail() {
     new_test_ext2().execute_with(|| {
@@ -977,6 +1565,11 @@ fn simple_crowdfunding_hard_cap_reached() {
             DeipAsset::new(base_asset_id, hard_cap),
             vec![DeipAsset::new(usd_id, usd_to_sale), DeipAsset::new(eur_id, eur_to_sale)]
         ));
+        assert!(InvestmentIdByProjectId::contains_key(project_id, sale_id));
+        assert_noop!(
+            Deip::handover_project(Origin::signed(*account_id), *project_id, BOB_ACCOUNT_ID),
+            Error::<Test>::ProjectHasOpenCrowdfunding
+        );
 
         Deip::offchain_worker(System::block_number());
         assert_eq!(state.read().transactions.len(), 1);
@@ -1015,6 +1608,10 @@ fn simple_crowdfunding_hard_cap_reached() {
         assert_eq!(Assets::balance(eur_id, *account_id), Assets::total_supply(eur_id) - eur_to_sale);
 
         assert_eq!(Assets::balance(base_asset_id, *account_id), hard_cap + balance_before);
+        assert_noop!(
+            Deip::handover_project(Origin::signed(*account_id), *project_id, BOB_ACCOUNT_ID),
+            Error::<Test>::ProjectHasTokenizedAssets
+        );
     })
 }
 
@@ -47,13 +47,14 @@ const NON_LOCAL: u8 = 101;
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_support::{
-        traits::{Currency, ExistenceRequirement, UnfilteredDispatchable, WithdrawReasons},
+        traits::{Currency, ExistenceRequirement, PalletInfo, UnfilteredDispatchable, WithdrawReasons},
         transactional,
+        storage::migration::StorageKeyIterator,
     };
     use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
     use frame_system::{pallet_prelude::*, RawOrigin};
     use sp_runtime::traits::{One, StaticLookup, Zero};
-    use sp_std::{prelude::*, vec};
+    use sp_std::{prelude::*, vec, convert::TryInto};
 
     #[cfg(feature = "std")]
     use frame_support::traits::GenesisBuild;
@@ -70,13 +71,18 @@ pub mod pallet {
     pub(crate) type AssetsAssetIdOf<T> = <T as pallet_assets::Config>::AssetId;
     pub(crate) type AssetsBalanceOf<T> = <T as pallet_assets::Config>::Balance;
     type AssetsWeightInfoOf<T> = <T as pallet_assets::Config>::WeightInfo;
+    type AssetsDetailsOf<T> = pallet_assets::AssetDetails<
+        AssetsBalanceOf<T>,
+        AccountIdOf<T>,
+        <<T as pallet_assets::Config>::Currency as Currency<AccountIdOf<T>>>::Balance,
+    >;
 
     #[pallet::config]
     pub trait Config:
         frame_system::Config + pallet_assets::Config + SendTransactionTypes<Call<Self>>
     {
         type ProjectsInfo: DeipProjectsInfo<Self::AccountId>;
-        type DeipAccountId: Into<Self::AccountId> + Parameter + Member;
+        type DeipAccountId: TryInto<Self::AccountId> + Parameter + Member;
 
         /// Period of check for accounts with zero NFTs
         #[pallet::constant]
@@ -163,6 +169,8 @@ pub mod pallet {
         ReservedAssetAccountCannotBeFreezed,
         NFTNotFound,
         NFTBalanceNotFound,
+        /// Account can't be resolved (e.g. DAO is dissolved)
+        DeipAccountIdInvalid,
     }
 
     #[pallet::storage]
@@ -279,6 +287,10 @@ pub mod pallet {
             }
         }
 
+        fn deip_account(who: T::DeipAccountId) -> Result<AccountIdOf<T>, Error<T>> {
+            who.try_into().map_err(|_| Error::<T>::DeipAccountIdInvalid)
+        }
+
         pub fn account_balance(account: &AccountIdOf<T>, asset: &T::AssetId) -> T::Balance {
             pallet_assets::Pallet::<T>::balance(*asset, account.clone())
         }
@@ -287,6 +299,23 @@ pub mod pallet {
             pallet_assets::Pallet::<T>::total_supply(*asset)
         }
 
+        /// Assets with non-zero balance of the account.
+        /// `pallet_assets` has no index by account so all assets are scanned,
+        /// `None` if there are more than `max_scanned` assets
+        pub fn account_assets(account: &AccountIdOf<T>, max_scanned: u32) -> Option<Vec<T::AssetId>> {
+            let prefix = <T as frame_system::Config>::PalletInfo::name::<pallet_assets::Pallet<T>>()
+                .expect("pallet_assets is a part of the runtime");
+            let assets: Vec<T::AssetId> =
+                StorageKeyIterator::<T::AssetId, AssetsDetailsOf<T>, Blake2_128Concat>::new(prefix.as_bytes(), b"Asset")
+                    .map(|(id, _)| id)
+                    .take(max_scanned as usize + 1)
+                    .collect();
+            if assets.len() > max_scanned as usize {
+                return None;
+            }
+            Some(assets.into_iter().filter(|id| !Self::account_balance(account, id).is_zero()).collect())
+        }
+
         pub fn get_project_nfts(id: &DeipProjectIdOf<T>) -> Vec<T::AssetId> {
             AssetIdByProjectId::<T>::try_get(id.clone()).unwrap_or_default()
         }
@@ -541,7 +570,7 @@ pub mod pallet {
                 };
             }
 
-            let admin_source = <T::Lookup as StaticLookup>::unlookup(admin.into());
+            let admin_source = <T::Lookup as StaticLookup>::unlookup(Self::deip_account(admin)?);
             let call = pallet_assets::Call::<T>::create(id, admin_source, 0u32, min_balance);
             let result = call.dispatch_bypass_filter(origin);
             if result.is_err() {
@@ -583,8 +612,9 @@ pub mod pallet {
             beneficiary: T::DeipAccountId,
             #[pallet::compact] amount: AssetsBalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
+            let beneficiary = Self::deip_account(beneficiary)?;
             let beneficiary_source =
-                <T::Lookup as StaticLookup>::unlookup(beneficiary.clone().into());
+                <T::Lookup as StaticLookup>::unlookup(beneficiary.clone());
             let call = pallet_assets::Call::<T>::mint(id, beneficiary_source, amount);
             let result = call.dispatch_bypass_filter(origin)?;
 
@@ -592,13 +622,13 @@ pub mod pallet {
                 NftBalanceMap::<T>::mutate_exists(id, |maybe| {
                     let balances = match maybe.as_mut() {
                         None => {
-                            *maybe = Some(vec![beneficiary.into()]);
+                            *maybe = Some(vec![beneficiary]);
                             return;
                         }
                         Some(b) => b,
                     };
 
-                    let account = beneficiary.into();
+                    let account = beneficiary;
                     match balances.binary_search_by_key(&&account, |a| a) {
                         Ok(_) => (),
                         Err(i) => balances.insert(i, account),
@@ -621,7 +651,7 @@ pub mod pallet {
                 Error::<T>::ProjectSecurityTokenCannotBeBurned
             );
 
-            let who_source = <T::Lookup as StaticLookup>::unlookup(who.into());
+            let who_source = <T::Lookup as StaticLookup>::unlookup(Self::deip_account(who)?);
             let call = pallet_assets::Call::<T>::burn(id, who_source, amount);
             call.dispatch_bypass_filter(origin)
         }
@@ -633,7 +663,7 @@ pub mod pallet {
             target: T::DeipAccountId,
             #[pallet::compact] amount: AssetsBalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
-            Self::transfer_impl(origin, id, target.into(), amount)
+            Self::transfer_impl(origin, id, Self::deip_account(target)?, amount)
         }
 
         #[pallet::weight(AssetsWeightInfoOf::<T>::freeze())]
@@ -652,7 +682,7 @@ pub mod pallet {
                 Error::<T>::ReservedAssetAccountCannotBeFreezed
             );
 
-            let who_source = <T::Lookup as StaticLookup>::unlookup(who.into());
+            let who_source = <T::Lookup as StaticLookup>::unlookup(Self::deip_account(who)?);
             let call = pallet_assets::Call::<T>::freeze(id, who_source);
             call.dispatch_bypass_filter(origin)
         }
@@ -663,7 +693,7 @@ pub mod pallet {
             #[pallet::compact] id: T::AssetId,
             who: T::DeipAccountId,
         ) -> DispatchResultWithPostInfo {
-            let who_source = <T::Lookup as StaticLookup>::unlookup(who.into());
+            let who_source = <T::Lookup as StaticLookup>::unlookup(Self::deip_account(who)?);
             let call = pallet_assets::Call::<T>::thaw(id, who_source);
             call.dispatch_bypass_filter(origin)
         }
@@ -702,7 +732,7 @@ pub mod pallet {
             #[pallet::compact] id: T::AssetId,
             owner: T::DeipAccountId,
         ) -> DispatchResultWithPostInfo {
-            let owner_source = <T::Lookup as StaticLookup>::unlookup(owner.into());
+            let owner_source = <T::Lookup as StaticLookup>::unlookup(Self::deip_account(owner)?);
             let call = pallet_assets::Call::<T>::transfer_ownership(id, owner_source);
             call.dispatch_bypass_filter(origin)
         }
@@ -715,9 +745,9 @@ pub mod pallet {
             admin: T::DeipAccountId,
             freezer: T::DeipAccountId,
         ) -> DispatchResultWithPostInfo {
-            let issuer_source = <T::Lookup as StaticLookup>::unlookup(issuer.into());
-            let admin_source = <T::Lookup as StaticLookup>::unlookup(admin.into());
-            let freezer_source = <T::Lookup as StaticLookup>::unlookup(freezer.into());
+            let issuer_source = <T::Lookup as StaticLookup>::unlookup(Self::deip_account(issuer)?);
+            let admin_source = <T::Lookup as StaticLookup>::unlookup(Self::deip_account(admin)?);
+            let freezer_source = <T::Lookup as StaticLookup>::unlookup(Self::deip_account(freezer)?);
             let call =
                 pallet_assets::Call::<T>::set_team(id, issuer_source, admin_source, freezer_source);
             call.dispatch_bypass_filter(origin)
@@ -3,20 +3,46 @@ use sp_std::prelude::*;
 use codec::Codec;
 
 use super::dao::{DaoId, Dao};
+use super::budget::{BudgetId, Budget};
+use super::invitation::Invitation;
+use super::metadata::MetadataRevision;
+use super::handle::DaoHandle;
 
 pub type GetResult<AccountId> = Option<Dao<AccountId, DaoId>>;
 pub type GetMultiResult<AccountId> = Vec<Option<Dao<AccountId, DaoId>>>;
+pub type GetListByMemberResult<AccountId> = Vec<Dao<AccountId, DaoId>>;
+pub type GetInvitationListByDaoResult<AccountId, BlockNumber> = Vec<(AccountId, Invitation<BlockNumber>)>;
+pub type GetInvitationListByMemberResult<BlockNumber> = Vec<(DaoId, Invitation<BlockNumber>)>;
+pub type GetBudgetResult<AccountId, AssetId, Balance, BlockNumber> =
+    Option<Budget<AccountId, AssetId, Balance, BlockNumber>>;
+pub type GetBudgetListResult<AccountId, AssetId, Balance, BlockNumber> =
+    Vec<(BudgetId, Budget<AccountId, AssetId, Balance, BlockNumber>)>;
+pub type GetMetadataHistoryResult<AccountId, BlockNumber, Moment> =
+    Vec<(u32, MetadataRevision<AccountId, BlockNumber, Moment>)>;
 
 sp_api::decl_runtime_apis! {
-    pub trait DeipDaoRuntimeApi<AccountId>
-        where AccountId: Codec
-    {
+    pub trait DeipDaoRuntimeApi<AccountId: Codec, AssetId: Codec, Balance: Codec, BlockNumber: Codec, Moment: Codec> {
         fn get(id: DaoId) -> GetResult<AccountId>;
         fn get_multi(ids: Vec<DaoId>) -> GetMultiResult<AccountId>;
+        /// DAOs which authority includes the member
+        fn get_list_by_member(member: AccountId) -> GetListByMemberResult<AccountId>;
+        /// Pending (not expired) invitations of DAO
+        fn get_invitation_list_by_dao(id: DaoId) -> GetInvitationListByDaoResult<AccountId, BlockNumber>;
+        /// Pending (not expired) invitations of the account
+        fn get_invitation_list_by_member(member: AccountId) -> GetInvitationListByMemberResult<BlockNumber>;
+        /// Budget with usage in the current period
+        fn get_budget(id: DaoId, budget_id: BudgetId) -> GetBudgetResult<AccountId, AssetId, Balance, BlockNumber>;
+        /// All budgets of DAO with usage in the current period
+        fn get_budget_list(id: DaoId) -> GetBudgetListResult<AccountId, AssetId, Balance, BlockNumber>;
+        /// Metadata revisions of DAO from the oldest one
+        fn get_metadata_history(id: DaoId) -> GetMetadataHistoryResult<AccountId, BlockNumber, Moment>;
+        /// Resolve DAO handle to DAO ID
+        fn get_id_by_handle(handle: DaoHandle) -> Option<DaoId>;
     }
 }
 
-use super::{Pallet, Config, DaoRepository};
+use super::{Pallet, Config, DaoRepository, DaoBudgets, DaoIdByMember, DaoInvitations, DaoIdByInvitee};
+use super::{DaoMetadataHistory, DaoIdByHandle};
 
 impl<T: Config> Pallet<T> {
     pub fn rpc_get(id: DaoId) -> GetResult<T::AccountId> {
@@ -25,4 +51,68 @@ impl<T: Config> Pallet<T> {
     pub fn rpc_get_multi(ids: Vec<DaoId>) -> GetMultiResult<T::AccountId> {
         ids.into_iter().map(|x| DaoRepository::<T>::try_get(x).ok()).collect()
     }
+    pub fn rpc_get_list_by_member(member: T::AccountId) -> GetListByMemberResult<T::AccountId> {
+        DaoIdByMember::<T>::iter_prefix(member)
+            .filter_map(|(id, _)| DaoRepository::<T>::try_get(id).ok())
+            .collect()
+    }
+    pub fn rpc_get_invitation_list_by_dao(
+        id: DaoId,
+    )
+        -> GetInvitationListByDaoResult<T::AccountId, T::BlockNumber>
+    {
+        let now = frame_system::Module::<T>::block_number();
+        DaoInvitations::<T>::iter_prefix(id)
+            .filter(|(_, invitation)| !invitation.is_expired(&now))
+            .collect()
+    }
+    pub fn rpc_get_invitation_list_by_member(
+        member: T::AccountId,
+    )
+        -> GetInvitationListByMemberResult<T::BlockNumber>
+    {
+        let now = frame_system::Module::<T>::block_number();
+        DaoIdByInvitee::<T>::iter_prefix(&member)
+            .filter_map(|(id, _)| {
+                DaoInvitations::<T>::get(id, &member)
+                    .filter(|invitation| !invitation.is_expired(&now))
+                    .map(|invitation| (id, invitation))
+            })
+            .collect()
+    }
+    pub fn rpc_get_budget(
+        id: DaoId,
+        budget_id: BudgetId,
+    )
+        -> GetBudgetResult<T::AccountId, T::AssetId, T::Balance, T::BlockNumber>
+    {
+        let now = frame_system::Module::<T>::block_number();
+        DaoBudgets::<T>::get(id, budget_id).map(|mut budget| {
+            budget.renew(now);
+            budget
+        })
+    }
+    pub fn rpc_get_budget_list(
+        id: DaoId,
+    )
+        -> GetBudgetListResult<T::AccountId, T::AssetId, T::Balance, T::BlockNumber>
+    {
+        let now = frame_system::Module::<T>::block_number();
+        DaoBudgets::<T>::iter_prefix(id).map(|(budget_id, mut budget)| {
+            budget.renew(now);
+            (budget_id, budget)
+        }).collect()
+    }
+    pub fn rpc_get_metadata_history(
+        id: DaoId,
+    )
+        -> GetMetadataHistoryResult<T::AccountId, T::BlockNumber, T::Moment>
+    {
+        let mut history: Vec<_> = DaoMetadataHistory::<T>::iter_prefix(id).collect();
+        history.sort_by_key(|(number, _)| *number);
+        history
+    }
+    pub fn rpc_get_id_by_handle(handle: DaoHandle) -> Option<DaoId> {
+        DaoIdByHandle::<T>::get(handle)
+    }
 }
@@ -13,7 +13,84 @@
 //!
 //! * `create` - Create a DAO.
 //! * `alter_authority` - Alter DAO's authority.
+//!   New members may join the authority only by accepting invitations,
+//!   so replaced authority may consist of current members only.
+//!   Roles of members removed from the authority are revoked.
+//! * `invite` - Invite an account to join a DAO authority until the expiry block.
+//! * `cancel_invitation` - Cancel a pending invitation.
+//! * `accept_invitation` - Accept an invitation and join the DAO authority.
+//! * `decline_invitation` - Decline an invitation.
+//! * `update_dao` - Replace metadata hash of a DAO.
+//! * `set_metadata_record` - Replace typed metadata record of a DAO.
+//! * `set_handle` - Set or rename a human-readable handle of a DAO.
+//! * `reserve_handle` - Reserve a handle so no DAO may take it (root only).
+//! * `release_handle` - Release a reserved handle (root only).
 //! * `on_behalf` - Perform action on behalf of a DAO.
+//!   Action may be performed by the DAO authority or by a single member
+//!   which role allows the call.
+//! * `set_role` - Create a DAO role or replace calls allowed for it.
+//! * `remove_role` - Remove a DAO role.
+//! * `assign_role` - Assign a DAO role to a member account (signatory of the DAO authority).
+//! * `revoke_role` - Revoke a DAO role from a member account.
+//! * `set_budget` - Create a DAO budget or replace it with a new one.
+//! * `remove_budget` - Remove a DAO budget.
+//! * `set_governance` - Enable, replace or disable voting governance of a DAO.
+//! * `propose_motion` - Propose a call to be dispatched on behalf of a DAO after voting.
+//! * `vote_motion` - Vote for or against a DAO motion.
+//! * `close_motion` - Close voting of a DAO motion and dispatch the call if it passed.
+//! * `dissolve` - Sweep DAO assets to beneficiaries and dissolve the DAO.
+//!
+//! ### Budgets
+//!
+//! Calls that spend DAO assets (as reported by `Config::SpendingCalls`)
+//! may be dispatched by a member or a role member via `on_behalf`
+//! without the DAO authority approval only within DAO budgets.
+//! Budget limits amount of a single asset that may be spent per period.
+//! Batch mixing spendings with other calls is checked by roles as a whole.
+//!
+//! ### Voting governance
+//!
+//! DAO with voting governance is controlled by motions instead of the authority key:
+//! `on_behalf` calls of the authority are forbidden, members vote on motions
+//! (one signatory - one vote or weighted by balance of a project token)
+//! and passed motions are dispatched as the DAO key.
+//! Changes of a motion call that fails are rolled back.
+//! So `alter_authority`, `update_dao` etc. are reachable through motions.
+//! Vote weight is saved when the vote is cast and is limited by the voter's weight
+//! at closing, so tokens transferred after voting are not counted twice.
+//!
+//! ### Metadata history
+//!
+//! Every change of DAO metadata (hash or typed record) including the initial one on creation
+//! is saved as a revision with the block, the timestamp and the account that submitted it
+//! (signer of `on_behalf` or proposer of the motion).
+//!
+//! ### Handles
+//!
+//! DAO may have a human-readable handle resolved to the DAO ID.
+//! Handle is 3 to 32 lowercase latin letters, digits, `-` or `_`
+//! starting and ending with a letter or digit. Handle may be renamed
+//! not earlier than `Config::HandleRenameCooldown` blocks after it was set,
+//! the previous handle becomes free. Handle of a dissolved DAO stays reserved.
+//!
+//! ### Nested DAOs
+//!
+//! DAO may be a signatory of another DAO (`Config::DeipAccountId` resolves it to the DAO key).
+//! Sub-DAO takes part in multi-sig decisions by dispatching calls as it's DAO key via `on_behalf`.
+//! If the sub-DAO may decide alone (it is the only signatory or the threshold is one)
+//! it's authority is allowed to call `on_behalf` of the parent DAO directly.
+//! Such delegation is checked recursively up to `Config::MaxNestingDepth` levels.
+//!
+//! ### Dissolution
+//!
+//! Dissolved DAO is removed with all it's roles, budgets, motions and invitations,
+//! it's metadata history is kept.
+//! It's ID stays reserved: `on_behalf` calls and conversions of the DAO ID
+//! to the DAO key are rejected. Native balance and assets of the DAO key
+//! are swept to beneficiaries (by `Config::SweepAssets`) before dissolution.
+//! Every holding of the DAO key should be listed: dissolution is rejected
+//! if anything is left on the DAO key after the sweep.
+//! The check scans all assets so `dissolve` takes the number of assets as a witness.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
@@ -47,9 +124,12 @@ pub mod pallet {
     use sp_std::prelude::*;
     use sp_std::collections::{btree_map::BTreeMap};
     use sp_std::iter::FromIterator;
+    use sp_std::convert::TryInto;
     
-    use sp_runtime::{MultiSigner, traits::{Dispatchable, IdentifyAccount}};
+    use sp_runtime::{MultiSigner, traits::{Dispatchable, IdentifyAccount, AtLeast32BitUnsigned, Saturating, Zero}};
     use frame_support::dispatch::DispatchResult;
+    use frame_support::transactional;
+    use frame_support::storage::{with_transaction, TransactionOutcome};
     
     use sp_core::H256;
     
@@ -57,7 +137,7 @@ pub mod pallet {
 
     /// Configuration trait
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config + pallet_timestamp::Config {
         /// Type represents events
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         /// Type represents particular call from batch-transaction 
@@ -68,6 +148,27 @@ pub mod pallet {
              UnfilteredDispatchable<Origin = Self::Origin> +
              frame_support::dispatch::Codec + 
              IsSubType<Call<Self>>;
+        /// Runtime defined predicates on calls that may be allowed for DAO roles
+        type CallPredicates: CallPredicates<<Self as Config>::Call>;
+        /// Asset identifier used in DAO budgets
+        type AssetId: Member + Parameter;
+        /// Balance used in DAO budgets for both native currency and assets
+        type Balance: Member + Parameter + AtLeast32BitUnsigned + Copy;
+        /// Runtime defined extraction of DAO assets spent by calls
+        type SpendingCalls: SpendingCalls<<Self as Config>::Call, Self::AssetId, Self::Balance>;
+        /// Runtime defined balances of tokens used for weighted voting
+        type TokenBalances: TokenBalances<Self::AccountId, Self::AssetId, Self::Balance>;
+        /// Runtime defined transfers of whole DAO balances on dissolution
+        type SweepAssets: SweepAssets<Self::AccountId, Self::AssetId>;
+        /// Account that may be a DAO signatory: plain account or another DAO
+        type DeipAccountId: TryInto<Self::AccountId> + Parameter + Member;
+        /// Max depth of sub-DAOs authorising `on_behalf` calls of the DAO.
+        /// Zero allows only the DAO authority itself
+        #[pallet::constant]
+        type MaxNestingDepth: Get<u32>;
+        /// Min number of blocks between DAO handle changes
+        #[pallet::constant]
+        type HandleRenameCooldown: Get<Self::BlockNumber>;
     }
     
     #[doc(hidden)]
@@ -88,7 +189,57 @@ pub mod pallet {
         /// Access denied
         Forbidden,
         ///
-        AuthorityMismatch
+        AuthorityMismatch,
+        /// Role not found
+        RoleNotFound,
+        /// Budget not found
+        BudgetNotFound,
+        /// Spending exceeds remaining amount of budgets
+        BudgetExceeded,
+        /// DAO has no voting governance
+        GovernanceNotSet,
+        /// Voting period of governance should be non-zero
+        InvalidGovernance,
+        /// Motion already exists
+        MotionExists,
+        /// Motion not found
+        MotionNotFound,
+        /// Voting period of the motion is over
+        MotionVotingEnded,
+        /// Voting period of the motion is not over yet
+        MotionVotingNotEnded,
+        /// Member may be added to authority only by accepting an invitation
+        InvitationRequired,
+        /// Account is a member of DAO authority already
+        AlreadyMember,
+        /// Pending invitation already exists
+        InvitationExists,
+        /// Invitation not found
+        InvitationNotFound,
+        /// Invitation is expired
+        InvitationExpired,
+        /// DAO is dissolved
+        Dissolved,
+        /// Account can't be resolved (e.g. DAO is dissolved)
+        DeipAccountIdInvalid,
+        /// Field of metadata record exceeds `MAX_METADATA_FIELD_LENGTH`
+        MetadataTooLong,
+        /// Handle has wrong length or characters
+        HandleInvalid,
+        /// Handle belongs to another DAO
+        HandleTaken,
+        /// Handle is reserved
+        HandleReserved,
+        /// Handle is not reserved
+        HandleNotReserved,
+        /// Handle was changed less than `HandleRenameCooldown` blocks ago
+        HandleRenameCooldown,
+        /// DAO key still holds native balance or assets not listed for the sweep
+        AssetsNotSwept,
+        /// Account is not a member of DAO authority
+        NotMember,
+        /// There are more assets to check for DAO key holdings than the witness allows
+        AssetsWitnessTooLow,
     }
     
     #[pallet::event]
@@ -100,16 +251,64 @@ pub mod pallet {
         /// Emits when authority alteration
         DaoAlterAuthority(DaoOf<T>),
         DaoMetadataUpdated(DaoOf<T>),
+        /// Emits when role created or it's allowed calls replaced
+        DaoRoleSet(DaoId, RoleId),
+        /// Emits when role removed
+        DaoRoleRemoved(DaoId, RoleId),
+        /// Emits when role assigned to member: (DAO, role, member)
+        DaoRoleAssigned(DaoId, RoleId, T::AccountId),
+        /// Emits when role revoked from member: (DAO, role, member)
+        DaoRoleRevoked(DaoId, RoleId, T::AccountId),
+        /// Emits when budget created or replaced
+        DaoBudgetSet(DaoId, BudgetId),
+        /// Emits when budget removed
+        DaoBudgetRemoved(DaoId, BudgetId),
+        /// Emits when nothing left to spend in the current period of budget
+        DaoBudgetExhausted(DaoId, BudgetId),
+        /// Emits when voting governance enabled, replaced or disabled
+        DaoGovernanceSet(DaoId),
+        /// Emits when motion proposed: (DAO, motion, proposer)
+        DaoMotionProposed(DaoId, MotionId, T::AccountId),
+        /// Emits when member voted: (DAO, motion, voter, aye)
+        DaoMotionVoted(DaoId, MotionId, T::AccountId, bool),
+        /// Emits when passed motion dispatched: (DAO, motion, dispatch result)
+        DaoMotionExecuted(DaoId, MotionId, DispatchResult),
+        /// Emits when motion did not pass
+        DaoMotionRejected(DaoId, MotionId),
+        /// Emits when account invited: (DAO, invitee, expiry block)
+        DaoMemberInvited(DaoId, T::AccountId, T::BlockNumber),
+        /// Emits when invitation cancelled by DAO: (DAO, invitee)
+        DaoInvitationCancelled(DaoId, T::AccountId),
+        /// Emits when invitee accepted invitation and joined DAO authority: (DAO, invitee)
+        DaoInvitationAccepted(DaoId, T::AccountId),
+        /// Emits when invitee declined invitation: (DAO, invitee)
+        DaoInvitationDeclined(DaoId, T::AccountId),
+        /// Emits when DAO assets swept and DAO dissolved
+        DaoDissolved(DaoId),
+        /// Emits when DAO handle set or renamed
+        DaoHandleSet(DaoId, DaoHandle),
+        /// Emits when handle reserved
+        DaoHandleReserved(DaoHandle),
+        /// Emits when reserved handle released
+        DaoHandleReleased(DaoHandle),
     }
     
     #[doc(hidden)]
     #[pallet::genesis_config]
     #[derive(Default)]
-    pub struct GenesisConfig {}
+    pub struct GenesisConfig {
+        /// Handles that can't be taken by DAOs
+        pub reserved_handles: Vec<DaoHandle>,
+    }
     
     #[pallet::genesis_build]
     impl<T: Config> GenesisBuild<T> for GenesisConfig {
-        fn build(&self) {}
+        fn build(&self) {
+            for handle in &self.reserved_handles {
+                assert!(is_valid_handle(handle), "Invalid reserved handle");
+                ReservedHandles::<T>::insert(handle, ());
+            }
+        }
     }
     
     use dao::*;
@@ -123,6 +322,8 @@ pub mod pallet {
         use frame_system::Key;
         use codec::Codec;
         use sp_core::H256;
+        use sp_runtime::Perbill;
+        use super::DaoMetadata;
 
         #[allow(type_alias_bounds)]
         pub type DaoOf<T: Config> = Dao<T::AccountId, DaoId>;
@@ -213,6 +414,11 @@ pub mod pallet {
         }
         impl<T: Config> AssertAuthority<T> for InputAuthority<T::AccountId> {
             
+        }
+        impl<AccountId> Authority<AccountId> {
+            pub fn signatories(&self) -> &[AccountId] { &self.signatories }
+            /// Number of signatories required. Zero for plain account
+            pub fn threshold(&self) -> u16 { self.threshold }
         }
         impl<AccountId: Codec + Default + Clone> Authority<AccountId> {
             pub fn authority_key(&self) -> AccountId {
@@ -221,16 +427,25 @@ pub mod pallet {
             }
         }
         impl<AccountId: Ord + Eq + PartialEq> Authority<AccountId> {
-            pub fn add_member(&mut self, member: AccountId) {
+            /// Add member and re-apply threshold policy if any
+            pub fn add_member(&mut self, member: AccountId, policy: Option<&ThresholdPolicy>) {
                 if let Err(pos) = self.signatories.binary_search(&member) {
                     self.signatories.insert(pos, member);
-                    self.threshold += 1;
+                    match policy {
+                        Some(x) => self.apply_policy(x),
+                        None => self.threshold += 1,
+                    }
                 }
             }
-            pub fn remove_member(&mut self, member: AccountId) {
+            /// Remove member and re-apply threshold policy if any
+            pub fn remove_member(&mut self, member: AccountId, policy: Option<&ThresholdPolicy>) {
                 if self.signatories.len() == 1 { return }
                 if let Ok(pos) = self.signatories.binary_search(&member) {
                     self.signatories.remove(pos);
+                    if let Some(x) = policy {
+                        self.apply_policy(x);
+                        return
+                    }
                     if self.signatories.len() == 1 {
                         self.threshold = 0;
                         return
@@ -240,6 +455,55 @@ pub mod pallet {
                     }
                 }
             }
+            /// Set threshold for current signatories
+            pub fn set_threshold(&mut self, threshold: u16) -> Result<(), AuthorityAssert> {
+                if self.signatories.len() == 1 {
+                    ensure!(threshold == 0, AuthorityAssert::PlainAccountExpect);
+                } else {
+                    ensure!(
+                        threshold > 0 && threshold as usize <= self.signatories.len(),
+                        AuthorityAssert::ThresholdMismatch
+                    );
+                }
+                self.threshold = threshold;
+                Ok(())
+            }
+            pub fn apply_policy(&mut self, policy: &ThresholdPolicy) {
+                self.threshold = policy.threshold(self.signatories.len());
+            }
+        }
+        
+        /// Rule to compute threshold of multi-sig authority on every membership change
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub enum ThresholdPolicy {
+            /// Fixed number of signatures, limited by number of signatories
+            Fixed(u16),
+            /// Fraction of signatories rounded up
+            Fraction(Perbill),
+            /// All signatories
+            Unanimous,
+        }
+        impl ThresholdPolicy {
+            /// Threshold for given number of signatories. Zero for plain account
+            pub fn threshold(&self, signatories: usize) -> u16 {
+                let signatories = signatories.min(u16::MAX as usize) as u16;
+                if signatories <= 1 { return 0 }
+                let threshold = match self {
+                    Self::Fixed(m) => *m,
+                    Self::Fraction(x) => x.mul_ceil(signatories as u32) as u16,
+                    Self::Unanimous => signatories,
+                };
+                threshold.max(1).min(signatories)
+            }
+        }
+        impl<Account> InputAuthority<Account> {
+            /// Convert signatories
+            pub fn try_map_account<A, E>(self, f: impl Fn(Account) -> Result<A, E>) -> Result<InputAuthority<A>, E> {
+                let Self { signatories, threshold } = self;
+                let signatories = signatories.into_iter().map(f).collect::<Result<_, E>>()?;
+                Ok(InputAuthority { signatories, threshold })
+            }
         }
         impl<AccountId: Codec + Default + Clone + Ord + Eq + PartialEq> InputAuthority<AccountId> {
             pub(crate) fn assert(self, authority_key: &AccountId) -> Result<Authority<AccountId>, AuthorityAssert>
@@ -277,7 +541,11 @@ pub mod pallet {
             /// Must be generated internally when DAO will be created,
             /// nobody knows private half of this key
             dao_key: AccountId,
-            metadata: Option<H256>
+            metadata: Option<H256>,
+            /// Re-applied on every membership change if set,
+            /// otherwise threshold grows and shrinks with number of signatories
+            threshold_policy: Option<ThresholdPolicy>,
+            metadata_record: Option<DaoMetadata>,
         }
         impl<AccountId, Id> Dao<AccountId, Id> {
             pub fn new(
@@ -289,13 +557,16 @@ pub mod pallet {
             )
                 -> Self
             {
-                Self { authority_key, authority, id, dao_key, metadata }
+                Self { authority_key, authority, id, dao_key, metadata, threshold_policy: None, metadata_record: None }
             }
             
             pub fn authority_key(&self) -> &AccountId { &self.authority_key }
             pub fn authority(&self) -> &Authority<AccountId>{ &self.authority }
             pub fn id(&self) -> &Id { &self.id }
             pub fn dao_key(&self) -> &AccountId { &self.dao_key }
+            pub fn threshold_policy(&self) -> Option<&ThresholdPolicy> { self.threshold_policy.as_ref() }
+            pub fn metadata(&self) -> Option<&H256> { self.metadata.as_ref() }
+            pub fn metadata_record(&self) -> Option<&DaoMetadata> { self.metadata_record.as_ref() }
             
             pub fn alter_authoriry(self, op: AlterAuthority<AccountId>) -> Result<Self, AuthorityAssert>
                 where
@@ -305,26 +576,45 @@ pub mod pallet {
                     mut authority,
                     id,
                     dao_key,
-                    metadata
+                    metadata,
+                    mut threshold_policy,
+                    metadata_record,
                 } = self;
                 match op {
                     AlterAuthority::AddMember { member } => {
-                        authority.add_member(member);
+                        authority.add_member(member, threshold_policy.as_ref());
                     },
                     AlterAuthority::RemoveMember { member } => {
-                        authority.remove_member(member);
+                        authority.remove_member(member, threshold_policy.as_ref());
                     },
                     AlterAuthority::ReplaceAuthority { authority_key: new_authority_key, authority: new_authority } => {
                         authority = new_authority.assert(&new_authority_key)?;
                     },
+                    AlterAuthority::SetThreshold { threshold } => {
+                        authority.set_threshold(threshold)?;
+                    },
+                    AlterAuthority::SetThresholdPolicy { policy } => {
+                        if let Some(ref x) = policy {
+                            authority.apply_policy(x);
+                        }
+                        threshold_policy = policy;
+                    },
                 }
-                Ok(Self::new(authority.authority_key(), authority, id, dao_key, metadata))
+                let mut dao = Self::new(authority.authority_key(), authority, id, dao_key, metadata);
+                dao.threshold_policy = threshold_policy;
+                dao.metadata_record = metadata_record;
+                Ok(dao)
             }
 
             pub fn update_metadata(mut self, new_metadata: Option<H256>,) -> Self {
                 self.metadata = new_metadata;
                 self
             }
+
+            pub fn update_metadata_record(mut self, record: Option<DaoMetadata>) -> Self {
+                self.metadata_record = record;
+                self
+            }
         }
         
         #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
@@ -333,159 +623,1595 @@ pub mod pallet {
         pub enum AlterAuthority<AccountId> {
             AddMember { member: AccountId },
             RemoveMember { member: AccountId},
-            ReplaceAuthority { authority_key: AccountId, authority: InputAuthority<AccountId> }
+            ReplaceAuthority { authority_key: AccountId, authority: InputAuthority<AccountId> },
+            /// Change threshold keeping signatories
+            SetThreshold { threshold: u16 },
+            /// Set policy and apply it to current signatories or unset it
+            SetThresholdPolicy { policy: Option<ThresholdPolicy> },
+        }
+        impl<Account> AlterAuthority<Account> {
+            /// Convert accounts of the operation
+            pub fn try_map_account<A, E>(self, f: impl Fn(Account) -> Result<A, E>) -> Result<AlterAuthority<A>, E> {
+                Ok(match self {
+                    Self::AddMember { member } => AlterAuthority::AddMember { member: f(member)? },
+                    Self::RemoveMember { member } => AlterAuthority::RemoveMember { member: f(member)? },
+                    Self::ReplaceAuthority { authority_key, authority } => AlterAuthority::ReplaceAuthority {
+                        authority_key: f(authority_key)?,
+                        authority: authority.try_map_account(f)?,
+                    },
+                    Self::SetThreshold { threshold } => AlterAuthority::SetThreshold { threshold },
+                    Self::SetThresholdPolicy { policy } => AlterAuthority::SetThresholdPolicy { policy },
+                })
+            }
         }
     }
     
-    impl<T: Config> Pallet<T> {
-        pub fn dao_key(dao_id: &DaoId) -> T::AccountId {
-            dao_key::<T::AccountId>(dao_id)
+    use role::*;
+    pub mod role {
+        use sp_std::prelude::*;
+        use frame_support::pallet_prelude::*;
+        
+        #[cfg(feature = "std")]
+        use serde::{Serialize, Deserialize};
+        
+        pub type RoleId = sp_core::H160;
+        /// ID of the call predicate defined by runtime
+        pub type CallPredicateId = u32;
+        
+        #[allow(type_alias_bounds)]
+        pub type RoleOf<T: super::Config> = Role<T::AccountId>;
+        
+        /// Filter of calls allowed for a role
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub enum CallFilter {
+            /// Any call of the pallet
+            Pallet { pallet_index: u8 },
+            /// Particular call of the pallet
+            Call { pallet_index: u8, call_index: u8 },
+            /// Calls matched by the runtime defined predicate
+            Predicate(CallPredicateId),
+        }
+        
+        /// Runtime defined predicates on calls
+        pub trait CallPredicates<Call> {
+            fn matches(predicate: CallPredicateId, call: &Call) -> bool;
+        }
+        impl<Call> CallPredicates<Call> for () {
+            fn matches(_predicate: CallPredicateId, _call: &Call) -> bool { false }
+        }
+        
+        /// DAO role: calls that members of the role may dispatch on behalf of DAO
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct Role<AccountId> {
+            filters: Vec<CallFilter>,
+            /// Sorted member accounts
+            members: Vec<AccountId>,
+        }
+        impl<AccountId> Role<AccountId> {
+            pub fn new(filters: Vec<CallFilter>) -> Self {
+                Self { filters, members: Vec::new() }
+            }
+            pub fn filters(&self) -> &[CallFilter] { &self.filters }
+            pub fn members(&self) -> &[AccountId] { &self.members }
+            
+            pub fn set_filters(&mut self, filters: Vec<CallFilter>) {
+                self.filters = filters;
+            }
+            
+            /// Check that call is allowed by any of role filters.
+            /// Pallet and call indices are the first two bytes of the encoded runtime call
+            pub fn allows<Call: Encode, P: CallPredicates<Call>>(&self, call: &Call) -> bool {
+                let (pallet, index) = call.using_encoded(|x| (x.first().copied(), x.get(1).copied()));
+                self.filters.iter().any(|x| match x {
+                    CallFilter::Pallet { pallet_index } => {
+                        pallet == Some(*pallet_index)
+                    },
+                    CallFilter::Call { pallet_index, call_index } => {
+                        pallet == Some(*pallet_index) && index == Some(*call_index)
+                    },
+                    CallFilter::Predicate(predicate) => P::matches(*predicate, call),
+                })
+            }
+        }
+        impl<AccountId: Ord> Role<AccountId> {
+            /// Returns `false` if already assigned
+            pub fn assign(&mut self, member: AccountId) -> bool {
+                match self.members.binary_search(&member) {
+                    Ok(_) => false,
+                    Err(pos) => { self.members.insert(pos, member); true },
+                }
+            }
+            /// Returns `false` if not assigned
+            pub fn revoke(&mut self, member: &AccountId) -> bool {
+                match self.members.binary_search(member) {
+                    Ok(pos) => { self.members.remove(pos); true },
+                    Err(_) => false,
+                }
+            }
         }
-    }
-    pub fn dao_key<T: Decode + Default>(dao_id: &DaoId) -> T {
-        let entropy = (b"deip/DAOs/", dao_id.as_bytes()).using_encoded(sp_io::hashing::blake2_256);
-        T::decode(&mut &entropy[..]).unwrap_or_default()
-    }
-    pub fn dao_key2<T: frame_system::Config>(dao_id: &DaoId) -> T::AccountId {
-        dao_key::<T::AccountId>(dao_id)
     }
     
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    use budget::*;
+    pub mod budget {
+        use sp_std::prelude::*;
+        use frame_support::pallet_prelude::*;
+        use frame_support::dispatch::DispatchResult;
+        use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating, Zero};
+        use super::{Config, RoleId};
         
-        #[pallet::weight(10_000)]
-        pub fn create(
-            origin: OriginFor<T>,
-            name: DaoId,
-            authority: InputAuthority<T::AccountId>,
-            metadata: Option<H256>,
-        )
-            -> DispatchResultWithPostInfo
-        {
-            let authority_key = ensure_signed(origin)?;
-            let authority = authority.assert(&authority_key).map_err::<Error<T>, _>(Into::into)?;
-            ensure!(!DaoRepository::<T>::contains_key(&name), Error::<T>::Exists);
-            let dao_key = Self::dao_key(&name);
-            let dao = DaoOf::<T>::new(
-                authority_key,
-                authority,
-                name,
-                dao_key,
-                metadata,
-            );
-            StorageOpsTransaction::<StorageOps<T>>::new()
-                .commit(move |ops| {
-                    ops.push_op(StorageOps::CreateDao(dao.clone()));
-                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoCreate(dao)));
-                });
-            Ok(Some(0).into())
+        #[cfg(feature = "std")]
+        use serde::{Serialize, Deserialize};
+        
+        pub type BudgetId = sp_core::H160;
+        
+        #[allow(type_alias_bounds)]
+        pub type BudgetOf<T: Config> = Budget<T::AccountId, T::AssetId, T::Balance, T::BlockNumber>;
+        #[allow(type_alias_bounds)]
+        pub type SpendingOf<T: Config> = Spending<T::AssetId, T::Balance>;
+        
+        /// Asset of DAO budget
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub enum BudgetAsset<AssetId> {
+            /// Native currency
+            Native,
+            /// Asset of `pallet_deip_assets`
+            Asset(AssetId),
         }
         
-        #[pallet::weight(10_000)]
-        pub fn alter_authority(
-            origin: OriginFor<T>,
-            alter_authority: AlterAuthority<T::AccountId>,
-        )
-            -> DispatchResultWithPostInfo
-        {
-            let who = ensure_signed(origin)?;
-            let mut dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
-            dao = dao.alter_authoriry(alter_authority).map_err::<Error<T>, _>(Into::into)?;
-            StorageOpsTransaction::<StorageOps<T>>::new()
-                .commit(move |ops| {
-                    ops.push_op(StorageOps::UpdateDao(dao.clone()));
-                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAlterAuthority(dao)));
-                });
-            Ok(Some(0).into())
+        /// Who may spend DAO budget
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub enum BudgetSpender<AccountId> {
+            /// Single member account
+            Member(AccountId),
+            /// Any member of the DAO role
+            Role(RoleId),
         }
-
-        #[pallet::weight(10_000)]
-        pub fn update_dao(
-            origin: OriginFor<T>,
-            new_metadata: Option<H256>,
-        ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-            let mut dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
-            dao = dao.update_metadata(new_metadata);
-            StorageOpsTransaction::<StorageOps<T>>::new()
-                .commit(move |ops| {
-                    ops.push_op(StorageOps::UpdateDao(dao.clone()));
-                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoMetadataUpdated(dao)));
-                });
-
-            Ok(None.into())
+        
+        /// Amount of DAO asset spent by a call
+        #[derive(Debug, Clone, Eq, PartialEq)]
+        pub struct Spending<AssetId, Balance> {
+            pub asset: BudgetAsset<AssetId>,
+            pub amount: Balance,
         }
-
-        #[pallet::weight(10_000)]
-        pub fn on_behalf(
-            origin: OriginFor<T>,
-            name: DaoId,
-            call: Box<<T as Config>::Call>,
-        ) -> DispatchResultWithPostInfo
+        
+        /// Runtime defined extraction of DAO assets spent by calls
+        pub trait SpendingCalls<Call, AssetId, Balance> {
+            /// Assets spent by the call. `None` if the call is not a spending,
+            /// a batch is a spending only if every call in it is
+            fn spendings(call: &Call) -> Option<Vec<Spending<AssetId, Balance>>>;
+        }
+        impl<Call, AssetId, Balance> SpendingCalls<Call, AssetId, Balance> for () {
+            fn spendings(_call: &Call) -> Option<Vec<Spending<AssetId, Balance>>> { None }
+        }
+        
+        /// Runtime defined transfer of whole balance of DAO asset
+        pub trait SweepAssets<AccountId, AssetId> {
+            /// Transfer whole balance of the asset
+            fn sweep(asset: &BudgetAsset<AssetId>, from: &AccountId, to: &AccountId) -> DispatchResult;
+            /// Assets with non-zero balance of the account (native free balance included).
+            /// `None` if there are more than `max_assets` assets to check
+            fn holdings(who: &AccountId, max_assets: u32) -> Option<Vec<BudgetAsset<AssetId>>>;
+        }
+        
+        /// Periodic allowance to spend DAO asset without authority approval
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct Budget<AccountId, AssetId, Balance, BlockNumber> {
+            spender: BudgetSpender<AccountId>,
+            asset: BudgetAsset<AssetId>,
+            /// Amount allowed to spend per period
+            limit: Balance,
+            /// Period length in blocks. Budget with zero period never renews
+            period: BlockNumber,
+            /// First block of the current period
+            period_start: BlockNumber,
+            /// Amount spent in the current period
+            spent: Balance,
+        }
+        impl<AccountId, AssetId, Balance, BlockNumber> Budget<AccountId, AssetId, Balance, BlockNumber> {
+            pub fn spender(&self) -> &BudgetSpender<AccountId> { &self.spender }
+            pub fn asset(&self) -> &BudgetAsset<AssetId> { &self.asset }
+            pub fn limit(&self) -> &Balance { &self.limit }
+            pub fn period(&self) -> &BlockNumber { &self.period }
+            pub fn period_start(&self) -> &BlockNumber { &self.period_start }
+            pub fn spent(&self) -> &Balance { &self.spent }
+        }
+        impl<AccountId, AssetId, Balance, BlockNumber> Budget<AccountId, AssetId, Balance, BlockNumber>
+            where
+                Balance: AtLeast32BitUnsigned + Copy,
+                BlockNumber: AtLeast32BitUnsigned + Copy
         {
-            let who = ensure_signed(origin)?;
-            let dao = load_dao::<T>(LoadBy::DaoId {
-                id: &name,
-                who: KeyType::Members(&who)
-            })?;
-            call.dispatch(RawOrigin::Signed(dao.dao_key().clone()).into())
+            pub fn new(
+                spender: BudgetSpender<AccountId>,
+                asset: BudgetAsset<AssetId>,
+                limit: Balance,
+                period: BlockNumber,
+                now: BlockNumber,
+            )
+                -> Self
+            {
+                Self { spender, asset, limit, period, period_start: now, spent: Zero::zero() }
+            }
+            
+            /// Start a new period if the current one is over
+            pub fn renew(&mut self, now: BlockNumber) {
+                if self.period.is_zero() || now < self.period_start.saturating_add(self.period) {
+                    return
+                }
+                let elapsed_periods = (now - self.period_start) / self.period;
+                self.period_start = self.period_start.saturating_add(elapsed_periods.saturating_mul(self.period));
+                self.spent = Zero::zero();
+            }
+            
+            pub fn remaining(&self) -> Balance { self.limit.saturating_sub(self.spent) }
+            
+            pub fn is_exhausted(&self) -> bool { self.remaining().is_zero() }
+            
+            /// Returns `false` if amount exceeds the remaining one
+            pub fn spend(&mut self, amount: Balance) -> bool {
+                if amount > self.remaining() { return false }
+                self.spent = self.spent.saturating_add(amount);
+                true
+            }
         }
     }
     
-    // ==== Storage ====:
-    
-    #[pallet::storage]
-    #[pallet::getter(fn get_dao)]
-    pub(super) type DaoRepository<T: Config> = StorageMap<_,
-        Blake2_128Concat,
-        DaoId,
-        DaoOf<T>,
-        OptionQuery
-    >;
-    
-    #[pallet::storage]
-    #[pallet::getter(fn lookup_dao)]
-    pub(super) type DaoLookup<T: Config> = StorageMap<_,
-        Blake2_128Concat,
-        T::AccountId,
-        DaoId,
-        OptionQuery
-    >;
+    use invitation::*;
+    pub mod invitation {
+        use frame_support::pallet_prelude::*;
+        use super::Config;
+        
+        #[cfg(feature = "std")]
+        use serde::{Serialize, Deserialize};
+        
+        #[allow(type_alias_bounds)]
+        pub type InvitationOf<T: Config> = Invitation<T::BlockNumber>;
+        
+        /// Invitation to join DAO authority
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct Invitation<BlockNumber> {
+            /// Last block when invitation may be accepted
+            expires_at: BlockNumber,
+        }
+        impl<BlockNumber: PartialOrd> Invitation<BlockNumber> {
+            pub fn new(expires_at: BlockNumber) -> Self {
+                Self { expires_at }
+            }
+            pub fn expires_at(&self) -> &BlockNumber { &self.expires_at }
+            pub fn is_expired(&self, now: &BlockNumber) -> bool {
+                *now > self.expires_at
+            }
+        }
+    }
     
-    use storage_ops::*;
-    #[doc(no_inline)]
-    /// Module contains abstractions over pallet storage operations
-    pub mod storage_ops {
+    use metadata::*;
+    pub mod metadata {
         use sp_std::prelude::*;
-        use pallet_deip_toolkit::storage_ops::StorageOp;
-        use super::{Config, Event, Pallet};
-        use super::{DaoOf, DaoRepository, DaoLookup};
-
-        /// Storage operations
-        pub enum StorageOps<T: Config> {
-            /// Deposit event
-            DepositEvent(Event<T>),
-            /// Create DAO
-            CreateDao(DaoOf<T>),
-            /// Update DAO
-            UpdateDao(DaoOf<T>),
-            
+        use frame_support::pallet_prelude::*;
+        use sp_core::H256;
+        use super::Config;
+        
+        #[cfg(feature = "std")]
+        use serde::{Serialize, Deserialize};
+        
+        /// Max length of name and website of metadata record
+        pub const MAX_METADATA_FIELD_LENGTH: usize = 256;
+        
+        #[allow(type_alias_bounds)]
+        pub type MetadataRevisionOf<T: Config> = MetadataRevision<T::AccountId, T::BlockNumber, T::Moment>;
+        
+        /// Typed metadata of DAO
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct DaoMetadata {
+            pub name: Vec<u8>,
+            /// Hash of the description stored off-chain
+            pub description: Option<H256>,
+            /// Hash of the logo stored off-chain
+            pub logo: Option<H256>,
+            pub website: Option<Vec<u8>>,
         }
-        impl<T: Config> StorageOp for StorageOps<T> {
-            fn exec(self) {
-                match self {
-                    Self::DepositEvent(e) => {
-                        Pallet::<T>::deposit_event(e)
-                    },
-                    Self::CreateDao(dao) => {
-                        DaoLookup::<T>::insert(dao.dao_key().clone(), dao.id().clone());
-                        DaoRepository::<T>::insert(*dao.id(), dao);
-                    }
-                    Self::UpdateDao(dao) => {
-                        DaoRepository::<T>::insert(*dao.id(), dao);
-                    }
+        impl DaoMetadata {
+            pub fn is_valid(&self) -> bool {
+                self.name.len() <= MAX_METADATA_FIELD_LENGTH
+                    && self.website.as_ref().map_or(0, |x| x.len()) <= MAX_METADATA_FIELD_LENGTH
+            }
+        }
+        
+        /// State of DAO metadata after the change
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct MetadataRevision<AccountId, BlockNumber, Moment> {
+            metadata: Option<H256>,
+            record: Option<DaoMetadata>,
+            /// Block of the change
+            block: BlockNumber,
+            /// Timestamp of the block
+            timestamp: Moment,
+            /// Account submitted the change
+            author: AccountId,
+        }
+        impl<AccountId, BlockNumber, Moment> MetadataRevision<AccountId, BlockNumber, Moment> {
+            pub fn new(
+                metadata: Option<H256>,
+                record: Option<DaoMetadata>,
+                block: BlockNumber,
+                timestamp: Moment,
+                author: AccountId,
+            )
+                -> Self
+            {
+                Self { metadata, record, block, timestamp, author }
+            }
+            pub fn metadata(&self) -> Option<&H256> { self.metadata.as_ref() }
+            pub fn record(&self) -> Option<&DaoMetadata> { self.record.as_ref() }
+            pub fn block(&self) -> &BlockNumber { &self.block }
+            pub fn timestamp(&self) -> &Moment { &self.timestamp }
+            pub fn author(&self) -> &AccountId { &self.author }
+        }
+    }
+    
+    use handle::*;
+    pub mod handle {
+        use sp_std::prelude::*;
+        use frame_support::pallet_prelude::*;
+        use sp_runtime::traits::Saturating;
+        use super::Config;
+        
+        #[cfg(feature = "std")]
+        use serde::{Serialize, Deserialize};
+        
+        pub type DaoHandle = Vec<u8>;
+        
+        pub const MIN_HANDLE_LENGTH: usize = 3;
+        pub const MAX_HANDLE_LENGTH: usize = 32;
+        
+        #[allow(type_alias_bounds)]
+        pub type HandleRecordOf<T: Config> = HandleRecord<T::BlockNumber>;
+        
+        /// Lowercase latin letters, digits, `-` and `_`
+        /// starting and ending with a letter or digit
+        pub fn is_valid_handle(handle: &[u8]) -> bool {
+            let is_alphanumeric = |x: &u8| x.is_ascii_lowercase() || x.is_ascii_digit();
+            (MIN_HANDLE_LENGTH..=MAX_HANDLE_LENGTH).contains(&handle.len())
+                && handle.iter().all(|x| is_alphanumeric(x) || *x == b'-' || *x == b'_')
+                && handle.first().filter(|x| is_alphanumeric(x)).is_some()
+                && handle.last().filter(|x| is_alphanumeric(x)).is_some()
+        }
+        
+        /// Handle of DAO
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct HandleRecord<BlockNumber> {
+            handle: DaoHandle,
+            /// Block the handle was set at
+            set_at: BlockNumber,
+        }
+        impl<BlockNumber: Copy + Saturating + PartialOrd> HandleRecord<BlockNumber> {
+            pub fn new(handle: DaoHandle, set_at: BlockNumber) -> Self {
+                Self { handle, set_at }
+            }
+            pub fn handle(&self) -> &DaoHandle { &self.handle }
+            pub fn set_at(&self) -> &BlockNumber { &self.set_at }
+            
+            pub fn can_rename(&self, now: BlockNumber, cooldown: BlockNumber) -> bool {
+                now >= self.set_at.saturating_add(cooldown)
+            }
+        }
+    }
+    
+    use governance::*;
+    pub mod governance {
+        use sp_std::prelude::*;
+        use frame_support::pallet_prelude::*;
+        use sp_runtime::{Perbill, traits::{AtLeast32BitUnsigned, Saturating, Zero}};
+        use super::Config;
+        
+        #[cfg(feature = "std")]
+        use serde::{Serialize, Deserialize};
+        
+        pub type MotionId = sp_core::H160;
+        
+        #[allow(type_alias_bounds)]
+        pub type GovernanceOf<T: Config> = Governance<T::AssetId, T::BlockNumber>;
+        #[allow(type_alias_bounds)]
+        pub type MotionOf<T: Config> = Motion<T::AccountId, Box<<T as Config>::Call>, T::BlockNumber, T::Balance>;
+        
+        /// Weight of DAO members votes
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub enum VoteWeighting<AssetId> {
+            /// One vote per signatory of DAO authority
+            Member,
+            /// Votes of signatories of DAO authority weighted by balance of the project token
+            Token(AssetId),
+        }
+        
+        /// Runtime defined balances of tokens used for weighted voting
+        pub trait TokenBalances<AccountId, AssetId, Balance> {
+            fn balance(asset: &AssetId, who: &AccountId) -> Balance;
+            fn total_supply(asset: &AssetId) -> Balance;
+        }
+        impl<AccountId, AssetId, Balance: Zero> TokenBalances<AccountId, AssetId, Balance> for () {
+            fn balance(_asset: &AssetId, _who: &AccountId) -> Balance { Zero::zero() }
+            fn total_supply(_asset: &AssetId) -> Balance { Zero::zero() }
+        }
+        
+        /// Voting governance of DAO
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct Governance<AssetId, BlockNumber> {
+            pub weighting: VoteWeighting<AssetId>,
+            /// Minimal share of the total votes weight that should be cast
+            pub quorum: Perbill,
+            /// Minimal share of "aye" votes weight among cast ones to pass a motion
+            pub threshold: Perbill,
+            /// Voting period of a motion in blocks
+            pub voting_period: BlockNumber,
+        }
+        impl<AssetId, BlockNumber> Governance<AssetId, BlockNumber> {
+            /// Check that cast votes pass a motion
+            pub fn passes<Balance: AtLeast32BitUnsigned + Copy>(
+                &self,
+                ayes: Balance,
+                nays: Balance,
+                total: Balance,
+            )
+                -> bool
+            {
+                let cast = ayes.saturating_add(nays);
+                !ayes.is_zero()
+                    && cast >= self.quorum.mul_ceil(total)
+                    && ayes >= self.threshold.mul_ceil(cast)
+            }
+        }
+        
+        /// Call to be dispatched on behalf of DAO if members vote for it
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct Motion<AccountId, Call, BlockNumber, Balance> {
+            call: Call,
+            proposer: AccountId,
+            /// Last block of voting period
+            end: BlockNumber,
+            /// Members voted for the motion with their vote weights, sorted by member
+            ayes: Vec<(AccountId, Balance)>,
+            /// Members voted against the motion with their vote weights, sorted by member
+            nays: Vec<(AccountId, Balance)>,
+        }
+        impl<AccountId, Call, BlockNumber, Balance> Motion<AccountId, Call, BlockNumber, Balance> {
+            pub fn call(&self) -> &Call { &self.call }
+            pub fn proposer(&self) -> &AccountId { &self.proposer }
+            pub fn end(&self) -> &BlockNumber { &self.end }
+            pub fn ayes(&self) -> &[(AccountId, Balance)] { &self.ayes }
+            pub fn nays(&self) -> &[(AccountId, Balance)] { &self.nays }
+            pub fn into_call(self) -> Call { self.call }
+        }
+        impl<AccountId: Ord + Clone, Call, BlockNumber, Balance: PartialEq> Motion<AccountId, Call, BlockNumber, Balance> {
+            /// New motion voted for by the proposer with the weight
+            pub fn new(call: Call, proposer: AccountId, weight: Balance, end: BlockNumber) -> Self {
+                Self { call, ayes: vec![(proposer.clone(), weight)], proposer, end, nays: Vec::new() }
+            }
+            
+            /// Vote with the weight of the voter at the moment.
+            /// Returns `false` if vote is not changed
+            pub fn vote(&mut self, who: AccountId, weight: Balance, aye: bool) -> bool {
+                let (add_to, remove_from) = if aye {
+                    (&mut self.ayes, &mut self.nays)
+                } else {
+                    (&mut self.nays, &mut self.ayes)
+                };
+                if let Ok(pos) = remove_from.binary_search_by(|(x, _)| x.cmp(&who)) {
+                    remove_from.remove(pos);
+                }
+                match add_to.binary_search_by(|(x, _)| x.cmp(&who)) {
+                    Ok(pos) if add_to[pos].1 == weight => false,
+                    Ok(pos) => { add_to[pos].1 = weight; true },
+                    Err(pos) => { add_to.insert(pos, (who, weight)); true },
+                }
+            }
+        }
+    }
+    
+    impl<T: Config> Pallet<T> {
+        pub fn dao_key(dao_id: &DaoId) -> T::AccountId {
+            dao_key::<T::AccountId>(dao_id)
+        }
+        
+        fn deip_account(account: T::DeipAccountId) -> Result<T::AccountId, Error<T>> {
+            account.try_into().map_err(|_| Error::<T>::DeipAccountIdInvalid)
+        }
+        
+        /// Check that account is the authority of DAO without voting governance
+        /// or of the sub-DAO that may decide alone on behalf of it (recursively up to `depth` levels)
+        fn is_authority(who: &T::AccountId, dao: &DaoOf<T>, depth: u32) -> bool {
+            if DaoGovernance::<T>::contains_key(dao.id()) {
+                return false
+            }
+            if MatchKey::<T>::match_key(&KeyType::members(who), dao) {
+                return true
+            }
+            if depth == 0 || dao.authority().threshold() > 1 {
+                return false
+            }
+            dao.authority().signatories().iter().any(|x| {
+                match DaoLookup::<T>::get(x).and_then(DaoRepository::<T>::get) {
+                    Some(sub_dao) => Self::is_authority(who, &sub_dao, depth - 1),
+                    None => false,
+                }
+            })
+        }
+        
+        /// Dispatch call as the DAO key remembering the account submitted it.
+        /// Nested dispatches keep the outermost submitter
+        fn dispatch_as_dao(
+            dao: &DaoOf<T>,
+            submitter: T::AccountId,
+            call: <T as Config>::Call,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let is_outermost = !Submitter::<T>::exists();
+            if is_outermost {
+                Submitter::<T>::put(submitter);
+            }
+            let result = call.dispatch(RawOrigin::Signed(dao.dao_key().clone()).into());
+            if is_outermost {
+                Submitter::<T>::kill();
+            }
+            result
+        }
+        
+        fn metadata_revision(dao: &DaoOf<T>, author: T::AccountId) -> MetadataRevisionOf<T> {
+            MetadataRevision::new(
+                dao.metadata().copied(),
+                dao.metadata_record().cloned(),
+                frame_system::Module::<T>::block_number(),
+                pallet_timestamp::Module::<T>::get(),
+                author,
+            )
+        }
+        
+        pub fn is_dissolved(dao_id: &DaoId) -> bool {
+            DissolvedDaos::<T>::contains_key(dao_id)
+        }
+        
+        /// Sweep all assets to their beneficiaries or none of them.
+        /// Fails if the DAO key still holds anything after the sweep
+        #[transactional]
+        fn sweep_assets(
+            dao_key: &T::AccountId,
+            beneficiaries: &[(BudgetAsset<T::AssetId>, T::AccountId)],
+            assets_witness: u32,
+        )
+            -> DispatchResult
+        {
+            for (asset, beneficiary) in beneficiaries {
+                T::SweepAssets::sweep(asset, dao_key, beneficiary)?;
+            }
+            let holdings = T::SweepAssets::holdings(dao_key, assets_witness)
+                .ok_or(Error::<T>::AssetsWitnessTooLow)?;
+            ensure!(holdings.is_empty(), Error::<T>::AssetsNotSwept);
+            Ok(())
+        }
+        
+        /// Weight of `dissolve`: sweep of every listed asset
+        /// and check of `assets_witness` assets for holdings left on the DAO key
+        fn dissolve_weight(beneficiaries: usize, assets_witness: u32) -> Weight {
+            let db = T::DbWeight::get();
+            (10_000 as Weight)
+                .saturating_add(db.reads_writes(3, 3).saturating_mul(beneficiaries as Weight))
+                .saturating_add(db.reads(2).saturating_mul(assets_witness as Weight))
+        }
+        
+        /// Check that any of member roles allows the call
+        fn role_allows(dao_id: &DaoId, who: &T::AccountId, call: &<T as Config>::Call) -> bool {
+            MemberRoles::<T>::get(dao_id, who).iter().any(|role_id| {
+                matches!(
+                    DaoRoles::<T>::get(dao_id, role_id),
+                    Some(role) if role.allows::<_, T::CallPredicates>(call)
+                )
+            })
+        }
+        
+        /// Vote weight of DAO member, zero for non-members
+        fn vote_weight(dao: &DaoOf<T>, weighting: &VoteWeighting<T::AssetId>, who: &T::AccountId) -> T::Balance {
+            if dao.authority().signatories().binary_search(who).is_err() {
+                return Zero::zero()
+            }
+            match weighting {
+                VoteWeighting::Member => 1u32.into(),
+                VoteWeighting::Token(asset) => T::TokenBalances::balance(asset, who),
+            }
+        }
+        
+        /// Total vote weight of DAO members
+        fn total_vote_weight(dao: &DaoOf<T>, weighting: &VoteWeighting<T::AssetId>) -> T::Balance {
+            match weighting {
+                VoteWeighting::Member => (dao.authority().signatories().len() as u32).into(),
+                VoteWeighting::Token(asset) => T::TokenBalances::total_supply(asset),
+            }
+        }
+        
+        /// Tally weights of votes limited by current weights of voters,
+        /// so tokens sold or moved to another voter after the vote are counted once at most
+        fn motion_passes(dao: &DaoOf<T>, governance: &GovernanceOf<T>, motion: &MotionOf<T>) -> bool {
+            let tally = |votes: &[(T::AccountId, T::Balance)]| votes.iter()
+                .map(|(who, weight)| Self::vote_weight(dao, &governance.weighting, who).min(*weight))
+                .fold(T::Balance::zero(), |acc, x| acc.saturating_add(x));
+            governance.passes(
+                tally(motion.ayes()),
+                tally(motion.nays()),
+                Self::total_vote_weight(dao, &governance.weighting),
+            )
+        }
+        
+        /// Charge member's and member roles' budgets for assets spent by the call.
+        /// Returns charged budgets
+        fn charge_budgets(
+            dao_id: &DaoId,
+            who: &T::AccountId,
+            spendings: Vec<SpendingOf<T>>,
+        )
+            -> Result<Vec<(BudgetId, BudgetOf<T>)>, Error<T>>
+        {
+            let roles = MemberRoles::<T>::get(dao_id, who);
+            let now = frame_system::Module::<T>::block_number();
+            let mut budgets: Vec<(BudgetId, BudgetOf<T>, bool)> = DaoBudgets::<T>::iter_prefix(dao_id)
+                .filter(|(_, budget)| match budget.spender() {
+                    BudgetSpender::Member(member) => member == who,
+                    BudgetSpender::Role(role_id) => roles.binary_search(role_id).is_ok(),
+                })
+                .map(|(budget_id, mut budget)| {
+                    budget.renew(now);
+                    (budget_id, budget, false)
+                })
+                .collect();
+            budgets.sort_by_key(|(budget_id, ..)| *budget_id);
+            for spending in spendings {
+                let mut same_asset = budgets.iter_mut()
+                    .filter(|(_, budget, _)| budget.asset() == &spending.asset)
+                    .peekable();
+                ensure!(same_asset.peek().is_some(), Error::<T>::Forbidden);
+                let (_, budget, charged) = same_asset
+                    .find(|(_, budget, _)| budget.remaining() >= spending.amount)
+                    .ok_or(Error::<T>::BudgetExceeded)?;
+                budget.spend(spending.amount);
+                *charged = true;
+            }
+            Ok(budgets.into_iter()
+                .filter(|(.., charged)| *charged)
+                .map(|(budget_id, budget, _)| (budget_id, budget))
+                .collect())
+        }
+    }
+    pub fn dao_key<T: Decode + Default>(dao_id: &DaoId) -> T {
+        let entropy = (b"deip/DAOs/", dao_id.as_bytes()).using_encoded(sp_io::hashing::blake2_256);
+        T::decode(&mut &entropy[..]).unwrap_or_default()
+    }
+    pub fn dao_key2<T: frame_system::Config>(dao_id: &DaoId) -> T::AccountId {
+        dao_key::<T::AccountId>(dao_id)
+    }
+    
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        
+        #[pallet::weight(10_000)]
+        pub fn create(
+            origin: OriginFor<T>,
+            name: DaoId,
+            authority: InputAuthority<T::DeipAccountId>,
+            metadata: Option<H256>,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let authority_key = ensure_signed(origin)?;
+            let authority = authority.try_map_account(Self::deip_account)?
+                .assert(&authority_key).map_err::<Error<T>, _>(Into::into)?;
+            ensure!(!DaoRepository::<T>::contains_key(&name), Error::<T>::Exists);
+            ensure!(!Self::is_dissolved(&name), Error::<T>::Dissolved);
+            let dao_key = Self::dao_key(&name);
+            let dao = DaoOf::<T>::new(
+                authority_key,
+                authority,
+                name,
+                dao_key,
+                metadata,
+            );
+            let author = Submitter::<T>::get().unwrap_or_else(|| dao.authority_key().clone());
+            let revision = Self::metadata_revision(&dao, author);
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::AddMetadataRevision(*dao.id(), revision));
+                    ops.push_op(StorageOps::CreateDao(dao.clone()));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoCreate(dao)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn alter_authority(
+            origin: OriginFor<T>,
+            alter_authority: AlterAuthority<T::DeipAccountId>,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let alter_authority = alter_authority.try_map_account(Self::deip_account)?;
+            ensure!(
+                !matches!(alter_authority, AlterAuthority::AddMember { .. }),
+                Error::<T>::InvitationRequired
+            );
+            let mut dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            if let AlterAuthority::ReplaceAuthority { ref authority, .. } = alter_authority {
+                ensure!(
+                    authority.signatories.iter().all(|x| dao.authority().signatories().binary_search(x).is_ok()),
+                    Error::<T>::InvitationRequired
+                );
+            }
+            dao = dao.alter_authoriry(alter_authority).map_err::<Error<T>, _>(Into::into)?;
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateDao(dao.clone()));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAlterAuthority(dao)));
+                });
+            Ok(Some(0).into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn update_dao(
+            origin: OriginFor<T>,
+            new_metadata: Option<H256>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let mut dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            dao = dao.update_metadata(new_metadata);
+            let revision = Self::metadata_revision(&dao, Submitter::<T>::get().unwrap_or(who));
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateDao(dao.clone()));
+                    ops.push_op(StorageOps::AddMetadataRevision(*dao.id(), revision));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoMetadataUpdated(dao)));
+                });
+
+            Ok(None.into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn set_handle(
+            origin: OriginFor<T>,
+            handle: DaoHandle,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            ensure!(is_valid_handle(&handle), Error::<T>::HandleInvalid);
+            ensure!(!ReservedHandles::<T>::contains_key(&handle), Error::<T>::HandleReserved);
+            ensure!(!DaoIdByHandle::<T>::contains_key(&handle), Error::<T>::HandleTaken);
+            let now = frame_system::Module::<T>::block_number();
+            let old = DaoHandles::<T>::get(dao.id());
+            if let Some(ref old) = old {
+                ensure!(
+                    old.can_rename(now, T::HandleRenameCooldown::get()),
+                    Error::<T>::HandleRenameCooldown
+                );
+            }
+            let dao_id = *dao.id();
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoHandleSet(dao_id, handle.clone())));
+                    ops.push_op(StorageOps::SetHandle(dao_id, old, HandleRecord::new(handle, now)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn reserve_handle(
+            origin: OriginFor<T>,
+            handle: DaoHandle,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            ensure_root(origin)?;
+            ensure!(is_valid_handle(&handle), Error::<T>::HandleInvalid);
+            ensure!(!ReservedHandles::<T>::contains_key(&handle), Error::<T>::HandleReserved);
+            ensure!(!DaoIdByHandle::<T>::contains_key(&handle), Error::<T>::HandleTaken);
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoHandleReserved(handle.clone())));
+                    ops.push_op(StorageOps::ReserveHandle(handle));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn release_handle(
+            origin: OriginFor<T>,
+            handle: DaoHandle,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            ensure_root(origin)?;
+            ensure!(ReservedHandles::<T>::contains_key(&handle), Error::<T>::HandleNotReserved);
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoHandleReleased(handle.clone())));
+                    ops.push_op(StorageOps::ReleaseHandle(handle));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn set_metadata_record(
+            origin: OriginFor<T>,
+            record: Option<DaoMetadata>,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            if let Some(ref x) = record {
+                ensure!(x.is_valid(), Error::<T>::MetadataTooLong);
+            }
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?
+                .update_metadata_record(record);
+            let revision = Self::metadata_revision(&dao, Submitter::<T>::get().unwrap_or(who));
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateDao(dao.clone()));
+                    ops.push_op(StorageOps::AddMetadataRevision(*dao.id(), revision));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoMetadataUpdated(dao)));
+                });
+            Ok(Some(0).into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn on_behalf(
+            origin: OriginFor<T>,
+            name: DaoId,
+            call: Box<<T as Config>::Call>,
+        ) -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            ensure!(!Self::is_dissolved(&name), Error::<T>::Dissolved);
+            let dao = DaoRepository::<T>::get(name).ok_or(Error::<T>::NotFound)?;
+            let mut charged_budgets = Vec::new();
+            let is_authority = Self::is_authority(&who, &dao, T::MaxNestingDepth::get());
+            if !is_authority {
+                match T::SpendingCalls::spendings(&call) {
+                    Some(spendings) if !spendings.is_empty() => {
+                        charged_budgets = Self::charge_budgets(&name, &who, spendings)?;
+                    },
+                    _ => ensure!(Self::role_allows(&name, &who, &call), Error::<T>::Forbidden),
+                }
+            }
+            let result = Self::dispatch_as_dao(&dao, who, *call);
+            if result.is_ok() && !charged_budgets.is_empty() {
+                StorageOpsTransaction::<StorageOps<T>>::new()
+                    .commit(move |ops| {
+                        for (budget_id, budget) in charged_budgets {
+                            let exhausted = budget.is_exhausted();
+                            ops.push_op(StorageOps::UpdateBudget(name, budget_id, budget));
+                            if exhausted {
+                                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoBudgetExhausted(name, budget_id)));
+                            }
+                        }
+                    });
+            }
+            result
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn set_role(
+            origin: OriginFor<T>,
+            role_id: RoleId,
+            filters: Vec<CallFilter>,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let role = match DaoRoles::<T>::get(dao.id(), role_id) {
+                Some(mut role) => { role.set_filters(filters); role },
+                None => RoleOf::<T>::new(filters),
+            };
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateRole(*dao.id(), role_id, role));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoRoleSet(*dao.id(), role_id)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn remove_role(
+            origin: OriginFor<T>,
+            role_id: RoleId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let role = DaoRoles::<T>::get(dao.id(), role_id).ok_or(Error::<T>::RoleNotFound)?;
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::RemoveRole(*dao.id(), role_id, role));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoRoleRemoved(*dao.id(), role_id)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn assign_role(
+            origin: OriginFor<T>,
+            role_id: RoleId,
+            member: T::AccountId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let mut role = DaoRoles::<T>::get(dao.id(), role_id).ok_or(Error::<T>::RoleNotFound)?;
+            ensure!(dao.authority().signatories().binary_search(&member).is_ok(), Error::<T>::NotMember);
+            if !role.assign(member.clone()) {
+                return Ok(Some(0).into())
+            }
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateRole(*dao.id(), role_id, role));
+                    ops.push_op(StorageOps::AssignRole(*dao.id(), role_id, member.clone()));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoRoleAssigned(*dao.id(), role_id, member)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn revoke_role(
+            origin: OriginFor<T>,
+            role_id: RoleId,
+            member: T::AccountId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let mut role = DaoRoles::<T>::get(dao.id(), role_id).ok_or(Error::<T>::RoleNotFound)?;
+            if !role.revoke(&member) {
+                return Ok(Some(0).into())
+            }
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateRole(*dao.id(), role_id, role));
+                    ops.push_op(StorageOps::RevokeRole(*dao.id(), role_id, member.clone()));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoRoleRevoked(*dao.id(), role_id, member)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn set_budget(
+            origin: OriginFor<T>,
+            budget_id: BudgetId,
+            spender: BudgetSpender<T::AccountId>,
+            asset: BudgetAsset<T::AssetId>,
+            limit: T::Balance,
+            period: T::BlockNumber,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let now = frame_system::Module::<T>::block_number();
+            let budget = BudgetOf::<T>::new(spender, asset, limit, period, now);
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateBudget(*dao.id(), budget_id, budget));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoBudgetSet(*dao.id(), budget_id)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn remove_budget(
+            origin: OriginFor<T>,
+            budget_id: BudgetId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            ensure!(DaoBudgets::<T>::contains_key(dao.id(), budget_id), Error::<T>::BudgetNotFound);
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::RemoveBudget(*dao.id(), budget_id));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoBudgetRemoved(*dao.id(), budget_id)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn set_governance(
+            origin: OriginFor<T>,
+            governance: Option<GovernanceOf<T>>,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            if let Some(ref x) = governance {
+                ensure!(!x.voting_period.is_zero(), Error::<T>::InvalidGovernance);
+            }
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::SetGovernance(*dao.id(), governance));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoGovernanceSet(*dao.id())));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn propose_motion(
+            origin: OriginFor<T>,
+            name: DaoId,
+            motion_id: MotionId,
+            call: Box<<T as Config>::Call>,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = DaoRepository::<T>::get(name).ok_or(Error::<T>::NotFound)?;
+            let governance = DaoGovernance::<T>::get(name).ok_or(Error::<T>::GovernanceNotSet)?;
+            ensure!(!DaoMotions::<T>::contains_key(name, motion_id), Error::<T>::MotionExists);
+            let weight = Self::vote_weight(&dao, &governance.weighting, &who);
+            ensure!(!weight.is_zero(), Error::<T>::Forbidden);
+            let end = frame_system::Module::<T>::block_number().saturating_add(governance.voting_period);
+            let motion = MotionOf::<T>::new(call, who.clone(), weight, end);
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateMotion(name, motion_id, motion));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoMotionProposed(name, motion_id, who)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn vote_motion(
+            origin: OriginFor<T>,
+            name: DaoId,
+            motion_id: MotionId,
+            aye: bool,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let mut motion = DaoMotions::<T>::get(name, motion_id).ok_or(Error::<T>::MotionNotFound)?;
+            ensure!(frame_system::Module::<T>::block_number() <= *motion.end(), Error::<T>::MotionVotingEnded);
+            let dao = DaoRepository::<T>::get(name).ok_or(Error::<T>::NotFound)?;
+            let governance = DaoGovernance::<T>::get(name).ok_or(Error::<T>::GovernanceNotSet)?;
+            let weight = Self::vote_weight(&dao, &governance.weighting, &who);
+            ensure!(!weight.is_zero(), Error::<T>::Forbidden);
+            if !motion.vote(who.clone(), weight, aye) {
+                return Ok(Some(0).into())
+            }
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::UpdateMotion(name, motion_id, motion));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoMotionVoted(name, motion_id, who, aye)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn close_motion(
+            origin: OriginFor<T>,
+            name: DaoId,
+            motion_id: MotionId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            ensure_signed(origin)?;
+            let motion = DaoMotions::<T>::get(name, motion_id).ok_or(Error::<T>::MotionNotFound)?;
+            ensure!(frame_system::Module::<T>::block_number() > *motion.end(), Error::<T>::MotionVotingNotEnded);
+            let dao = DaoRepository::<T>::get(name).ok_or(Error::<T>::NotFound)?;
+            let passes = match DaoGovernance::<T>::get(name) {
+                Some(governance) => Self::motion_passes(&dao, &governance, &motion),
+                None => false,
+            };
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(|ops| {
+                    ops.push_op(StorageOps::RemoveMotion(name, motion_id));
+                });
+            let event = if passes {
+                let proposer = motion.proposer().clone();
+                let result = with_transaction(|| {
+                    let result = Self::dispatch_as_dao(&dao, proposer, *motion.into_call())
+                        .map(|_| ())
+                        .map_err(|e| e.error);
+                    if result.is_ok() {
+                        TransactionOutcome::Commit(result)
+                    } else {
+                        TransactionOutcome::Rollback(result)
+                    }
+                });
+                Event::<T>::DaoMotionExecuted(name, motion_id, result)
+            } else {
+                Event::<T>::DaoMotionRejected(name, motion_id)
+            };
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::DepositEvent(event));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn invite(
+            origin: OriginFor<T>,
+            member: T::DeipAccountId,
+            expires_at: T::BlockNumber,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let member = Self::deip_account(member)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let now = frame_system::Module::<T>::block_number();
+            let invitation = InvitationOf::<T>::new(expires_at);
+            ensure!(!invitation.is_expired(&now), Error::<T>::InvitationExpired);
+            ensure!(
+                dao.authority().signatories().binary_search(&member).is_err(),
+                Error::<T>::AlreadyMember
+            );
+            ensure!(
+                !matches!(DaoInvitations::<T>::get(dao.id(), &member), Some(x) if !x.is_expired(&now)),
+                Error::<T>::InvitationExists
+            );
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::CreateInvitation(*dao.id(), member.clone(), invitation));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoMemberInvited(*dao.id(), member, expires_at)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn cancel_invitation(
+            origin: OriginFor<T>,
+            member: T::DeipAccountId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let member = Self::deip_account(member)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            ensure!(DaoInvitations::<T>::contains_key(dao.id(), &member), Error::<T>::InvitationNotFound);
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::RemoveInvitation(*dao.id(), member.clone()));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoInvitationCancelled(*dao.id(), member)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn accept_invitation(
+            origin: OriginFor<T>,
+            name: DaoId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let invitation = DaoInvitations::<T>::get(name, &who).ok_or(Error::<T>::InvitationNotFound)?;
+            ensure!(
+                !invitation.is_expired(&frame_system::Module::<T>::block_number()),
+                Error::<T>::InvitationExpired
+            );
+            let dao = DaoRepository::<T>::get(name).ok_or(Error::<T>::NotFound)?
+                .alter_authoriry(AlterAuthority::AddMember { member: who.clone() })
+                .map_err::<Error<T>, _>(Into::into)?;
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::RemoveInvitation(name, who.clone()));
+                    ops.push_op(StorageOps::UpdateDao(dao.clone()));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoInvitationAccepted(name, who)));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAlterAuthority(dao)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        #[pallet::weight(10_000)]
+        pub fn decline_invitation(
+            origin: OriginFor<T>,
+            name: DaoId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            ensure!(DaoInvitations::<T>::contains_key(name, &who), Error::<T>::InvitationNotFound);
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::RemoveInvitation(name, who.clone()));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoInvitationDeclined(name, who)));
+                });
+            Ok(Some(0).into())
+        }
+        
+        /// `assets_witness` is the upper bound of the number of assets
+        /// checked for holdings of the DAO key (all assets of the chain)
+        #[pallet::weight(Pallet::<T>::dissolve_weight(beneficiaries.len(), *assets_witness))]
+        pub fn dissolve(
+            origin: OriginFor<T>,
+            beneficiaries: Vec<(BudgetAsset<T::AssetId>, T::AccountId)>,
+            #[pallet::compact] assets_witness: u32,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            Self::sweep_assets(&who, &beneficiaries, assets_witness)?;
+            StorageOpsTransaction::<StorageOps<T>>::new()
+                .commit(move |ops| {
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoDissolved(*dao.id())));
+                    ops.push_op(StorageOps::DissolveDao(dao));
+                });
+            Ok(Some(0).into())
+        }
+    }
+    
+    // ==== Storage ====:
+    
+    #[pallet::storage]
+    #[pallet::getter(fn get_dao)]
+    pub(super) type DaoRepository<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        DaoId,
+        DaoOf<T>,
+        OptionQuery
+    >;
+    
+    #[pallet::storage]
+    #[pallet::getter(fn lookup_dao)]
+    pub(super) type DaoLookup<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        T::AccountId,
+        DaoId,
+        OptionQuery
+    >;
+    
+    /// Index of DAOs by signatories of their authorities
+    #[pallet::storage]
+    pub(super) type DaoIdByMember<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        DaoId,
+        (),
+        OptionQuery
+    >;
+    
+    #[pallet::storage]
+    #[pallet::getter(fn get_invitation)]
+    pub(super) type DaoInvitations<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        T::AccountId,
+        InvitationOf<T>,
+        OptionQuery
+    >;
+    
+    /// Index of invitations by invitees
+    #[pallet::storage]
+    pub(super) type DaoIdByInvitee<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        DaoId,
+        (),
+        OptionQuery
+    >;
+    
+    #[pallet::storage]
+    #[pallet::getter(fn get_role)]
+    pub(super) type DaoRoles<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        RoleId,
+        RoleOf<T>,
+        OptionQuery
+    >;
+    
+    #[pallet::storage]
+    #[pallet::getter(fn member_roles)]
+    pub(super) type MemberRoles<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<RoleId>,
+        ValueQuery
+    >;
+    
+    #[pallet::storage]
+    #[pallet::getter(fn get_budget)]
+    pub(super) type DaoBudgets<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        BudgetId,
+        BudgetOf<T>,
+        OptionQuery
+    >;
+    
+    #[pallet::storage]
+    #[pallet::getter(fn get_governance)]
+    pub(super) type DaoGovernance<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        DaoId,
+        GovernanceOf<T>,
+        OptionQuery
+    >;
+    
+    #[pallet::storage]
+    #[pallet::getter(fn get_motion)]
+    pub(super) type DaoMotions<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        MotionId,
+        MotionOf<T>,
+        OptionQuery
+    >;
+    
+    /// Metadata revisions of DAO by revision number
+    #[pallet::storage]
+    pub(super) type DaoMetadataHistory<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        u32,
+        MetadataRevisionOf<T>,
+        OptionQuery
+    >;
+    
+    /// Number of metadata revisions of DAO
+    #[pallet::storage]
+    pub(super) type DaoMetadataRevisions<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        DaoId,
+        u32,
+        ValueQuery
+    >;
+    
+    /// Handles of DAOs
+    #[pallet::storage]
+    #[pallet::getter(fn dao_handle)]
+    pub(super) type DaoHandles<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        DaoId,
+        HandleRecordOf<T>,
+        OptionQuery
+    >;
+    
+    /// Index of DAOs by handles
+    #[pallet::storage]
+    #[pallet::getter(fn dao_id_by_handle)]
+    pub(super) type DaoIdByHandle<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        DaoHandle,
+        DaoId,
+        OptionQuery
+    >;
+    
+    /// Handles that can't be taken by DAOs
+    #[pallet::storage]
+    pub(super) type ReservedHandles<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        DaoHandle,
+        (),
+        OptionQuery
+    >;
+    
+    /// Account submitted the call dispatched on behalf of DAO. Exists only during the dispatch
+    #[pallet::storage]
+    pub(super) type Submitter<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+    
+    /// IDs of dissolved DAOs that may not be reused
+    #[pallet::storage]
+    pub(super) type DissolvedDaos<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        DaoId,
+        (),
+        OptionQuery
+    >;
+    
+    use storage_ops::*;
+    #[doc(no_inline)]
+    /// Module contains abstractions over pallet storage operations
+    pub mod storage_ops {
+        use sp_std::prelude::*;
+        use pallet_deip_toolkit::storage_ops::StorageOp;
+        use super::{Config, Event, Pallet};
+        use super::{DaoOf, DaoRepository, DaoLookup, DaoId, DaoIdByMember};
+        use super::{RoleId, RoleOf, DaoRoles, MemberRoles};
+        use super::{BudgetId, BudgetOf, DaoBudgets};
+        use super::{GovernanceOf, DaoGovernance, MotionId, MotionOf, DaoMotions};
+        use super::{InvitationOf, DaoInvitations, DaoIdByInvitee};
+        use super::DissolvedDaos;
+        use super::{MetadataRevisionOf, DaoMetadataHistory, DaoMetadataRevisions};
+        use super::{DaoHandle, HandleRecordOf, DaoHandles, DaoIdByHandle, ReservedHandles};
+
+        /// Storage operations
+        pub enum StorageOps<T: Config> {
+            /// Deposit event
+            DepositEvent(Event<T>),
+            /// Create DAO
+            CreateDao(DaoOf<T>),
+            /// Update DAO
+            UpdateDao(DaoOf<T>),
+            /// Create or update role
+            UpdateRole(DaoId, RoleId, RoleOf<T>),
+            /// Remove role and revoke it from members
+            RemoveRole(DaoId, RoleId, RoleOf<T>),
+            /// Add role to member's roles index
+            AssignRole(DaoId, RoleId, T::AccountId),
+            /// Remove role from member's roles index
+            RevokeRole(DaoId, RoleId, T::AccountId),
+            /// Create or update budget
+            UpdateBudget(DaoId, BudgetId, BudgetOf<T>),
+            /// Remove budget
+            RemoveBudget(DaoId, BudgetId),
+            /// Set or remove voting governance
+            SetGovernance(DaoId, Option<GovernanceOf<T>>),
+            /// Create or update motion
+            UpdateMotion(DaoId, MotionId, MotionOf<T>),
+            /// Remove motion
+            RemoveMotion(DaoId, MotionId),
+            /// Create or replace invitation
+            CreateInvitation(DaoId, T::AccountId, InvitationOf<T>),
+            /// Remove invitation
+            RemoveInvitation(DaoId, T::AccountId),
+            /// Remove DAO with all it's entities and reserve it's ID
+            DissolveDao(DaoOf<T>),
+            /// Append revision to metadata history
+            AddMetadataRevision(DaoId, MetadataRevisionOf<T>),
+            /// Set DAO handle releasing the old one
+            SetHandle(DaoId, Option<HandleRecordOf<T>>, HandleRecordOf<T>),
+            /// Add handle to reserved ones
+            ReserveHandle(DaoHandle),
+            /// Remove handle from reserved ones
+            ReleaseHandle(DaoHandle),
+        }
+        impl<T: Config> StorageOp for StorageOps<T> {
+            fn exec(self) {
+                match self {
+                    Self::DepositEvent(e) => {
+                        Pallet::<T>::deposit_event(e)
+                    },
+                    Self::CreateDao(dao) => {
+                        DaoLookup::<T>::insert(dao.dao_key().clone(), dao.id().clone());
+                        for member in dao.authority().signatories() {
+                            DaoIdByMember::<T>::insert(member, dao.id(), ());
+                        }
+                        DaoRepository::<T>::insert(*dao.id(), dao);
+                    }
+                    Self::UpdateDao(dao) => {
+                        if let Some(old) = DaoRepository::<T>::get(dao.id()) {
+                            let signatories = dao.authority().signatories();
+                            for member in old.authority().signatories() {
+                                if signatories.binary_search(member).is_err() {
+                                    DaoIdByMember::<T>::remove(member, dao.id());
+                                    for role_id in MemberRoles::<T>::take(dao.id(), member) {
+                                        DaoRoles::<T>::mutate(dao.id(), role_id, |maybe_role| {
+                                            if let Some(role) = maybe_role { role.revoke(member); }
+                                        });
+                                    }
+                                }
+                            }
+                        }
+                        for member in dao.authority().signatories() {
+                            DaoIdByMember::<T>::insert(member, dao.id(), ());
+                        }
+                        DaoRepository::<T>::insert(*dao.id(), dao);
+                    }
+                    Self::UpdateRole(dao_id, role_id, role) => {
+                        DaoRoles::<T>::insert(dao_id, role_id, role);
+                    }
+                    Self::RemoveRole(dao_id, role_id, role) => {
+                        for m in role.members() {
+                            revoke_member_role::<T>(&dao_id, &role_id, m);
+                        }
+                        DaoRoles::<T>::remove(dao_id, role_id);
+                    }
+                    Self::AssignRole(dao_id, role_id, member) => {
+                        MemberRoles::<T>::mutate(dao_id, member, |roles| {
+                            if let Err(pos) = roles.binary_search(&role_id) {
+                                roles.insert(pos, role_id);
+                            }
+                        });
+                    }
+                    Self::RevokeRole(dao_id, role_id, member) => {
+                        revoke_member_role::<T>(&dao_id, &role_id, &member);
+                    }
+                    Self::UpdateBudget(dao_id, budget_id, budget) => {
+                        DaoBudgets::<T>::insert(dao_id, budget_id, budget);
+                    }
+                    Self::RemoveBudget(dao_id, budget_id) => {
+                        DaoBudgets::<T>::remove(dao_id, budget_id);
+                    }
+                    Self::SetGovernance(dao_id, governance) => {
+                        DaoGovernance::<T>::mutate_exists(dao_id, |x| *x = governance);
+                    }
+                    Self::UpdateMotion(dao_id, motion_id, motion) => {
+                        DaoMotions::<T>::insert(dao_id, motion_id, motion);
+                    }
+                    Self::RemoveMotion(dao_id, motion_id) => {
+                        DaoMotions::<T>::remove(dao_id, motion_id);
+                    }
+                    Self::CreateInvitation(dao_id, member, invitation) => {
+                        DaoIdByInvitee::<T>::insert(&member, dao_id, ());
+                        DaoInvitations::<T>::insert(dao_id, member, invitation);
+                    }
+                    Self::RemoveInvitation(dao_id, member) => {
+                        DaoIdByInvitee::<T>::remove(&member, dao_id);
+                        DaoInvitations::<T>::remove(dao_id, member);
+                    }
+                    Self::DissolveDao(dao) => {
+                        let dao_id = dao.id();
+                        for member in dao.authority().signatories() {
+                            DaoIdByMember::<T>::remove(member, dao_id);
+                        }
+                        for (member, _) in DaoInvitations::<T>::drain_prefix(dao_id) {
+                            DaoIdByInvitee::<T>::remove(member, dao_id);
+                        }
+                        DaoRoles::<T>::remove_prefix(dao_id);
+                        MemberRoles::<T>::remove_prefix(dao_id);
+                        DaoBudgets::<T>::remove_prefix(dao_id);
+                        DaoGovernance::<T>::remove(dao_id);
+                        DaoMotions::<T>::remove_prefix(dao_id);
+                        if let Some(record) = DaoHandles::<T>::take(dao_id) {
+                            DaoIdByHandle::<T>::remove(record.handle());
+                            ReservedHandles::<T>::insert(record.handle(), ());
+                        }
+                        DaoLookup::<T>::remove(dao.dao_key());
+                        DaoRepository::<T>::remove(dao_id);
+                        DissolvedDaos::<T>::insert(dao_id, ());
+                    }
+                    Self::AddMetadataRevision(dao_id, revision) => {
+                        let number = DaoMetadataRevisions::<T>::get(dao_id);
+                        DaoMetadataHistory::<T>::insert(dao_id, number, revision);
+                        DaoMetadataRevisions::<T>::insert(dao_id, number.saturating_add(1));
+                    }
+                    Self::SetHandle(dao_id, old, record) => {
+                        if let Some(old) = old {
+                            DaoIdByHandle::<T>::remove(old.handle());
+                        }
+                        DaoIdByHandle::<T>::insert(record.handle(), dao_id);
+                        DaoHandles::<T>::insert(dao_id, record);
+                    }
+                    Self::ReserveHandle(handle) => {
+                        ReservedHandles::<T>::insert(handle, ());
+                    }
+                    Self::ReleaseHandle(handle) => {
+                        ReservedHandles::<T>::remove(handle);
+                    }
                 }
             }
         }
+        
+        fn revoke_member_role<T: Config>(dao_id: &DaoId, role_id: &RoleId, member: &T::AccountId) {
+            MemberRoles::<T>::mutate_exists(dao_id, member, |maybe_roles| {
+                if let Some(roles) = maybe_roles {
+                    roles.retain(|x| x != role_id);
+                    if roles.is_empty() { *maybe_roles = None; }
+                }
+            });
+        }
     }
 }
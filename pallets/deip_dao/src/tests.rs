@@ -17,7 +17,9 @@ frame_support::construct_runtime!(
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
+        Timestamp: pallet_timestamp::{Module, Call, Storage, Inherent},
         DeipDao: pallet_deip_dao::{Module, Call, Storage, Event<T>, Config},
+        Utility: pallet_utility::{Module, Call, Event},
     }
 );
 
@@ -25,6 +27,9 @@ frame_support::parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub BlockWeights: frame_system::limits::BlockWeights =
         frame_system::limits::BlockWeights::simple_max(1024);
+    pub const MaxNestingDepth: u32 = 2;
+    pub const MinimumPeriod: u64 = 1;
+    pub const HandleRenameCooldown: u64 = 10;
 }
 
 impl frame_system::Config for TestRuntime {
@@ -42,7 +47,7 @@ impl frame_system::Config for TestRuntime {
     type Header = sp_runtime::testing::Header;
     type Event = Event;
     type BlockHashCount = BlockHashCount;
-    type DbWeight = ();
+    type DbWeight = frame_support::weights::constants::RocksDbWeight;
     type Version = ();
     type PalletInfo = PalletInfo;
     type AccountData = ();
@@ -52,9 +57,126 @@ impl frame_system::Config for TestRuntime {
     type SS58Prefix = ();
 }
 
+impl pallet_timestamp::Config for TestRuntime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+impl pallet_utility::Config for TestRuntime {
+    type Event = Event;
+    type Call = Call;
+    type WeightInfo = ();
+}
+
 impl crate::Config for TestRuntime {
     type Event = Event;
     type Call = Call;
+    type CallPredicates = TestCallPredicates;
+    type AssetId = u32;
+    type Balance = u64;
+    type SpendingCalls = TestSpendingCalls;
+    type TokenBalances = TestTokenBalances;
+    type SweepAssets = TestSweepAssets;
+    type DeipAccountId = u64;
+    type MaxNestingDepth = MaxNestingDepth;
+    type HandleRenameCooldown = HandleRenameCooldown;
+}
+
+/// Records sweeps and holdings left after them, `FROZEN_ASSET` can't be swept.
+/// Every holding of any account counts as an asset to check
+pub struct TestSweepAssets;
+
+const FROZEN_ASSET: u32 = 2;
+
+std::thread_local! {
+    static SWEPT: std::cell::RefCell<Vec<(BudgetAsset<u32>, u64, u64)>> = Default::default();
+    static HOLDINGS: std::cell::RefCell<Vec<(u64, BudgetAsset<u32>)>> = Default::default();
+}
+
+fn swept() -> Vec<(BudgetAsset<u32>, u64, u64)> {
+    SWEPT.with(|x| x.borrow().clone())
+}
+
+fn hold(who: u64, asset: BudgetAsset<u32>) {
+    HOLDINGS.with(|x| x.borrow_mut().push((who, asset)));
+}
+
+impl crate::budget::SweepAssets<u64, u32> for TestSweepAssets {
+    fn sweep(asset: &BudgetAsset<u32>, from: &u64, to: &u64) -> DispatchResult {
+        if *asset == BudgetAsset::Asset(FROZEN_ASSET) {
+            return Err(DispatchError::Other("Frozen"))
+        }
+        SWEPT.with(|x| x.borrow_mut().push((asset.clone(), *from, *to)));
+        HOLDINGS.with(|x| x.borrow_mut().retain(|(who, x)| !(who == from && x == asset)));
+        Ok(())
+    }
+    fn holdings(who: &u64, max_assets: u32) -> Option<Vec<BudgetAsset<u32>>> {
+        HOLDINGS.with(|x| {
+            let holdings = x.borrow();
+            if holdings.len() > max_assets as usize { return None }
+            Some(holdings.iter().filter(|(x, _)| x == who).map(|(_, x)| x.clone()).collect())
+        })
+    }
+}
+
+/// Account holds `VOTING_TOKEN` balance equal to it's ID unless transferred
+pub struct TestTokenBalances;
+
+const VOTING_TOKEN: u32 = 1;
+
+std::thread_local! {
+    static TOKEN_BALANCES: std::cell::RefCell<std::collections::BTreeMap<u64, u64>> = Default::default();
+}
+
+fn transfer_tokens(from: u64, to: u64, amount: u64) {
+    let balance = |x: u64| TestTokenBalances::balance(&VOTING_TOKEN, &x);
+    let (from_balance, to_balance) = (balance(from) - amount, balance(to) + amount);
+    TOKEN_BALANCES.with(|x| x.borrow_mut().extend([(from, from_balance), (to, to_balance)]));
+}
+
+impl crate::governance::TokenBalances<u64, u32, u64> for TestTokenBalances {
+    fn balance(asset: &u32, who: &u64) -> u64 {
+        if *asset != VOTING_TOKEN { return 0 }
+        TOKEN_BALANCES.with(|x| x.borrow().get(who).copied()).unwrap_or(*who)
+    }
+    fn total_supply(asset: &u32) -> u64 {
+        if *asset == VOTING_TOKEN { 10 } else { 0 }
+    }
+}
+
+/// Predicate `REMARK_PREDICATE` matches `frame_system::remark` calls
+pub struct TestCallPredicates;
+
+const REMARK_PREDICATE: u32 = 1;
+
+impl crate::role::CallPredicates<Call> for TestCallPredicates {
+    fn matches(predicate: u32, call: &Call) -> bool {
+        predicate == REMARK_PREDICATE
+            && matches!(call, Call::System(frame_system::Call::remark(..)))
+    }
+}
+
+/// `frame_system::remark` of N bytes spends N units of native currency
+pub struct TestSpendingCalls;
+
+impl crate::budget::SpendingCalls<Call, u32, u64> for TestSpendingCalls {
+    fn spendings(call: &Call) -> Option<Vec<crate::budget::Spending<u32, u64>>> {
+        match call {
+            Call::System(frame_system::Call::remark(bytes)) if !bytes.is_empty() => {
+                Some(vec![crate::budget::Spending { asset: BudgetAsset::Native, amount: bytes.len() as u64 }])
+            },
+            Call::Utility(pallet_utility::Call::batch(calls))
+            | Call::Utility(pallet_utility::Call::batch_all(calls)) => {
+                calls.iter()
+                    .map(Self::spendings)
+                    .collect::<Option<Vec<_>>>()
+                    .map(|x| x.concat())
+            },
+            _ => None,
+        }
+    }
 }
 
 pub struct ExtBuilder;
@@ -72,6 +194,13 @@ fn with_test_ext<R>(t: impl FnOnce() -> R) -> R {
 
 use frame_support::{assert_noop, assert_ok};
 use crate::dao::*;
+use crate::role::*;
+use crate::budget::*;
+use crate::governance::*;
+use crate::invitation::*;
+use crate::metadata::*;
+use crate::handle::*;
+use sp_runtime::{Perbill, DispatchError, DispatchResult};
 use sp_std::str::FromStr;
 use frame_system::RawOrigin;
 
@@ -95,17 +224,38 @@ fn fake_test_example() {
     })
 }
 
+fn test_dao_id() -> DaoId {
+    DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes())
+}
+
+fn create_dao(who: u64) -> DaoId {
+    let id = test_dao_id();
+    DeipDao::create(Origin::signed(who), id, plain_key_source(who), None).expect("create OK");
+    id
+}
+
+fn replace_authority_call(to: u64) -> Call {
+    Call::DeipDao(RawCall::alter_authority(AlterAuthority::ReplaceAuthority {
+        authority_key: to,
+        authority: plain_key_source(to),
+    }))
+}
+
+fn remark_call() -> Call {
+    Call::System(frame_system::Call::remark(vec![]))
+}
+
 #[test]
 fn dao_create() {
     with_test_ext(|| {
         System::set_block_number(1);
         let who = 1;
-        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
-        assert_ok!(DeipDao::create(Origin::signed(who), id, plain_key_source(who)));
+        let id = test_dao_id();
+        assert_ok!(DeipDao::create(Origin::signed(who), id, plain_key_source(who), None));
         assert!(matches!(
             last_event(),
             Event::pallet_deip_dao(RawEvent::DaoCreate(dao))
-            if dao.dao_key() == &who && dao.id() == &id
+            if dao.authority_key() == &who && dao.id() == &id
         ));
     })
 }
@@ -114,137 +264,925 @@ fn dao_create() {
 fn dao_create_exists() {
     with_test_ext(|| {
         let who = 1;
-        let name = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
-        DeipDao::create(Origin::signed(who), name, plain_key_source(who)).expect("create OK");
+        let name = create_dao(who);
         assert_noop!(
-            DeipDao::create(Origin::signed(who), name, plain_key_source(who)),
+            DeipDao::create(Origin::signed(who), name, plain_key_source(who), None),
             Error::<TestRuntime>::Exists,
         );
     })
 }
 
 #[test]
-fn dao_transfer_ownership() {
+fn dao_alter_authority() {
     with_test_ext(|| {
         System::set_block_number(1);
         let who = 1;
-        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
-        DeipDao::create(Origin::signed(who), id, plain_key_source(who)).expect("create OK");
+        let id = create_dao(who);
         let transfer_to = 2;
-        assert_ok!(DeipDao::transfer_ownership(Origin::signed(who), id, transfer_to, plain_key_source(transfer_to)));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(who), id, Box::new(replace_authority_call(transfer_to))),
+            Error::<TestRuntime>::InvitationRequired,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), id, invite_call(transfer_to, 100)));
+        assert_ok!(DeipDao::accept_invitation(Origin::signed(transfer_to), id));
+        let authority_key = *DeipDao::get_dao(id).unwrap().authority_key();
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority_key), id, Box::new(replace_authority_call(transfer_to))));
         assert!(matches!(
             last_event(),
-            Event::pallet_deip_dao(RawEvent::DaoTransferOwnership(dao))
-            if dao.dao_key() == &transfer_to && dao.id() == &id
+            Event::pallet_deip_dao(RawEvent::DaoAlterAuthority(dao))
+            if dao.authority_key() == &transfer_to && dao.id() == &id
         ));
     })
 }
 
 #[test]
-fn dao_transfer_ownership_not_found() {
+fn dao_alter_authority_not_found() {
     with_test_ext(|| {
         System::set_block_number(1);
-        let who = 1;
-        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
         let transfer_to = 2;
         assert_noop!(
-            DeipDao::transfer_ownership(Origin::signed(who), id, transfer_to, plain_key_source(who)),
+            DeipDao::alter_authority(Origin::signed(1), AlterAuthority::ReplaceAuthority {
+                authority_key: transfer_to,
+                authority: plain_key_source(transfer_to),
+            }),
             Error::<TestRuntime>::NotFound,
         );
     })
 }
 
 #[test]
-fn dao_transfer_ownership_forbidden() {
+fn dao_on_behalf_not_found() {
     with_test_ext(|| {
         System::set_block_number(1);
-        let owner = 1;
-        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
-        DeipDao::create(Origin::signed(owner), id, plain_key_source(owner)).expect("create OK");
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(1), test_dao_id(), Box::new(replace_authority_call(2))),
+            Error::<TestRuntime>::NotFound,
+        );
+    })
+}
+
+#[test]
+fn dao_on_behalf_forbidden() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let name = create_dao(1);
         let transfer_to = 2;
-        let other = 3;
         assert_noop!(
-            DeipDao::transfer_ownership(Origin::signed(transfer_to), id, transfer_to, plain_key_source(transfer_to)),
+            DeipDao::on_behalf(Origin::signed(transfer_to), name, Box::new(replace_authority_call(transfer_to))),
             Error::<TestRuntime>::Forbidden,
         );
+    })
+}
+
+/// Invite member to DAO authority, returns the new authority key
+fn join_dao(id: DaoId, member: u64) -> u64 {
+    assert_ok!(DeipDao::invite(Origin::signed(DeipDao::dao_key(&id)), member, 100));
+    assert_ok!(DeipDao::accept_invitation(Origin::signed(member), id));
+    *DeipDao::get_dao(id).unwrap().authority_key()
+}
+
+fn set_role_call(role_id: RoleId, filters: Vec<CallFilter>) -> Box<Call> {
+    Box::new(Call::DeipDao(RawCall::set_role(role_id, filters)))
+}
+
+fn assign_role_call(role_id: RoleId, member: u64) -> Box<Call> {
+    Box::new(Call::DeipDao(RawCall::assign_role(role_id, member)))
+}
+
+#[test]
+fn dao_role_member_acts_within_role() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let authority = join_dao(id, member);
+        let role_id = RoleId::from_low_u64_be(1);
+        let remark = codec::Encode::encode(&remark_call());
+        let filters = vec![CallFilter::Call { pallet_index: remark[0], call_index: remark[1] }];
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, set_role_call(role_id, filters)));
+        expect_event(RawEvent::DaoRoleSet(id, role_id));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(authority), id, assign_role_call(role_id, 3)),
+            Error::<TestRuntime>::NotMember,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, assign_role_call(role_id, member)));
+        expect_event(RawEvent::DaoRoleAssigned(id, role_id, member));
+        assert_eq!(DeipDao::member_roles(id, member), vec![role_id]);
+
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, Box::new(remark_call())));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), id, Box::new(replace_authority_call(member))),
+            Error::<TestRuntime>::Forbidden,
+        );
+
+        let revoke = Box::new(Call::DeipDao(RawCall::revoke_role(role_id, member)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, revoke));
+        expect_event(RawEvent::DaoRoleRevoked(id, role_id, member));
         assert_noop!(
-            DeipDao::transfer_ownership(Origin::signed(other), id, transfer_to, plain_key_source(transfer_to)),
+            DeipDao::on_behalf(Origin::signed(member), id, Box::new(remark_call())),
             Error::<TestRuntime>::Forbidden,
         );
     })
 }
 
 #[test]
-fn dao_on_behalf() {
+fn dao_role_allows_calls_by_predicate() {
     with_test_ext(|| {
         System::set_block_number(1);
-        let who = 1;
-        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
-        DeipDao::create(
-            Origin::signed(who),
-            id,
-            plain_key_source(who)
-        ).expect("create OK");
-        let transfer_to = 2;
-        assert_ok!(
-            DeipDao::on_behalf(
-                Origin::signed(who),
-                id,
-                Box::new(Call::DeipDao(RawCall::transfer_ownership(
-                    id,
-                    transfer_to,
-                    plain_key_source(transfer_to)
-                )))
-            )
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let authority = join_dao(id, member);
+        let role_id = RoleId::from_low_u64_be(1);
+        let filters = vec![CallFilter::Predicate(REMARK_PREDICATE)];
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, set_role_call(role_id, filters)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, assign_role_call(role_id, member)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, Box::new(remark_call())));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), id, Box::new(replace_authority_call(member))),
+            Error::<TestRuntime>::Forbidden,
         );
     })
 }
 
 #[test]
-fn dao_on_behalf_not_found() {
+fn dao_member_removal_revokes_roles() {
     with_test_ext(|| {
         System::set_block_number(1);
-        let who = 1;
-        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
-        let transfer_to = 2;
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let authority = join_dao(id, member);
+        let role_id = RoleId::from_low_u64_be(1);
+        let filters = vec![CallFilter::Predicate(REMARK_PREDICATE)];
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, set_role_call(role_id, filters)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, assign_role_call(role_id, member)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, Box::new(remark_call())));
+
+        let dao_key = DeipDao::dao_key(&id);
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::RemoveMember { member }));
+        assert!(DeipDao::member_roles(id, member).is_empty());
+        assert!(DeipDao::get_role(id, role_id).unwrap().members().is_empty());
         assert_noop!(
-            DeipDao::on_behalf(
-                Origin::signed(who),
-                id,
-                Box::new(Call::DeipDao(RawCall::transfer_ownership(
-                    id,
-                    transfer_to,
-                    plain_key_source(transfer_to)
-                )))
-            ),
-            Error::<TestRuntime>::NotFound,
+            DeipDao::on_behalf(Origin::signed(member), id, Box::new(remark_call())),
+            Error::<TestRuntime>::Forbidden,
         );
     })
 }
 
 #[test]
-fn dao_on_behalf_forbidden() {
+fn dao_remove_role_revokes_it_from_members() {
     with_test_ext(|| {
         System::set_block_number(1);
-        let who = 1;
-        let name = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
-        DeipDao::create(
-            Origin::signed(who),
-            name,
-            plain_key_source(who)
-        ).expect("create OK");
-        let transfer_to = 2;
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let authority = join_dao(id, member);
+        let role_id = RoleId::from_low_u64_be(1);
+        let dao_pallet = codec::Encode::encode(&replace_authority_call(member))[0];
+        let filters = vec![CallFilter::Pallet { pallet_index: dao_pallet }];
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(authority), id, assign_role_call(role_id, member)),
+            Error::<TestRuntime>::RoleNotFound,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, set_role_call(role_id, filters)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, assign_role_call(role_id, member)));
+
+        let remove = Box::new(Call::DeipDao(RawCall::remove_role(role_id)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, remove));
+        expect_event(RawEvent::DaoRoleRemoved(id, role_id));
+        assert!(DeipDao::get_role(id, role_id).is_none());
+        assert!(DeipDao::member_roles(id, member).is_empty());
+    })
+}
+
+fn spend_call(amount: usize) -> Box<Call> {
+    Box::new(Call::System(frame_system::Call::remark(vec![0; amount])))
+}
+
+fn set_budget_call(budget_id: BudgetId, spender: BudgetSpender<u64>, limit: u64, period: u64) -> Box<Call> {
+    Box::new(Call::DeipDao(RawCall::set_budget(budget_id, spender, BudgetAsset::Native, limit, period)))
+}
+
+#[test]
+fn dao_member_spends_within_budget() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let budget_id = BudgetId::from_low_u64_be(1);
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), id, spend_call(1)),
+            Error::<TestRuntime>::Forbidden,
+        );
+        let set_budget = set_budget_call(budget_id, BudgetSpender::Member(member), 10, 0);
+        assert_ok!(DeipDao::on_behalf(Origin::signed(owner), id, set_budget));
+        expect_event(RawEvent::DaoBudgetSet(id, budget_id));
+        
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, spend_call(4)));
+        assert_eq!(DeipDao::get_budget(id, budget_id).unwrap().spent(), &4);
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), id, spend_call(7)),
+            Error::<TestRuntime>::BudgetExceeded,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, spend_call(6)));
+        expect_event(RawEvent::DaoBudgetExhausted(id, budget_id));
+        assert!(DeipDao::get_budget(id, budget_id).unwrap().is_exhausted());
+        // authority is not limited by budgets
+        assert_ok!(DeipDao::on_behalf(Origin::signed(owner), id, spend_call(100)));
+        assert_eq!(DeipDao::get_budget(id, budget_id).unwrap().spent(), &10);
+    })
+}
+
+#[test]
+fn dao_budget_spending_batch_cant_carry_other_calls() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let budget_id = BudgetId::from_low_u64_be(1);
+        let set_budget = set_budget_call(budget_id, BudgetSpender::Member(member), 10, 0);
+        assert_ok!(DeipDao::on_behalf(Origin::signed(owner), id, set_budget));
+        
+        let exploit = Box::new(Call::Utility(pallet_utility::Call::batch_all(vec![
+            *spend_call(1),
+            replace_authority_call(member),
+        ])));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), id, exploit),
+            Error::<TestRuntime>::Forbidden,
+        );
+        assert_eq!(DeipDao::get_budget(id, budget_id).unwrap().spent(), &0);
+        
+        let spendings = Box::new(Call::Utility(pallet_utility::Call::batch_all(vec![
+            *spend_call(1),
+            *spend_call(2),
+        ])));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, spendings));
+        assert_eq!(DeipDao::get_budget(id, budget_id).unwrap().spent(), &3);
+    })
+}
+
+#[test]
+fn dao_budget_renews_every_period() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let budget_id = BudgetId::from_low_u64_be(1);
+        let set_budget = set_budget_call(budget_id, BudgetSpender::Member(member), 10, 5);
+        assert_ok!(DeipDao::on_behalf(Origin::signed(owner), id, set_budget));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, spend_call(10)));
+        
+        System::set_block_number(5);
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), id, spend_call(1)),
+            Error::<TestRuntime>::BudgetExceeded,
+        );
+        assert_eq!(DeipDao::rpc_get_budget(id, budget_id).unwrap().spent(), &10);
+        
+        System::set_block_number(13);
+        assert_eq!(DeipDao::rpc_get_budget(id, budget_id).unwrap().spent(), &0);
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, spend_call(3)));
+        let budget = DeipDao::get_budget(id, budget_id).unwrap();
+        assert_eq!((budget.period_start(), budget.spent()), (&11, &3));
+    })
+}
+
+#[test]
+fn dao_role_members_share_budget() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member1, member2) = (1, 2, 3);
+        let id = create_dao(owner);
+        join_dao(id, member1);
+        let authority = join_dao(id, member2);
+        let role_id = RoleId::from_low_u64_be(1);
+        let budget_id = BudgetId::from_low_u64_be(1);
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, set_role_call(role_id, vec![])));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, assign_role_call(role_id, member1)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, assign_role_call(role_id, member2)));
+        let set_budget = set_budget_call(budget_id, BudgetSpender::Role(role_id), 10, 0);
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, set_budget));
+        
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member1), id, spend_call(6)));
         assert_noop!(
-            DeipDao::on_behalf(
-                Origin::signed(transfer_to),
-                name,
-                Box::new(Call::DeipDao(RawCall::transfer_ownership(
-                    name,
-                    transfer_to,
-                    plain_key_source(transfer_to)
-                )))
-            ),
+            DeipDao::on_behalf(Origin::signed(member2), id, spend_call(6)),
+            Error::<TestRuntime>::BudgetExceeded,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member2), id, spend_call(4)));
+        assert_eq!(DeipDao::rpc_get_budget_list(id), vec![(budget_id, DeipDao::get_budget(id, budget_id).unwrap())]);
+        
+        let remove = Box::new(Call::DeipDao(RawCall::remove_budget(budget_id)));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(authority), id, remove.clone()));
+        expect_event(RawEvent::DaoBudgetRemoved(id, budget_id));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(authority), id, remove),
+            Error::<TestRuntime>::BudgetNotFound,
+        );
+    })
+}
+
+fn enable_governance(owner: u64, id: DaoId, weighting: VoteWeighting<u32>) {
+    let governance = Governance {
+        weighting,
+        quorum: Perbill::from_percent(50),
+        threshold: Perbill::from_percent(60),
+        voting_period: 10,
+    };
+    let call = Box::new(Call::DeipDao(RawCall::set_governance(Some(governance))));
+    assert_ok!(DeipDao::on_behalf(Origin::signed(owner), id, call));
+    expect_event(RawEvent::DaoGovernanceSet(id));
+}
+
+fn update_dao_call(metadata: sp_core::H256) -> Box<Call> {
+    Box::new(Call::DeipDao(RawCall::update_dao(Some(metadata))))
+}
+
+fn invite_call(member: u64, expires_at: u64) -> Box<Call> {
+    Box::new(Call::DeipDao(RawCall::invite(member, expires_at)))
+}
+
+#[test]
+fn dao_governed_by_member_motions() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let motion_id = MotionId::from_low_u64_be(1);
+        assert_noop!(
+            DeipDao::propose_motion(Origin::signed(owner), id, motion_id, invite_call(member, 100)),
+            Error::<TestRuntime>::GovernanceNotSet,
+        );
+        enable_governance(owner, id, VoteWeighting::Member);
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(owner), id, invite_call(member, 100)),
             Error::<TestRuntime>::Forbidden,
         );
+        assert_noop!(
+            DeipDao::propose_motion(Origin::signed(member), id, motion_id, invite_call(member, 100)),
+            Error::<TestRuntime>::Forbidden,
+        );
+        
+        assert_ok!(DeipDao::propose_motion(Origin::signed(owner), id, motion_id, invite_call(member, 100)));
+        expect_event(RawEvent::DaoMotionProposed(id, motion_id, owner));
+        assert_noop!(
+            DeipDao::propose_motion(Origin::signed(owner), id, motion_id, invite_call(member, 100)),
+            Error::<TestRuntime>::MotionExists,
+        );
+        assert_noop!(
+            DeipDao::close_motion(Origin::signed(member), id, motion_id),
+            Error::<TestRuntime>::MotionVotingNotEnded,
+        );
+        System::set_block_number(12);
+        assert_noop!(
+            DeipDao::vote_motion(Origin::signed(owner), id, motion_id, false),
+            Error::<TestRuntime>::MotionVotingEnded,
+        );
+        assert_ok!(DeipDao::close_motion(Origin::signed(member), id, motion_id));
+        expect_event(RawEvent::DaoMotionExecuted(id, motion_id, Ok(())));
+        assert!(DeipDao::get_motion(id, motion_id).is_none());
+        assert_ok!(DeipDao::accept_invitation(Origin::signed(member), id));
+        assert_eq!(DeipDao::get_dao(id).unwrap().authority().signatories(), &[owner, member]);
+        
+        // 1 of 2 votes does not reach the threshold
+        let motion_id = MotionId::from_low_u64_be(2);
+        let metadata = sp_core::H256::from_low_u64_be(1);
+        assert_ok!(DeipDao::propose_motion(Origin::signed(member), id, motion_id, update_dao_call(metadata)));
+        assert_ok!(DeipDao::vote_motion(Origin::signed(owner), id, motion_id, false));
+        expect_event(RawEvent::DaoMotionVoted(id, motion_id, owner, false));
+        System::set_block_number(23);
+        assert_ok!(DeipDao::close_motion(Origin::signed(member), id, motion_id));
+        expect_event(RawEvent::DaoMotionRejected(id, motion_id));
+    })
+}
+
+#[test]
+fn dao_governed_by_token_weighted_motions() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let owner = 1;
+        let id = create_dao(owner);
+        join_dao(id, 2);
+        let authority = join_dao(id, 4);
+        enable_governance(authority, id, VoteWeighting::Token(VOTING_TOKEN));
+        let metadata = sp_core::H256::from_low_u64_be(1);
+        
+        // 2 of 10 does not reach the quorum
+        let motion_id = MotionId::from_low_u64_be(1);
+        assert_ok!(DeipDao::propose_motion(Origin::signed(2), id, motion_id, update_dao_call(metadata)));
+        assert_noop!(
+            DeipDao::vote_motion(Origin::signed(0), id, motion_id, true),
+            Error::<TestRuntime>::Forbidden,
+        );
+        // token holders that are not members don't vote
+        assert_noop!(
+            DeipDao::propose_motion(Origin::signed(3), id, MotionId::from_low_u64_be(3), update_dao_call(metadata)),
+            Error::<TestRuntime>::Forbidden,
+        );
+        assert_noop!(
+            DeipDao::vote_motion(Origin::signed(3), id, motion_id, true),
+            Error::<TestRuntime>::Forbidden,
+        );
+        System::set_block_number(12);
+        assert_ok!(DeipDao::close_motion(Origin::signed(2), id, motion_id));
+        expect_event(RawEvent::DaoMotionRejected(id, motion_id));
+        
+        // 4 of 6 cast reaches the threshold
+        let motion_id = MotionId::from_low_u64_be(2);
+        assert_ok!(DeipDao::propose_motion(Origin::signed(4), id, motion_id, update_dao_call(metadata)));
+        assert_ok!(DeipDao::vote_motion(Origin::signed(2), id, motion_id, true));
+        assert_ok!(DeipDao::vote_motion(Origin::signed(2), id, motion_id, false));
+        assert_eq!(DeipDao::get_motion(id, motion_id).unwrap().nays(), &[(2, 2)]);
+        System::set_block_number(23);
+        assert_ok!(DeipDao::close_motion(Origin::signed(2), id, motion_id));
+        assert!(System::events().iter().any(|x| matches!(
+            &x.event,
+            Event::pallet_deip_dao(RawEvent::DaoMetadataUpdated(dao)) if dao.id() == &id
+        )));
+        expect_event(RawEvent::DaoMotionExecuted(id, motion_id, Ok(())));
+    })
+}
+
+#[test]
+fn dao_motion_counts_transferred_tokens_once() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let owner = 1;
+        let id = create_dao(owner);
+        join_dao(id, 0);
+        join_dao(id, 3);
+        let authority = join_dao(id, 6);
+        enable_governance(authority, id, VoteWeighting::Token(VOTING_TOKEN));
+        let metadata = sp_core::H256::from_low_u64_be(1);
+        
+        // 3 of 10 would reach the quorum if the same 3 tokens were counted twice
+        let motion_id = MotionId::from_low_u64_be(1);
+        assert_ok!(DeipDao::propose_motion(Origin::signed(3), id, motion_id, update_dao_call(metadata)));
+        transfer_tokens(3, 0, 3);
+        assert_ok!(DeipDao::vote_motion(Origin::signed(0), id, motion_id, true));
+        assert_eq!(DeipDao::get_motion(id, motion_id).unwrap().ayes(), &[(0, 3), (3, 3)]);
+        System::set_block_number(12);
+        assert_ok!(DeipDao::close_motion(Origin::signed(0), id, motion_id));
+        expect_event(RawEvent::DaoMotionRejected(id, motion_id));
+        
+        // tokens sold after the vote are not counted
+        let motion_id = MotionId::from_low_u64_be(2);
+        assert_ok!(DeipDao::propose_motion(Origin::signed(6), id, motion_id, update_dao_call(metadata)));
+        transfer_tokens(6, 7, 6);
+        System::set_block_number(23);
+        assert_ok!(DeipDao::close_motion(Origin::signed(0), id, motion_id));
+        expect_event(RawEvent::DaoMotionRejected(id, motion_id));
+    })
+}
+
+fn dao_ids_by_member(member: u64) -> Vec<DaoId> {
+    DaoIdByMember::<TestRuntime>::iter_prefix(member).map(|(id, _)| id).collect()
+}
+
+#[test]
+fn dao_member_index_follows_authority() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = create_dao(1);
+        let dao_key = DeipDao::dao_key(&id);
+        assert_eq!(dao_ids_by_member(1), vec![id]);
+        
+        assert_ok!(DeipDao::invite(Origin::signed(dao_key), 2, 100));
+        assert_ok!(DeipDao::accept_invitation(Origin::signed(2), id));
+        assert_eq!((dao_ids_by_member(1), dao_ids_by_member(2)), (vec![id], vec![id]));
+        
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::RemoveMember { member: 1 }));
+        assert_eq!((dao_ids_by_member(1), dao_ids_by_member(2)), (vec![], vec![id]));
+        
+        assert_ok!(DeipDao::invite(Origin::signed(dao_key), 3, 100));
+        assert_ok!(DeipDao::accept_invitation(Origin::signed(3), id));
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::ReplaceAuthority {
+            authority_key: 3,
+            authority: plain_key_source(3),
+        }));
+        assert_eq!((dao_ids_by_member(2), dao_ids_by_member(3)), (vec![], vec![id]));
+        assert_eq!(DeipDao::rpc_get_list_by_member(3), vec![DeipDao::get_dao(id).unwrap()]);
+        assert!(DeipDao::rpc_get_list_by_member(1).is_empty());
+    })
+}
+
+#[test]
+fn dao_member_joins_by_invitation() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let dao_key = DeipDao::dao_key(&id);
+        assert_noop!(
+            DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::AddMember { member }),
+            Error::<TestRuntime>::InvitationRequired,
+        );
+        assert_noop!(
+            DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::ReplaceAuthority {
+                authority_key: member,
+                authority: plain_key_source(member),
+            }),
+            Error::<TestRuntime>::InvitationRequired,
+        );
+        assert_noop!(
+            DeipDao::invite(Origin::signed(dao_key), owner, 10),
+            Error::<TestRuntime>::AlreadyMember,
+        );
+        assert_noop!(
+            DeipDao::accept_invitation(Origin::signed(member), id),
+            Error::<TestRuntime>::InvitationNotFound,
+        );
+        
+        assert_ok!(DeipDao::on_behalf(Origin::signed(owner), id, invite_call(member, 10)));
+        expect_event(RawEvent::DaoMemberInvited(id, member, 10));
+        assert_noop!(
+            DeipDao::invite(Origin::signed(dao_key), member, 20),
+            Error::<TestRuntime>::InvitationExists,
+        );
+        let invitation = Invitation::new(10);
+        assert_eq!(DeipDao::rpc_get_invitation_list_by_dao(id), vec![(member, invitation.clone())]);
+        assert_eq!(DeipDao::rpc_get_invitation_list_by_member(member), vec![(id, invitation)]);
+        
+        assert_ok!(DeipDao::accept_invitation(Origin::signed(member), id));
+        expect_event(RawEvent::DaoAlterAuthority(DeipDao::get_dao(id).unwrap()));
+        assert_eq!(DeipDao::get_dao(id).unwrap().authority().signatories(), &[owner, member]);
+        assert!(DeipDao::get_invitation(id, member).is_none());
+        assert!(DeipDao::rpc_get_invitation_list_by_member(member).is_empty());
+        
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::ReplaceAuthority {
+            authority_key: member,
+            authority: plain_key_source(member),
+        }));
+        assert_eq!(DeipDao::get_dao(id).unwrap().authority().signatories(), &[member]);
+    })
+}
+
+#[test]
+fn dao_invitation_declined_cancelled_or_expired() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let dao_key = DeipDao::dao_key(&id);
+        
+        assert_ok!(DeipDao::invite(Origin::signed(dao_key), member, 10));
+        assert_ok!(DeipDao::decline_invitation(Origin::signed(member), id));
+        expect_event(RawEvent::DaoInvitationDeclined(id, member));
+        assert!(DeipDao::rpc_get_invitation_list_by_dao(id).is_empty());
+        
+        assert_ok!(DeipDao::invite(Origin::signed(dao_key), member, 10));
+        assert_ok!(DeipDao::cancel_invitation(Origin::signed(dao_key), member));
+        expect_event(RawEvent::DaoInvitationCancelled(id, member));
+        assert_noop!(
+            DeipDao::decline_invitation(Origin::signed(member), id),
+            Error::<TestRuntime>::InvitationNotFound,
+        );
+        
+        assert_ok!(DeipDao::invite(Origin::signed(dao_key), member, 10));
+        System::set_block_number(11);
+        assert!(DeipDao::rpc_get_invitation_list_by_member(member).is_empty());
+        assert_noop!(
+            DeipDao::accept_invitation(Origin::signed(member), id),
+            Error::<TestRuntime>::InvitationExpired,
+        );
+        assert_noop!(
+            DeipDao::invite(Origin::signed(dao_key), member, 10),
+            Error::<TestRuntime>::InvitationExpired,
+        );
+        assert_ok!(DeipDao::invite(Origin::signed(dao_key), member, 20));
+        assert_ok!(DeipDao::accept_invitation(Origin::signed(member), id));
+    })
+}
+
+fn dao_threshold(id: DaoId) -> u16 {
+    DeipDao::get_dao(id).unwrap().authority().threshold()
+}
+
+#[test]
+fn dao_threshold_policy_applied_on_membership_change() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = create_dao(1);
+        let dao_key = DeipDao::dao_key(&id);
+        
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::SetThresholdPolicy {
+            policy: Some(ThresholdPolicy::Unanimous),
+        }));
+        assert_eq!(dao_threshold(id), 0);
+        for member in 2..=4 {
+            assert_ok!(DeipDao::invite(Origin::signed(dao_key), member, 100));
+            assert_ok!(DeipDao::accept_invitation(Origin::signed(member), id));
+            assert_eq!(dao_threshold(id), member as u16);
+        }
+        
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::SetThresholdPolicy {
+            policy: Some(ThresholdPolicy::Fraction(Perbill::from_percent(50))),
+        }));
+        assert_eq!(dao_threshold(id), 2);
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::RemoveMember { member: 4 }));
+        assert_eq!(dao_threshold(id), 2);
+        
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::SetThresholdPolicy {
+            policy: Some(ThresholdPolicy::Fixed(5)),
+        }));
+        assert_eq!(dao_threshold(id), 3);
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::RemoveMember { member: 3 }));
+        assert_eq!(dao_threshold(id), 2);
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::RemoveMember { member: 2 }));
+        assert_eq!(dao_threshold(id), 0);
+        let dao = DeipDao::get_dao(id).unwrap();
+        assert_eq!(dao.authority_key(), &1);
+        assert_eq!(dao.threshold_policy(), Some(&ThresholdPolicy::Fixed(5)));
+    })
+}
+
+#[test]
+fn dao_set_threshold_keeps_signatories() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = create_dao(1);
+        let dao_key = DeipDao::dao_key(&id);
+        for member in 2..=3 {
+            assert_ok!(DeipDao::invite(Origin::signed(dao_key), member, 100));
+            assert_ok!(DeipDao::accept_invitation(Origin::signed(member), id));
+        }
+        assert_eq!(dao_threshold(id), 2);
+        let authority_key = DeipDao::get_dao(id).unwrap().authority_key().clone();
+        
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::SetThreshold { threshold: 3 }));
+        let dao = DeipDao::get_dao(id).unwrap();
+        expect_event(RawEvent::DaoAlterAuthority(dao.clone()));
+        assert_eq!(dao.authority().signatories(), &[1, 2, 3]);
+        assert_eq!(dao.authority().threshold(), 3);
+        assert_ne!(dao.authority_key(), &authority_key);
+        assert_eq!(DeipDao::lookup_dao(dao_key), Some(id));
+        
+        for threshold in [0, 4] {
+            assert_noop!(
+                DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::SetThreshold { threshold }),
+                Error::<TestRuntime>::AuthorityMismatch,
+            );
+        }
+        
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), AlterAuthority::RemoveMember { member: 3 }));
+        assert_eq!(dao_threshold(id), 2);
+    })
+}
+
+#[test]
+fn dao_dissolve_sweeps_assets() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member, beneficiary) = (1, 2, 3);
+        let id = create_dao(owner);
+        let dao_key = DeipDao::dao_key(&id);
+        assert_ok!(DeipDao::invite(Origin::signed(dao_key), member, 100));
+        assert_ok!(DeipDao::accept_invitation(Origin::signed(member), id));
+        assert_ok!(DeipDao::invite(Origin::signed(dao_key), 4, 100));
+        let role_id = RoleId::from([1; 20]);
+        assert_ok!(DeipDao::set_role(Origin::signed(dao_key), role_id, vec![]));
+        assert_ok!(DeipDao::assign_role(Origin::signed(dao_key), role_id, member));
+        
+        assert_noop!(
+            DeipDao::dissolve(Origin::signed(dao_key), vec![(BudgetAsset::Asset(FROZEN_ASSET), beneficiary)], 10),
+            DispatchError::Other("Frozen"),
+        );
+        assert_noop!(DeipDao::dissolve(Origin::signed(owner), vec![], 10), Error::<TestRuntime>::NotFound);
+        
+        hold(dao_key, BudgetAsset::Native);
+        hold(dao_key, BudgetAsset::Asset(VOTING_TOKEN));
+        assert_ok!(DeipDao::dissolve(Origin::signed(dao_key), vec![
+            (BudgetAsset::Native, beneficiary),
+            (BudgetAsset::Asset(VOTING_TOKEN), member),
+        ], 10));
+        expect_event(RawEvent::DaoDissolved(id));
+        assert_eq!(swept(), vec![
+            (BudgetAsset::Native, dao_key, beneficiary),
+            (BudgetAsset::Asset(VOTING_TOKEN), dao_key, member),
+        ]);
+        assert!(DeipDao::is_dissolved(&id));
+        assert!(DeipDao::get_dao(id).is_none());
+        assert!(DeipDao::lookup_dao(dao_key).is_none());
+        assert!(dao_ids_by_member(owner).is_empty() && dao_ids_by_member(member).is_empty());
+        assert!(DeipDao::rpc_get_invitation_list_by_member(4).is_empty());
+        assert!(DeipDao::get_role(id, role_id).is_none());
+        assert!(DeipDao::member_roles(id, member).is_empty());
+        
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(owner), id, Box::new(remark_call())),
+            Error::<TestRuntime>::Dissolved,
+        );
+        assert_noop!(
+            DeipDao::create(Origin::signed(owner), id, plain_key_source(owner), None),
+            Error::<TestRuntime>::Dissolved,
+        );
+    })
+}
+
+#[test]
+fn dao_dissolve_weight_depends_on_assets() {
+    use frame_support::weights::GetDispatchInfo;
+    let call = |beneficiaries: usize, assets_witness: u32| RawCall::<TestRuntime>::dissolve(
+        vec![(BudgetAsset::Native, 1); beneficiaries],
+        assets_witness,
+    ).get_dispatch_info().weight;
+    assert!(call(1, 0) > call(0, 0));
+    assert!(call(0, 10) > call(0, 1));
+}
+
+#[test]
+fn dao_dissolve_rejected_if_assets_left() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, beneficiary, unlisted_asset) = (1, 3, 5);
+        let id = create_dao(owner);
+        let dao_key = DeipDao::dao_key(&id);
+        hold(dao_key, BudgetAsset::Native);
+        hold(dao_key, BudgetAsset::Asset(unlisted_asset));
+        
+        assert_noop!(
+            DeipDao::dissolve(Origin::signed(dao_key), vec![(BudgetAsset::Native, beneficiary)], 10),
+            Error::<TestRuntime>::AssetsNotSwept,
+        );
+        assert_noop!(
+            DeipDao::dissolve(Origin::signed(dao_key), vec![(BudgetAsset::Native, beneficiary)], 0),
+            Error::<TestRuntime>::AssetsWitnessTooLow,
+        );
+        assert!(!DeipDao::is_dissolved(&id));
+        
+        assert_ok!(DeipDao::dissolve(Origin::signed(dao_key), vec![
+            (BudgetAsset::Native, beneficiary),
+            (BudgetAsset::Asset(unlisted_asset), beneficiary),
+        ], 0));
+        expect_event(RawEvent::DaoDissolved(id));
+    })
+}
+
+fn create_sub_dao(who: u64, id: DaoId, sub_dao_id: DaoId) {
+    let sub_dao_key = DeipDao::dao_key(&sub_dao_id);
+    let create = Call::DeipDao(RawCall::create(id, plain_key_source(sub_dao_key), None));
+    assert_ok!(DeipDao::on_behalf(Origin::signed(who), sub_dao_id, Box::new(create)));
+}
+
+#[test]
+fn dao_nested_authority_acts_on_behalf() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (owner, member) = (1, 2);
+        let lab = create_dao(owner);
+        let department = DaoId::from([1; 20]);
+        let university = DaoId::from([2; 20]);
+        let consortium = DaoId::from([3; 20]);
+        create_sub_dao(owner, department, lab);
+        create_sub_dao(owner, university, department);
+        create_sub_dao(owner, consortium, university);
+        assert_eq!(
+            DeipDao::get_dao(university).unwrap().authority_key(),
+            &DeipDao::dao_key(&department)
+        );
+        
+        assert_ok!(DeipDao::on_behalf(Origin::signed(owner), university, Box::new(remark_call())));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(owner), consortium, Box::new(remark_call())),
+            Error::<TestRuntime>::Forbidden,
+        );
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), department, Box::new(remark_call())),
+            Error::<TestRuntime>::Forbidden,
+        );
+        
+        let university_key = DeipDao::dao_key(&university);
+        assert_ok!(DeipDao::invite(Origin::signed(university_key), member, 100));
+        assert_ok!(DeipDao::accept_invitation(Origin::signed(member), university));
+        assert_ok!(DeipDao::alter_authority(Origin::signed(university_key), AlterAuthority::SetThreshold {
+            threshold: 2,
+        }));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(owner), university, Box::new(remark_call())),
+            Error::<TestRuntime>::Forbidden,
+        );
+        
+        enable_governance(owner, department, VoteWeighting::Member);
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(owner), department, Box::new(remark_call())),
+            Error::<TestRuntime>::Forbidden,
+        );
+    })
+}
+
+fn dao_metadata_history(id: DaoId) -> Vec<MetadataRevisionOf<TestRuntime>> {
+    DeipDao::rpc_get_metadata_history(id).into_iter().map(|(_, x)| x).collect()
+}
+
+#[test]
+fn dao_metadata_history_tracks_changes() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let (owner, member) = (1, 2);
+        let id = create_dao(owner);
+        let metadata = sp_core::H256::from_low_u64_be(1);
+        
+        System::set_block_number(2);
+        Timestamp::set_timestamp(20);
+        assert_ok!(DeipDao::on_behalf(Origin::signed(owner), id, update_dao_call(metadata)));
+        let record = DaoMetadata {
+            name: b"Lab".to_vec(),
+            description: None,
+            logo: Some(sp_core::H256::from_low_u64_be(2)),
+            website: Some(b"https://lab.example".to_vec()),
+        };
+        assert_noop!(
+            DeipDao::set_metadata_record(Origin::signed(DeipDao::dao_key(&id)), Some(DaoMetadata {
+                name: vec![0; MAX_METADATA_FIELD_LENGTH + 1],
+                ..record.clone()
+            })),
+            Error::<TestRuntime>::MetadataTooLong,
+        );
+        
+        enable_governance(owner, id, VoteWeighting::Member);
+        let motion_id = MotionId::from_low_u64_be(1);
+        let call = Box::new(Call::DeipDao(RawCall::set_metadata_record(Some(record.clone()))));
+        assert_ok!(DeipDao::propose_motion(Origin::signed(owner), id, motion_id, call));
+        System::set_block_number(13);
+        Timestamp::set_timestamp(130);
+        assert_ok!(DeipDao::close_motion(Origin::signed(member), id, motion_id));
+        assert_eq!(DeipDao::get_dao(id).unwrap().metadata_record(), Some(&record));
+        
+        assert_eq!(dao_metadata_history(id), vec![
+            MetadataRevision::new(None, None, 1, 10, owner),
+            MetadataRevision::new(Some(metadata), None, 2, 20, owner),
+            MetadataRevision::new(Some(metadata), Some(record), 13, 130, owner),
+        ]);
+        
+        assert_ok!(DeipDao::dissolve(Origin::signed(DeipDao::dao_key(&id)), vec![], 0));
+        assert_eq!(dao_metadata_history(id).len(), 3);
+    })
+}
+
+#[test]
+fn dao_handle_validation() {
+    assert!(is_valid_handle(b"deip-lab_1"));
+    assert!(!is_valid_handle(b"ab"));
+    assert!(!is_valid_handle(&[b'a'; MAX_HANDLE_LENGTH + 1]));
+    assert!(!is_valid_handle(b"Lab"));
+    assert!(!is_valid_handle(b"lab 1"));
+    assert!(!is_valid_handle(b"-lab"));
+    assert!(!is_valid_handle(b"lab_"));
+}
+
+#[test]
+fn dao_handle_set_and_renamed() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let owner = 1;
+        let id = create_dao(owner);
+        let dao_key = DeipDao::dao_key(&id);
+        let other = DaoId::from([1; 20]);
+        create_sub_dao(owner, other, id);
+        let other_key = DeipDao::dao_key(&other);
+        
+        assert_noop!(
+            DeipDao::set_handle(Origin::signed(dao_key), b"Lab".to_vec()),
+            Error::<TestRuntime>::HandleInvalid,
+        );
+        assert_noop!(
+            DeipDao::reserve_handle(Origin::signed(owner), b"deip".to_vec()),
+            DispatchError::BadOrigin,
+        );
+        assert_ok!(DeipDao::reserve_handle(RawOrigin::Root.into(), b"deip".to_vec()));
+        expect_event(RawEvent::DaoHandleReserved(b"deip".to_vec()));
+        assert_noop!(
+            DeipDao::set_handle(Origin::signed(dao_key), b"deip".to_vec()),
+            Error::<TestRuntime>::HandleReserved,
+        );
+        
+        assert_ok!(DeipDao::set_handle(Origin::signed(dao_key), b"lab".to_vec()));
+        expect_event(RawEvent::DaoHandleSet(id, b"lab".to_vec()));
+        assert_eq!(DeipDao::rpc_get_id_by_handle(b"lab".to_vec()), Some(id));
+        assert_noop!(
+            DeipDao::set_handle(Origin::signed(other_key), b"lab".to_vec()),
+            Error::<TestRuntime>::HandleTaken,
+        );
+        assert_noop!(
+            DeipDao::set_handle(Origin::signed(dao_key), b"lab-1".to_vec()),
+            Error::<TestRuntime>::HandleRenameCooldown,
+        );
+        
+        System::set_block_number(11);
+        assert_ok!(DeipDao::set_handle(Origin::signed(dao_key), b"lab-1".to_vec()));
+        assert_eq!(DeipDao::rpc_get_id_by_handle(b"lab".to_vec()), None);
+        assert_eq!(DeipDao::dao_handle(id).unwrap(), HandleRecord::new(b"lab-1".to_vec(), 11));
+        assert_ok!(DeipDao::set_handle(Origin::signed(other_key), b"lab".to_vec()));
+        
+        assert_ok!(DeipDao::dissolve(Origin::signed(dao_key), vec![], 0));
+        assert_eq!(DeipDao::rpc_get_id_by_handle(b"lab-1".to_vec()), None);
+        assert_noop!(
+            DeipDao::set_handle(Origin::signed(other_key), b"lab-1".to_vec()),
+            Error::<TestRuntime>::HandleReserved,
+        );
+        assert_ok!(DeipDao::release_handle(RawOrigin::Root.into(), b"lab-1".to_vec()));
+        assert_noop!(
+            DeipDao::release_handle(RawOrigin::Root.into(), b"lab-1".to_vec()),
+            Error::<TestRuntime>::HandleNotReserved,
+        );
     })
 }
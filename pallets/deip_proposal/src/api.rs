@@ -0,0 +1,119 @@
+// `decl_runtime_apis` generates client side functions with ten arguments
+#![allow(clippy::too_many_arguments)]
+
+use sp_std::prelude::*;
+
+use codec::{Codec, Encode, Decode};
+
+use sp_runtime::{DispatchError, RuntimeDebug};
+
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use frame_support::weights::Weight;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+use super::proposal::{ProposalId, BatchItem, ProposalBatch, ExecutionMode, DeipProposal};
+
+/// Batch to be dry-run: a new one in the given mode
+/// or a batch of an existing proposal in it's execution mode
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum DryRunTarget<Account, Call> {
+    Batch(Vec<BatchItem<Account, Call>>, ExecutionMode),
+    Proposal(ProposalId),
+}
+
+/// Module error of the dispatch result
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DryRunModuleError {
+    /// Module index in the runtime
+    pub index: u8,
+    /// Error index in the module
+    pub error: u8,
+    /// Error name
+    pub name: Option<Vec<u8>>,
+}
+
+impl DryRunModuleError {
+    fn from_dispatch_error(e: DispatchError) -> Option<Self> {
+        match e {
+            DispatchError::Module { index, error, message } => Some(Self {
+                index,
+                error,
+                name: message.map(|x| x.as_bytes().to_vec()),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Dry-run result of the batch item
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct DryRunItem<Event> {
+    pub result: DispatchResultWithPostInfo,
+    pub module_error: Option<DryRunModuleError>,
+    pub events: Vec<Event>,
+    /// Actual weight consumed by the item
+    pub weight: Weight,
+}
+
+/// Results of executed batch items.
+/// Batch is executed in it's mode, so items skipped after the failed one
+/// in the atomic mode have no results.
+/// `None` if proposal not found, new batch fails the same checks as on `propose`
+/// (size, depth and items limits, references) or account of the batch can't be resolved
+pub type DryRunResult<Event> = Option<Vec<DryRunItem<Event>>>;
+
+sp_api::decl_runtime_apis! {
+    pub trait DeipProposalRuntimeApi<AccountId: Codec, Call: Codec, Event: Codec> {
+        fn dry_run(target: DryRunTarget<AccountId, Call>) -> DryRunResult<Event>;
+    }
+}
+
+use super::{Pallet, Config, ProposalRepository};
+
+impl<T: Config> Pallet<T> {
+    pub fn rpc_dry_run(
+        target: DryRunTarget<T::DeipAccountId, <T as Config>::Call>
+    )
+        -> DryRunResult<<T as frame_system::Config>::Event>
+    {
+        let (batch, mode): (ProposalBatch<T>, _) = match target {
+            DryRunTarget::Batch(batch, mode) => {
+                let (batch, _) = DeipProposal::<T>::prepare_batch(batch, &ProposalId::default()).ok()?;
+                (batch, mode)
+            },
+            DryRunTarget::Proposal(id) => {
+                let proposal = ProposalRepository::<T>::get(id)?;
+                (proposal.batch, proposal.execution_mode)
+            },
+        };
+        Some(with_transaction(|| TransactionOutcome::Rollback(Self::dry_run_batch(batch, mode))))
+    }
+
+    /// Execute batch collecting events and module errors of every executed item
+    fn dry_run_batch(
+        batch: ProposalBatch<T>,
+        mode: ExecutionMode,
+    )
+        -> Vec<DryRunItem<<T as frame_system::Config>::Event>>
+    {
+        let mut items = Vec::with_capacity(batch.len());
+        let mut emitted = frame_system::Module::<T>::events().len();
+        Self::exec_batch_observed(batch, mode, |result, weight| {
+            let events = frame_system::Module::<T>::events();
+            let item_events = events.iter().skip(emitted).map(|x| x.event.clone()).collect();
+            emitted = events.len();
+            items.push(DryRunItem {
+                result: *result,
+                module_error: result.as_ref().err()
+                    .and_then(|e| DryRunModuleError::from_dispatch_error(e.error)),
+                events: item_events,
+                weight,
+            });
+        });
+        items
+    }
+}
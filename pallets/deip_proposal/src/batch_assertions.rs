@@ -1,5 +1,10 @@
 /// Module contains some assertions on proposal's batch
 
+use sp_std::prelude::*;
+
+use codec::Encode;
+use frame_support::traits::Get;
+
 use crate::proposal::{ProposalBatchX, ProposalId};
 use crate::batch_item_kind::{BatchItemKindT, BatchItemKind};
 use crate::batch_tree::{traverse_batch_tree, BatchTreeNode, StopTraverse};
@@ -11,28 +16,66 @@ pub enum ProposalAssertions {
     /// Reached depth limit of nested proposals
     DepthLimit,
     /// Proposal has self-references
-    SelfReference
+    SelfReference,
+    /// Nested proposal refers to one of it's ancestors
+    CyclicReference,
+    /// Batch of some level has too many items
+    ItemsLimit,
+    /// Batch tree has too many items in total
+    TotalItemsLimit,
+    /// Encoded batch is too large
+    SizeLimit,
 }
 
 /// Perform some assertions on proposal object
-pub fn assert_proposal<T: Config, BatchItem: BatchItemKindT<T>>(
+pub fn assert_proposal<T: Config, BatchItem: BatchItemKindT<T> + Encode>(
     batch: &ProposalBatchX<BatchItem>,
     proposal_id: &ProposalId,
-    depth_limit: usize,
 )
     -> Option<ProposalAssertions>
 {
+    if batch.encoded_size() > T::MaxBatchEncodedSize::get() as usize {
+        return Some(ProposalAssertions::SizeLimit)
+    }
+    let items_limit = T::MaxBatchItems::get() as usize;
+    if batch.len() > items_limit {
+        return Some(ProposalAssertions::ItemsLimit)
+    }
+    let depth_limit = T::MaxNestingDepth::get() as usize;
+    let total_items_limit = T::MaxTotalBatchItems::get() as usize;
+    let mut total_items = 0usize;
+    // IDs of proposals that contain the visited node: root one and nested ones
+    // (nested proposal has no ID until creation if `external_id` is not provided)
+    let mut ancestors: Vec<Option<&ProposalId>> = vec![Some(proposal_id)];
     let mut res = None;
-    traverse_batch_tree::<T, _, _>(&batch, |node: BatchTreeNode<&BatchItem>| {
+    traverse_batch_tree::<T, _, _>(batch, |node: BatchTreeNode<&BatchItem>| {
         if node.depth > depth_limit {
             res = Some(ProposalAssertions::DepthLimit);
             return Some(StopTraverse)
         }
-        if let BatchItemKind::Decide(id) = BatchItemKindT::<T>::kind(node.data) {
-            if id == proposal_id {
+        total_items += 1;
+        if total_items > total_items_limit {
+            res = Some(ProposalAssertions::TotalItemsLimit);
+            return Some(StopTraverse)
+        }
+        ancestors.truncate(node.depth);
+        match BatchItemKindT::<T>::kind(node.data) {
+            BatchItemKind::Decide(id) if id == proposal_id => {
                 res = Some(ProposalAssertions::SelfReference);
                 return Some(StopTraverse)
-            }
+            },
+            BatchItemKind::Decide(id) if ancestors.contains(&Some(id)) => {
+                res = Some(ProposalAssertions::CyclicReference);
+                return Some(StopTraverse)
+            },
+            BatchItemKind::Propose(nested, id) => {
+                if nested.len() > items_limit {
+                    res = Some(ProposalAssertions::ItemsLimit);
+                    return Some(StopTraverse)
+                }
+                ancestors.push(id);
+            },
+            _ => {},
         }
         None
     });
@@ -13,8 +13,9 @@ pub trait BatchItemKindT<T: Config>: Sized {
 
 /// Batch item kinds
 pub enum BatchItemKind<'a, Item> {
-    /// Batch item contains `propose` dispatchable
-    Propose(&'a ProposalBatchX<Item>),
+    /// Batch item contains `propose` or `amend` dispatchable:
+    /// (nested batch, ID of the nested proposal if known)
+    Propose(&'a ProposalBatchX<Item>, Option<&'a ProposalId>),
     /// Batch item contains `decide` dispatchable
     Decide(&'a ProposalId),
     Other
@@ -24,10 +25,14 @@ impl<T: Config> BatchItemKindT<T> for BatchItem<T::DeipAccountId, <T as Config>:
 
     fn kind(&self) -> BatchItemKind<'_, Self> {
         match self.call.is_sub_type() {
-            Some(Call::propose(batch, _)) => {
-                BatchItemKind::Propose(batch)
+            Some(Call::propose(batch, external_id, _, _, _, _)) => {
+                BatchItemKind::Propose(batch, external_id.as_ref())
             },
-            Some(Call::decide(proposal_id, _decision)) => {
+            Some(Call::amend(proposal_id, batch, _)) => {
+                BatchItemKind::Propose(batch, Some(proposal_id))
+            },
+            Some(Call::decide(proposal_id, _decision)) |
+            Some(Call::decide_as_dao(proposal_id, _, _decision)) => {
                 BatchItemKind::Decide(proposal_id)
             },
             _ => BatchItemKind::Other
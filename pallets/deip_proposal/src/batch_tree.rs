@@ -38,7 +38,7 @@ pub fn traverse_batch_tree<'a, T: Config, V, Data: BatchItemKindT<T>>(
                 return
             }
             match BatchItemKindT::<T>::kind(data) {
-                BatchItemKind::Propose(batch) => {
+                BatchItemKind::Propose(batch, _) => {
                     let boxed: Box<dyn Iterator<Item=&Data>> = Box::new(batch.iter());
                     stack.push_front(boxed.peekable());
                     break
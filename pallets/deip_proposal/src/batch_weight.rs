@@ -0,0 +1,72 @@
+//! Module contains weight calculations of the proposal dispatchables
+
+use frame_support::weights::{Weight, GetDispatchInfo};
+use frame_support::traits::Get;
+
+use crate::proposal::{ProposalId, ProposalBatch, InputProposalBatch};
+use crate::batch_item_kind::{BatchItemKind, BatchItemKindT};
+use crate::batch_tree::{traverse_batch_tree, BatchTreeNode};
+
+use super::{Config, ProposalRepository};
+
+
+/// Weight of proposal creation without weight of the batch.
+/// Writes proposal object, author's index, expiry queue and pending proposals of each member
+pub fn propose_base<T: Config>(items: usize) -> Weight {
+    T::DbWeight::get().reads_writes(1, 3u64.saturating_add(items as Weight))
+}
+
+/// Weight of decision without weight of the batch.
+/// Reads proposal object and timestamp, writes proposal related storage on resolve
+pub fn decide_base<T: Config>(items: usize) -> Weight {
+    T::DbWeight::get().reads_writes(2, 3u64.saturating_add(items as Weight))
+}
+
+/// Weight of proposal removal by it's author.
+/// Reads proposal object, updates expiry queue and pending proposals of each member,
+/// removes DAO decisions, author's index and proposal object
+pub fn cancel_base<T: Config>(members: usize) -> Weight {
+    let members = members as Weight;
+    T::DbWeight::get().reads_writes(2u64.saturating_add(members), 4u64.saturating_add(members))
+}
+
+/// Sum of dispatch weights of every call of the batch tree.
+/// Nested proposals are accounted by their own items only
+pub fn batch_tree_weight<T: Config>(batch: &InputProposalBatch<T>) -> Weight {
+    let mut weight: Weight = 0;
+    traverse_batch_tree::<T, _, _>(batch, |node: BatchTreeNode<&_>| {
+        if !matches!(BatchItemKindT::<T>::kind(node.data), BatchItemKind::Propose(..)) {
+            weight = weight.saturating_add(node.data.call.get_dispatch_info().weight);
+        }
+        None
+    });
+    weight
+}
+
+/// Sum of dispatch weights of the batch calls.
+/// Dispatch weight of nested `propose` call covers it's own batch
+pub fn batch_exec_weight<T: Config>(batch: &ProposalBatch<T>) -> Weight {
+    batch.iter().fold(0, |w: Weight, x| w.saturating_add(x.call.get_dispatch_info().weight))
+}
+
+/// Declared weight of `propose` (or `amend`) dispatchable
+pub fn propose<T: Config>(batch: &InputProposalBatch<T>) -> Weight {
+    propose_base::<T>(batch.len()).saturating_add(batch_tree_weight::<T>(batch))
+}
+
+/// Declared weight of `cancel` dispatchable
+pub fn cancel<T: Config>(proposal_id: &ProposalId) -> Weight {
+    let members = ProposalRepository::<T>::get(proposal_id).map_or(0, |x| x.decisions.len());
+    cancel_base::<T>(members)
+}
+
+/// Declared weight of `decide` dispatchable. Accounts for the worst case of batch execution
+pub fn decide<T: Config>(proposal_id: &ProposalId) -> Weight {
+    match ProposalRepository::<T>::get(proposal_id) {
+        Some(proposal) => {
+            decide_base::<T>(proposal.batch.len())
+                .saturating_add(batch_exec_weight::<T>(&proposal.batch))
+        },
+        None => decide_base::<T>(0),
+    }
+}
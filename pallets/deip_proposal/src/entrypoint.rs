@@ -1,6 +1,6 @@
 /// Pallet's business-logic public interface
 
-use crate::proposal::{InputProposalBatch, DeipProposal, ProposalId};
+use crate::proposal::{InputProposalBatch, DeipProposal, ProposalId, InputApprovalPolicy, ExecutionMode};
 use crate::storage::StorageWrite;
 
 use super::{Config, Error};
@@ -10,6 +10,10 @@ pub fn propose<T: Config>(
     author: T::AccountId,
     batch: InputProposalBatch<T>,
     external_id: Option<ProposalId>,
+    approval_policy: InputApprovalPolicy<T>,
+    execute_after: Option<T::Moment>,
+    ttl: Option<T::Moment>,
+    execution_mode: ExecutionMode,
 )
     -> Result<(), Error<T>>
 {
@@ -19,6 +23,10 @@ pub fn propose<T: Config>(
                 batch,
                 author,
                 external_id,
+                approval_policy,
+                execute_after,
+                ttl,
+                execution_mode,
                 ops,
                 pallet_timestamp::Module::<T>::get()
             )
@@ -8,9 +8,28 @@
 //! ## Overview
 //! This module contains functionality to create a postponed transaction (a proposal)
 //! where members of it are proposed to make decision on execution of of corresponding operations.
-//! When all members of proposal add them approvals then the bunch of operations (a batch)
+//! When members of proposal add them approvals so the proposal's approval policy is met
+//! (by default every member must approve) then the bunch of operations (a batch)
 //! that is a list of dispatchables with their signature origins (accounts)
 //! will be executed as a single transaction.
+//! Approval policy may also require an absolute number of approvals, a percentage of members
+//! or a total weight of approved members.
+//! Proposal may be time-locked with `execute_after` moment: approved proposal becomes scheduled
+//! and will be executed automatically at the beginning of the first block after this moment.
+//! Scheduled proposals are grouped by `ExpirySlot` intervals of their execution time as well.
+//! Scheduled proposals executed per block are limited by `MaxScheduledPerBlock` and by the total
+//! weight of their batches (`MaxScheduledWeight`), the rest ones wait for the next block.
+//! Pending proposal expires when it's time-to-live (`ttl`) is elapsed.
+//! Expired proposals are removed at the beginning of a block from the expiry queue
+//! that groups proposals by `ExpirySlot` intervals.
+//! Resolved (done, failed, rejected or expired) proposals are kept in the archive
+//! with final member decisions. Archive size is limited by `MaxArchivedProposals`,
+//! the oldest records are pruned first.
+//! The batch is executed in the mode chosen by the proposal author: atomically (by default),
+//! best-effort (failed items don't affect the rest ones) or all-or-nothing (every item is executed
+//! to report it's result but all changes are rolled back if any item failed).
+//! Weight of `propose` and `decide` dispatchables includes dispatch weights of the batch calls
+//! (nested proposals as well), unused weight is refunded.
 //!
 //! ## Interface
 //!
@@ -18,6 +37,11 @@
 //!
 //! * `propose` - Propose a postponed transaction.
 //! * `decide` - Make decision on a proposed transaction being a member of it.
+//! * `cancel` - Cancel a pending proposal being an author of it.
+//! * `amend` - Replace batch of a pending proposal being an author of it.
+//! * `expire` - Remove an expired proposal that is still waiting in the expiry queue.
+//! * `decide_as_dao` - Make decision on a proposed transaction being a signatory of DAO member of it.
+//!   Decision is made on behalf of DAO when the DAO's authority threshold is met.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
@@ -33,6 +57,9 @@ mod batch_item_kind;
 mod storage;
 pub mod entrypoint;
 mod batch_assertions;
+mod batch_weight;
+pub mod api;
+mod migration;
 
 #[doc(inline)]
 pub use pallet::*;
@@ -40,38 +67,40 @@ pub use pallet::*;
 /// Re-exports deip-toolkit
 pub use pallet_deip_toolkit;
 
-const NON_LOCAL: u8 = 99;
-
 #[frame_support::pallet]
 #[doc(hidden)]
 pub mod pallet {
     use frame_system::pallet_prelude::*;
     use frame_system::RawOrigin;
-    use frame_system::offchain::{SubmitTransaction, SendTransactionTypes};
     
     use frame_support::pallet_prelude::*;
-    use frame_support::weights::{PostDispatchInfo, GetDispatchInfo};
-    use frame_support::debug::RuntimeLogger;
-    use frame_support::debug::debug;
+    use frame_support::weights::{PostDispatchInfo, GetDispatchInfo, extract_actual_weight};
+    use frame_support::dispatch::DispatchErrorWithPostInfo;
+    use frame_support::storage::{with_transaction, TransactionOutcome};
     
     use frame_support::traits::{UnfilteredDispatchable, IsSubType};
     
     use sp_std::prelude::*;
+    use sp_std::convert::TryInto;
     use sp_std::collections::{btree_map::BTreeMap};
     
-    use sp_runtime::traits::{Dispatchable, Zero};
+    use sp_runtime::traits::{Dispatchable, Saturating, One, Zero};
+    use sp_runtime::DispatchError;
     
     use crate::proposal::{
         ProposalId, DeipProposal,
         ProposalMemberDecision, ProposalState,
         ProposalBatch, ProposalBatchItemOf,
-        InputProposalBatchItem
+        InputProposalBatchItem, InputApprovalPolicy,
+        ApprovalTally, ArchivedProposal, ProposalDecisions, DaoAuthorityT,
+        ExecutionMode, BatchItemResult, BatchResults, BatchExecOutcome,
     };
     use crate::storage::StorageWrite;
+    use crate::migration::Releases;
 
     /// Configuration trait
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_timestamp::Config + SendTransactionTypes<Call<Self>> {
+    pub trait Config: frame_system::Config + pallet_timestamp::Config {
         /// Type represents events
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         /// Type represents particular call from batch-transaction 
@@ -83,15 +112,60 @@ pub mod pallet {
              frame_support::dispatch::Codec + 
              IsSubType<Call<Self>>;
         
-        type DeipAccountId: Into<Self::AccountId> + Parameter + Member;
+        type DeipAccountId: TryInto<Self::AccountId> + Parameter + Member;
         
-        /// Pending proposal's time-to-live
+        /// Default pending proposal's time-to-live
         #[pallet::constant]
         type Ttl: Get<Self::Moment>;
         
-        /// Period of check for expired proposals
+        /// Max pending proposal's time-to-live
+        #[pallet::constant]
+        type MaxTtl: Get<Self::Moment>;
+        
+        /// Duration of the expiry and schedule queue slot.
+        /// Proposals that expire (or are executed) within the same slot are stored
+        /// under the same queue key
+        #[pallet::constant]
+        type ExpirySlot: Get<Self::Moment>;
+        
+        /// Max number of proposals expired per block.
+        /// Visiting of the expiry queue slot counts as one as well
+        #[pallet::constant]
+        type MaxExpiredPerBlock: Get<u32>;
+        
+        /// Authorities of DAOs that may be members of proposals
+        type DaoAuthorities: DaoAuthorityT<Self::AccountId>;
+        
+        /// Max number of resolved proposals kept in the archive.
+        /// Zero disables the archive
+        #[pallet::constant]
+        type MaxArchivedProposals: Get<u32>;
+        
+        /// Max number of scheduled proposals executed per block.
+        /// Visiting of the schedule queue slot counts as one as well
+        #[pallet::constant]
+        type MaxScheduledPerBlock: Get<u32>;
+        
+        /// Max total weight of scheduled proposal batches executed per block.
+        /// The first due proposal is executed anyway so a heavy batch can't stall the queue
+        #[pallet::constant]
+        type MaxScheduledWeight: Get<Weight>;
+        
+        /// Max depth of nested proposals. Depth of the proposal batch itself is one
         #[pallet::constant]
-        type ExpirePeriod: Get<Self::BlockNumber>;
+        type MaxNestingDepth: Get<u32>;
+        
+        /// Max number of items in the batch of each nesting level
+        #[pallet::constant]
+        type MaxBatchItems: Get<u32>;
+        
+        /// Max number of items in the batch including items of nested proposals
+        #[pallet::constant]
+        type MaxTotalBatchItems: Get<u32>;
+        
+        /// Max size of the encoded batch including nested proposals
+        #[pallet::constant]
+        type MaxBatchEncodedSize: Get<u32>;
     }
     
     #[doc(hidden)]
@@ -102,66 +176,14 @@ pub mod pallet {
     #[doc(hidden)]
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn offchain_worker(n: T::BlockNumber) {
-            RuntimeLogger::init();
-            if !sp_io::offchain::is_validator() {
-                debug!("{}", "not a validator");
-                return
-            }
-            if n % T::ExpirePeriod::get() != Zero::zero() {
-                debug!("skip expire proposals at {:?}", n);
-                return
-            }
-            debug!("expire proposals at {:?}", n);
+        fn on_initialize(_n: T::BlockNumber) -> Weight {
             let now = pallet_timestamp::Module::<T>::get();
-            for (id, obj) in ProposalRepository::<T>::iter() {
-                if !obj.expired(now) {
-                    continue
-                }
-                let call = Call::expire(id);
-                let submit = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
-                if submit.is_err() {
-                    debug!("{}", "error on submit unsigned transaction");
-                } else {
-                    debug!("{}", "submit unsigned transaction");
-                }
-            }
+            Self::execute_scheduled(now)
+                .saturating_add(Self::expire_pending(now))
         }
-    }
-    
-    #[pallet::validate_unsigned]
-    impl<T: Config> ValidateUnsigned for Pallet<T> {
-        type Call = Call<T>;
-
-        /// Validate unsigned call to this module.
-        ///
-        /// By default unsigned transactions are disallowed, but implementing the validator
-        /// here we make sure that some particular calls (the ones produced by offchain worker)
-        /// are being whitelisted and marked as valid.
-        fn validate_unsigned(
-            source: TransactionSource,
-            call: &Self::Call,
-        )
-            -> TransactionValidity
-        {
-            // Firstly let's check that we get the local transaction.
-            if !matches!(source, TransactionSource::Local | TransactionSource::InBlock) {
-                return InvalidTransaction::Custom(super::NON_LOCAL).into()
-            }
-            // Check that we call the right function.
-            if let Call::expire(ref proposal_id) = call {
-                let proposal = ProposalRepository::<T>::get(proposal_id);
-                let now = pallet_timestamp::Module::<T>::get();
-                if proposal.is_none() { return InvalidTransaction::Stale.into() }
-                else if !proposal.as_ref().unwrap().expired(now) { return InvalidTransaction::Future.into() }
-                ValidTransaction::with_tag_prefix("DeipProposalOffchainWorker")
-                    .propagate(false)
-                    .longevity(5)
-                    .and_provides((*proposal_id, proposal.unwrap().created_at))
-                    .build()
-            } else {
-                InvalidTransaction::Call.into()
-            }
+        
+        fn on_runtime_upgrade() -> Weight {
+            crate::migration::migrate_to_v2::<T>()
         }
     }
     
@@ -180,7 +202,29 @@ pub mod pallet {
         /// Self-referential proposal
         SelfReferential,
         /// Not expired yet
-        NotExpired
+        NotExpired,
+        /// Approval policy can't be met by proposal members
+        InvalidApprovalPolicy,
+        /// Current origin is not an author of Proposal
+        NotAnAuthor,
+        /// Proposal is not scheduled for execution or execution time is not reached yet
+        NotScheduled,
+        /// Proposal's time-to-live is zero or exceeds the max allowed one
+        InvalidTtl,
+        /// Account is not a DAO
+        NotADao,
+        /// Current origin is not a signatory of DAO authority
+        NotADaoSignatory,
+        /// Nested proposal decides on one of it's ancestor proposals
+        CyclicReference,
+        /// Batch of some nesting level exceeds `MaxBatchItems`
+        TooManyBatchItems,
+        /// Batch including nested proposals exceeds `MaxTotalBatchItems`
+        TooManyTotalBatchItems,
+        /// Encoded batch exceeds `MaxBatchEncodedSize`
+        BatchTooLarge,
+        /// Account can't be resolved (e.g. DAO is dissolved)
+        DeipAccountIdInvalid,
     }
     
     #[pallet::event]
@@ -198,20 +242,66 @@ pub mod pallet {
             member: T::AccountId,
             proposal_id: ProposalId
         },
+        /// Emits when proposal rejected by it's member
+        /// but still may be approved by the rest of members
+        Rejected {
+            member: T::AccountId,
+            proposal_id: ProposalId
+        },
+        /// Emits when signatory of DAO authority makes decision on behalf of DAO member.
+        /// Decision of DAO is made when DAO authority threshold is met
+        DaoMemberDecided {
+            member: T::AccountId,
+            dao: T::AccountId,
+            proposal_id: ProposalId,
+            decision: ProposalMemberDecision
+        },
         /// Emits when member revokes his approval
         RevokedApproval {
             member: T::AccountId,
             proposal_id: ProposalId
         },
-        /// Emits when proposal resolved (rejected / done / failed)
+        /// Emits when proposal resolved (rejected / done / partially done / failed)
         Resolved {
             member: T::AccountId,
             proposal_id: ProposalId,
-            state: ProposalState
+            state: ProposalState,
+            tally: ApprovalTally,
+            batch_results: BatchResults
+        },
+        /// Emits when approved proposal scheduled for execution
+        Scheduled {
+            member: T::AccountId,
+            proposal_id: ProposalId,
+            execute_after: T::Moment,
+            tally: ApprovalTally
+        },
+        /// Emits when scheduled proposal executed (done / partially done / failed)
+        Executed {
+            proposal_id: ProposalId,
+            state: ProposalState,
+            batch_results: BatchResults
+        },
+        /// Emits when scheduled proposal can't be executed and is removed from the schedule queue
+        ScheduledExecutionFailed {
+            proposal_id: ProposalId,
+            error: DispatchError
         },
         /// Expired
         Expired {
             proposal_id: ProposalId
+        },
+        /// Emits when proposal cancelled by it's author
+        Cancelled {
+            author: T::AccountId,
+            proposal_id: ProposalId
+        },
+        /// Emits when batch of proposal replaced by it's author.
+        /// Decisions of all members are reset
+        Amended {
+            author: T::AccountId,
+            batch: ProposalBatch<T>,
+            proposal_id: ProposalId
         }
     }
     
@@ -222,16 +312,22 @@ pub mod pallet {
     
     #[pallet::genesis_build]
     impl<T: Config> GenesisBuild<T> for GenesisConfig {
-        fn build(&self) {}
+        fn build(&self) {
+            StorageVersion::<T>::put(Releases::V2);
+        }
     }
     
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(10)]
+        #[pallet::weight(crate::batch_weight::propose::<T>(batch))]
         pub fn propose(
             origin: OriginFor<T>,
             batch: Vec<InputProposalBatchItem<T>>,
-            external_id: Option<ProposalId>
+            external_id: Option<ProposalId>,
+            approval_policy: InputApprovalPolicy<T>,
+            execute_after: Option<T::Moment>,
+            ttl: Option<T::Moment>,
+            execution_mode: ExecutionMode,
         )
             -> DispatchResultWithPostInfo
         {
@@ -239,12 +335,21 @@ pub mod pallet {
             
             // frame_support::debug::RuntimeLogger::init();
 
-            crate::entrypoint::propose::<T>(author, batch, external_id)?;
+            let actual_weight = crate::batch_weight::propose_base::<T>(batch.len());
+            crate::entrypoint::propose::<T>(
+                author,
+                batch,
+                external_id,
+                approval_policy,
+                execute_after,
+                ttl,
+                execution_mode,
+            )?;
             
-            Ok(Some(0).into())
+            Ok(Some(actual_weight).into())
         }
 
-        #[pallet::weight(10)]
+        #[pallet::weight(crate::batch_weight::decide::<T>(proposal_id))]
         pub fn decide(
             origin: OriginFor<T>,
             proposal_id: ProposalId,
@@ -254,6 +359,7 @@ pub mod pallet {
         {
             let member = ensure_signed(origin)?;
             let proposal = ProposalRepository::<T>::get(&proposal_id).ok_or(Error::<T>::NotFound)?;
+            let base_weight = crate::batch_weight::decide_base::<T>(proposal.batch.len());
             let maybe_batch_exec_result: Option<DispatchResultWithPostInfo> =
                 StorageWrite::<T>::new()
                     .commit(|ops| {
@@ -262,12 +368,44 @@ pub mod pallet {
                             decision,
                             Self::exec_batch,
                             ops,
+                            pallet_timestamp::Module::<T>::get(),
                         )
                     })?;
-            if let Some(batch_exec_result) = maybe_batch_exec_result {
-                let _batch_exec_ok = batch_exec_result?;
-            }
-            Ok(Some(0).into())
+            Self::with_base_weight(maybe_batch_exec_result, base_weight)
+        }
+        
+        #[pallet::weight(crate::batch_weight::cancel::<T>(proposal_id))]
+        pub fn cancel(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let author = ensure_signed(origin)?;
+            let proposal = ProposalRepository::<T>::get(proposal_id).ok_or(Error::<T>::NotFound)?;
+            let actual_weight = crate::batch_weight::cancel_base::<T>(proposal.decisions.len());
+            StorageWrite::<T>::new().commit(|ops| {
+                proposal.cancel(&author, ops)
+            })?;
+            Ok(Some(actual_weight).into())
+        }
+
+        #[pallet::weight(crate::batch_weight::propose::<T>(batch))]
+        pub fn amend(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+            batch: Vec<InputProposalBatchItem<T>>,
+            approval_policy: Option<InputApprovalPolicy<T>>,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let author = ensure_signed(origin)?;
+            let proposal = ProposalRepository::<T>::get(proposal_id).ok_or(Error::<T>::NotFound)?;
+            let actual_weight = crate::batch_weight::propose_base::<T>(batch.len());
+            StorageWrite::<T>::new().commit(|ops| {
+                proposal.amend(&author, batch, approval_policy, ops)
+            })?;
+            Ok(Some(actual_weight).into())
         }
         
         #[pallet::weight(10_000)]
@@ -277,7 +415,7 @@ pub mod pallet {
         )
             -> DispatchResultWithPostInfo
         {
-            ensure_none(origin)?;
+            ensure_signed(origin)?;
             
             let proposal = ProposalRepository::<T>::get(proposal_id)
                 .ok_or_else(|| Error::<T>::NotFound)?;
@@ -289,23 +427,239 @@ pub mod pallet {
             
             Ok(Some(0).into())
         }
+        
+        #[pallet::weight(crate::batch_weight::decide::<T>(proposal_id))]
+        pub fn decide_as_dao(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+            dao: T::DeipAccountId,
+            decision: ProposalMemberDecision,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let signatory = ensure_signed(origin)?;
+            let dao_key: T::AccountId = crate::proposal::deip_account::<T>(dao)?;
+            let proposal = ProposalRepository::<T>::get(proposal_id).ok_or(Error::<T>::NotFound)?;
+            let base_weight = crate::batch_weight::decide_base::<T>(proposal.batch.len());
+            let maybe_batch_exec_result: Option<DispatchResultWithPostInfo> =
+                StorageWrite::<T>::new()
+                    .commit(|ops| {
+                        proposal.decide_as_dao(
+                            &signatory,
+                            &dao_key,
+                            decision,
+                            Self::exec_batch,
+                            ops,
+                            pallet_timestamp::Module::<T>::get(),
+                        )
+                    })?;
+            Self::with_base_weight(maybe_batch_exec_result, base_weight)
+        }
     }
 
     impl<T: Config> Pallet<T> {
-        /// Execute batch as atomic transaction
-        #[frame_support::transactional]
-        fn exec_batch(batch: ProposalBatch<T>) -> DispatchResultWithPostInfo
+        /// Make post dispatch info of decision: base weight plus weight of executed batch (if any).
+        /// Batch execution error is propagated
+        fn with_base_weight(
+            maybe_batch_exec_result: Option<DispatchResultWithPostInfo>,
+            base_weight: Weight,
+        )
+            -> DispatchResultWithPostInfo
+        {
+            let batch_exec_result = match maybe_batch_exec_result {
+                Some(x) => x,
+                None => return Ok(Some(base_weight).into()),
+            };
+            let with_base = |x: PostDispatchInfo| -> PostDispatchInfo {
+                Some(base_weight.saturating_add(x.actual_weight.unwrap_or(0))).into()
+            };
+            batch_exec_result
+                .map(with_base)
+                .map_err(|e| DispatchErrorWithPostInfo {
+                    post_info: with_base(e.post_info),
+                    error: e.error,
+                })
+        }
+        
+        /// Execute batch in the given mode.
+        /// Each item is executed in it's own transaction, the whole batch is rolled back on error.
+        /// Actual weight of executed calls is reported in post dispatch info
+        fn exec_batch(batch: ProposalBatch<T>, mode: ExecutionMode) -> BatchExecOutcome {
+            Self::exec_batch_observed(batch, mode, |_, _| {})
+        }
+        
+        /// Execute batch like [`Self::exec_batch`] does calling `observe` after every executed item
+        /// with it's dispatch result and actual weight
+        pub(crate) fn exec_batch_observed(
+            batch: ProposalBatch<T>,
+            mode: ExecutionMode,
+            mut observe: impl FnMut(&DispatchResultWithPostInfo, Weight),
+        )
+            -> BatchExecOutcome
         {
             frame_support::debug::RuntimeLogger::init();
-            for x in batch {
-                let ProposalBatchItemOf::<T> { account, call } = x;
-                frame_support::debug::debug!("{:?}; {:?}", &account, &call);
-                call.dispatch(RawOrigin::Signed(account).into())?;
+            with_transaction(|| {
+                let mut actual_weight: Weight = 0;
+                let mut batch_results = Vec::with_capacity(batch.len());
+                let mut first_error = None;
+                for x in batch {
+                    if first_error.is_some() && mode == ExecutionMode::Atomic {
+                        batch_results.push(BatchItemResult::Skipped);
+                        continue
+                    }
+                    let ProposalBatchItemOf::<T> { account, call } = x;
+                    frame_support::debug::debug!("{:?}; {:?}", &account, &call);
+                    let info = call.get_dispatch_info();
+                    let result = with_transaction(|| {
+                        let result = call.dispatch(RawOrigin::Signed(account).into());
+                        if result.is_ok() {
+                            TransactionOutcome::Commit(result)
+                        } else {
+                            TransactionOutcome::Rollback(result)
+                        }
+                    });
+                    let weight = extract_actual_weight(&result, &info);
+                    actual_weight = actual_weight.saturating_add(weight);
+                    observe(&result, weight);
+                    match result {
+                        Ok(_) => batch_results.push(BatchItemResult::Done),
+                        Err(e) => {
+                            batch_results.push(BatchItemResult::Failed(e.error));
+                            first_error.get_or_insert(e.error);
+                        },
+                    }
+                }
+                let post_info: PostDispatchInfo = Some(actual_weight).into();
+                match first_error {
+                    Some(error) if mode != ExecutionMode::BestEffort => {
+                        TransactionOutcome::Rollback(BatchExecOutcome {
+                            result: Err(DispatchErrorWithPostInfo { post_info, error }),
+                            batch_results,
+                        })
+                    },
+                    _ => TransactionOutcome::Commit(BatchExecOutcome {
+                        result: Ok(post_info),
+                        batch_results,
+                    }),
+                }
+            })
+        }
+        
+        /// Execute due proposals from the elapsed slots of the schedule queue.
+        /// Skipped until the timestamp is set like expiration is.
+        /// Cursor stays at the current slot since proposals may still be scheduled into it
+        fn execute_scheduled(now: T::Moment) -> Weight {
+            if now.is_zero() {
+                return 0
             }
-            Ok(Some(0).into())
+            let now_slot = crate::proposal::schedule_slot::<T>(now);
+            let mut cursor = NextScheduledSlot::<T>::get().unwrap_or(now_slot);
+            let mut budget = T::MaxScheduledPerBlock::get();
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            let max_weight = T::MaxScheduledWeight::get();
+            let mut batches_weight: Weight = 0;
+            while cursor <= now_slot && budget > 0 {
+                budget -= 1;
+                let mut queue = ScheduledProposals::<T>::get(cursor);
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                let mut processed = 0;
+                for (execute_after, proposal_id) in queue.iter() {
+                    if budget == 0 || *execute_after > now {
+                        break
+                    }
+                    weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                    let proposal = match ProposalRepository::<T>::get(proposal_id) {
+                        Some(proposal) => proposal,
+                        None => {
+                            budget -= 1;
+                            processed += 1;
+                            continue
+                        },
+                    };
+                    let batch_weight = crate::batch_weight::batch_exec_weight::<T>(&proposal.batch);
+                    if batches_weight > 0 && batches_weight.saturating_add(batch_weight) > max_weight {
+                        break
+                    }
+                    budget -= 1;
+                    processed += 1;
+                    let executed = StorageWrite::<T>::new().commit(|ops| {
+                        proposal.execute(Self::exec_batch, ops, now)
+                    });
+                    let actual_weight = match executed {
+                        Ok(Ok(x)) => x.actual_weight,
+                        Ok(Err(e)) => e.post_info.actual_weight,
+                        Err(e) => {
+                            Self::deposit_event(Event::<T>::ScheduledExecutionFailed {
+                                proposal_id: *proposal_id,
+                                error: e.into(),
+                            });
+                            Some(0)
+                        },
+                    };
+                    batches_weight = batches_weight.saturating_add(batch_weight);
+                    weight = weight.saturating_add(actual_weight.unwrap_or(batch_weight));
+                }
+                queue.drain(..processed);
+                if !queue.is_empty() {
+                    ScheduledProposals::<T>::insert(cursor, queue);
+                    break
+                }
+                ScheduledProposals::<T>::remove(cursor);
+                if cursor == now_slot {
+                    break
+                }
+                cursor = cursor.saturating_add(One::one());
+            }
+            NextScheduledSlot::<T>::put(cursor);
+            weight
+        }
+        
+        /// Expire pending proposals from the elapsed slots of the expiry queue.
+        /// Skipped until the timestamp is set so the cursor doesn't start from the zero slot
+        fn expire_pending(now: T::Moment) -> Weight {
+            if now.is_zero() {
+                return 0
+            }
+            let now_slot = now / crate::proposal::expiry_slot_duration::<T>();
+            let mut cursor = NextExpirySlot::<T>::get().unwrap_or(now_slot);
+            let mut budget = T::MaxExpiredPerBlock::get();
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            while cursor <= now_slot && budget > 0 {
+                budget -= 1;
+                let mut queue = ExpiryQueue::<T>::get(cursor);
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                let due = queue.len().min(budget as usize);
+                budget -= due as u32;
+                for proposal_id in queue.drain(..due) {
+                    weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                    let proposal = match ProposalRepository::<T>::get(proposal_id) {
+                        Some(proposal) if proposal.expired(now) => proposal,
+                        _ => continue,
+                    };
+                    weight = weight.saturating_add(T::DbWeight::get().writes(4));
+                    let _ = StorageWrite::<T>::new().commit(|ops| {
+                        proposal.expire(now, ops)
+                    });
+                }
+                if !queue.is_empty() {
+                    ExpiryQueue::<T>::insert(cursor, queue);
+                    break
+                }
+                ExpiryQueue::<T>::remove(cursor);
+                cursor = cursor.saturating_add(One::one());
+            }
+            NextExpirySlot::<T>::put(cursor);
+            weight
         }
     }
     
+    /// Storage layout version used to run migrations once
+    #[pallet::storage]
+    pub(super) type StorageVersion<T: Config> = StorageValue<_,
+        Releases,
+        ValueQuery
+    >;
+
     #[pallet::storage]
     pub(super) type ProposalRepository<T: Config> = StorageMap<_,
         Blake2_128Concat,
@@ -324,6 +678,94 @@ pub mod pallet {
         OptionQuery
     >;
 
+    /// Queue of scheduled proposals grouped by execution slot and ordered by execution time
+    #[pallet::storage]
+    pub(super) type ScheduledProposals<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        T::Moment,
+        Vec<(T::Moment, ProposalId)>,
+        ValueQuery
+    >;
+
+    /// Next schedule slot to be processed
+    #[pallet::storage]
+    pub(super) type NextScheduledSlot<T: Config> = StorageValue<_,
+        T::Moment,
+        OptionQuery
+    >;
+
+    /// Queue of pending proposals grouped by expiry slot
+    #[pallet::storage]
+    pub(super) type ExpiryQueue<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        T::Moment,
+        Vec<ProposalId>,
+        ValueQuery
+    >;
+
+    /// Next expiry slot to be processed
+    #[pallet::storage]
+    pub(super) type NextExpirySlot<T: Config> = StorageValue<_,
+        T::Moment,
+        OptionQuery
+    >;
+
+    /// Decisions of DAO signatories accumulated until DAO authority threshold is met
+    #[pallet::storage]
+    pub(super) type DaoDecisions<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        ProposalId,
+        Blake2_128Concat,
+        T::AccountId,
+        ProposalDecisions<T>,
+        ValueQuery
+    >;
+
+    /// Archive of resolved proposals
+    #[pallet::storage]
+    pub(super) type ProposalArchive<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        ProposalId,
+        ArchivedProposal<T>,
+        OptionQuery
+    >;
+
+    #[pallet::storage]
+    pub(super) type ArchivedProposalIdByMember<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        ProposalId,
+        (),
+        OptionQuery
+    >;
+
+    #[pallet::storage]
+    pub(super) type ArchivedProposalIdByAuthor<T: Config> = StorageDoubleMap<_,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        ProposalId,
+        (),
+        OptionQuery
+    >;
+
+    /// Archived proposals in order of resolution used for pruning
+    #[pallet::storage]
+    pub(super) type ArchiveQueue<T: Config> = StorageMap<_,
+        Blake2_128Concat,
+        u64,
+        (ProposalId, T::BlockNumber),
+        OptionQuery
+    >;
+
+    /// Head and tail positions of the archive queue
+    #[pallet::storage]
+    pub(super) type ArchiveQueueBounds<T: Config> = StorageValue<_,
+        (u64, u64),
+        ValueQuery
+    >;
+
     #[allow(type_alias_bounds)]
     pub type PendingProposalsMap<T: Config> = BTreeMap<ProposalId, T::AccountId>;
     
@@ -0,0 +1,86 @@
+//! Module contains storage migrations of the pallet
+
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::prelude::*;
+
+use frame_support::pallet_prelude::*;
+
+use crate::proposal::{
+    ProposalId, DeipProposal, ProposalBatch, ProposalDecisions, ProposalState,
+    ApprovalPolicy, ExecutionMode,
+};
+
+use super::{Config, StorageVersion, ProposalRepository, ExpiryQueue, NextExpirySlot};
+
+/// Storage layout versions
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, Default)]
+pub enum Releases {
+    /// Proposal without approval policy, time-lock, time-to-live and execution mode
+    #[default]
+    V1,
+    /// Proposal with approval policy, time-lock, time-to-live and execution mode
+    V2,
+}
+
+/// Proposal object of the [`Releases::V1`] storage layout
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq)]
+pub(crate) struct DeipProposalV1<T: Config> {
+    pub(crate) id: ProposalId,
+    pub(crate) batch: ProposalBatch<T>,
+    pub(crate) decisions: ProposalDecisions<T>,
+    pub(crate) state: ProposalState,
+    pub(crate) author: T::AccountId,
+    pub(crate) created_at: T::Moment,
+}
+
+impl<T: Config> From<DeipProposalV1<T>> for DeipProposal<T> {
+    fn from(x: DeipProposalV1<T>) -> Self {
+        let DeipProposalV1::<T> { id, batch, decisions, state, author, created_at } = x;
+        Self {
+            id,
+            batch,
+            decisions,
+            state,
+            author,
+            created_at,
+            approval_policy: ApprovalPolicy::default(),
+            execute_after: None,
+            ttl: T::Ttl::get(),
+            decided_at: BTreeMap::new(),
+            execution_mode: ExecutionMode::default(),
+            batch_results: Vec::new(),
+        }
+    }
+}
+
+/// Migrate stored proposals to the [`Releases::V2`] layout:
+/// default approval policy and execution mode, default time-to-live and no time-lock.
+/// Migrated proposals are put to the expiry queue and the queue cursor is moved back
+/// to the earliest slot so the already expired ones are removed as well
+pub(crate) fn migrate_to_v2<T: Config>() -> Weight {
+    if StorageVersion::<T>::get() != Releases::V1 {
+        return T::DbWeight::get().reads(1)
+    }
+    let mut migrated: Weight = 0;
+    let mut first_slot: Option<T::Moment> = None;
+    ProposalRepository::<T>::translate::<DeipProposalV1<T>, _>(|_, old| {
+        let proposal: DeipProposal<T> = old.into();
+        let slot = proposal.expiry_slot();
+        ExpiryQueue::<T>::append(slot, proposal.id);
+        first_slot = Some(first_slot.map_or(slot, |x| x.min(slot)));
+        migrated += 1;
+        Some(proposal)
+    });
+    if let Some(slot) = first_slot {
+        NextExpirySlot::<T>::mutate(|cursor| {
+            if !matches!(cursor, Some(x) if *x <= slot) {
+                *cursor = Some(slot);
+            }
+        });
+    }
+    StorageVersion::<T>::put(Releases::V2);
+    T::DbWeight::get().reads_writes(
+        migrated.saturating_add(2),
+        migrated.saturating_mul(2).saturating_add(2),
+    )
+}
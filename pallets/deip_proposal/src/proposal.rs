@@ -1,12 +1,16 @@
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::prelude::*;
+use sp_std::convert::TryInto;
+
+use sp_runtime::Percent;
+use sp_runtime::traits::{Saturating, Zero, One};
 
 use frame_support::pallet_prelude::*;
 use frame_support::Hashable;
 
 use crate::storage::{StorageOpsT, StorageOps};
 
-use super::{Config, Event, Error, ProposalRepository};
+use super::{Config, Event, Error, ProposalRepository, DaoDecisions};
 
 #[cfg(feature = "std")]
 use serde::{Serialize, Deserialize};
@@ -35,6 +39,40 @@ pub type InputProposalBatchItem<T: Config> = BatchItem<
     <T as Config>::Call
 >;
 
+#[allow(type_alias_bounds)]
+pub type ProposalDecisions<T: Config> = BTreeMap<<T as frame_system::Config>::AccountId, ProposalMemberDecision>;
+
+#[allow(type_alias_bounds)]
+pub type ApprovalPolicyOf<T: Config> = ApprovalPolicy<<T as frame_system::Config>::AccountId>;
+
+#[allow(type_alias_bounds)]
+pub type InputApprovalPolicy<T: Config> = ApprovalPolicy<T::DeipAccountId>;
+
+/// Results of the batch items execution in the order of the batch
+pub type BatchResults = Vec<BatchItemResult>;
+
+/// Resolve account of the batch item or approval policy
+pub(crate) fn deip_account<T: Config>(account: T::DeipAccountId) -> Result<T::AccountId, Error<T>> {
+    account.try_into().map_err(|_| Error::<T>::DeipAccountIdInvalid)
+}
+
+/// DAO authority: signatories and number of them required to make decision on behalf of DAO
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DaoAuthority<AccountId> {
+    pub signatories: Vec<AccountId>,
+    pub threshold: u16,
+}
+
+/// Provides authority of the DAO by it's key
+pub trait DaoAuthorityT<AccountId> {
+    /// `None` if account is not a DAO key
+    fn authority(dao_key: &AccountId) -> Option<DaoAuthority<AccountId>>;
+}
+
+impl<AccountId> DaoAuthorityT<AccountId> for () {
+    fn authority(_dao_key: &AccountId) -> Option<DaoAuthority<AccountId>> { None }
+}
+
 /// Batch item generic container
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -51,12 +89,213 @@ pub struct DeipProposal<T: Config> {
     /// Batch-transaction items
     pub(super) batch: ProposalBatch<T>,
     /// Member decisions mapping
-    pub(super) decisions: BTreeMap<T::AccountId, ProposalMemberDecision>,
+    pub(super) decisions: ProposalDecisions<T>,
     /// Proposal state
     pub(super) state: ProposalState,
     /// Proposal author
     pub(super) author: T::AccountId,
-    pub(super) created_at: T::Moment
+    pub(super) created_at: T::Moment,
+    /// Policy that determines when proposal is approved
+    pub(super) approval_policy: ApprovalPolicyOf<T>,
+    /// Approved proposal will not be executed before this moment
+    pub(super) execute_after: Option<T::Moment>,
+    /// Pending proposal's time-to-live since creation
+    pub(super) ttl: T::Moment,
+    /// Moment of the last decision of each member
+    pub(super) decided_at: BTreeMap<T::AccountId, T::Moment>,
+    /// How the batch is executed
+    pub(super) execution_mode: ExecutionMode,
+    /// Results of the batch items execution (empty until executed)
+    pub(super) batch_results: BatchResults,
+}
+
+/// Resolved proposal kept in the archive
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq)]
+pub struct ArchivedProposal<T: Config> {
+    /// Proposal ID
+    pub(super) id: ProposalId,
+    /// Batch-transaction items
+    pub(super) batch: ProposalBatch<T>,
+    /// Final member decisions with moments they were made at
+    pub(super) decisions: BTreeMap<T::AccountId, ArchivedDecision<T::Moment>>,
+    /// Final proposal state
+    pub(super) state: ProposalState,
+    /// Proposal author
+    pub(super) author: T::AccountId,
+    pub(super) created_at: T::Moment,
+    /// Block the proposal was resolved at
+    pub(super) resolved_at: T::BlockNumber,
+    /// Results of the batch items execution (empty if not executed)
+    pub(super) batch_results: BatchResults,
+}
+
+/// Member decision kept in the archive
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ArchivedDecision<Moment> {
+    pub decision: ProposalMemberDecision,
+    /// `None` if member has never decided
+    pub decided_at: Option<Moment>,
+}
+
+impl<T: Config> ArchivedProposal<T> {
+    /// Make archive record of the resolved proposal
+    pub(crate) fn new(proposal: DeipProposal<T>, resolved_at: T::BlockNumber) -> Self {
+        let DeipProposal::<T> {
+            id,
+            batch,
+            decisions,
+            state,
+            author,
+            created_at,
+            decided_at,
+            batch_results,
+            ..
+        } = proposal;
+        let decisions = decisions.into_iter()
+            .map(|(member, decision)| {
+                let decided_at = decided_at.get(&member).copied();
+                (member, ArchivedDecision { decision, decided_at })
+            })
+            .collect();
+        Self { id, batch, decisions, state, author, created_at, resolved_at, batch_results }
+    }
+}
+
+/// Duration of the expiry and schedule queue slot (never zero)
+pub(crate) fn expiry_slot_duration<T: Config>() -> T::Moment {
+    T::ExpirySlot::get().max(One::one())
+}
+
+/// Slot of the schedule queue for the given execution time.
+/// Unlike the expiry slot it becomes due when it's start moment is reached
+pub(crate) fn schedule_slot<T: Config>(execute_after: T::Moment) -> T::Moment {
+    execute_after / expiry_slot_duration::<T>()
+}
+
+/// Proposal approval policy.
+/// Determines how many member approvals are enough to execute the batch
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ApprovalPolicy<Account> {
+    /// Every member must approve
+    #[default]
+    Unanimous,
+    /// At least `n` members must approve
+    Threshold(u16),
+    /// At least given percent of members must approve (rounding up)
+    Percentage(Percent),
+    /// Sum of weights of approved members must reach `threshold`.
+    /// Members not listed in `weights` are optional signers with zero weight
+    Weighted {
+        weights: Vec<(Account, u32)>,
+        threshold: u32,
+    },
+}
+
+/// Current progress of proposal approval
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ApprovalTally {
+    /// Weight of approved members
+    pub approved: u32,
+    /// Weight of members that not made decision yet
+    pub pending: u32,
+    /// Weight required to approve proposal
+    pub required: u32,
+}
+
+impl ApprovalTally {
+    /// Approval policy is met
+    pub fn approved(&self) -> bool {
+        self.approved >= self.required
+    }
+    
+    /// Approval policy can't be met anymore
+    pub fn unreachable(&self) -> bool {
+        self.approved.saturating_add(self.pending) < self.required
+    }
+}
+
+impl<Account> ApprovalPolicy<Account> {
+    /// Convert accounts of the policy
+    pub fn try_map_account<A: Ord, E>(self, f: impl Fn(Account) -> Result<A, E>) -> Result<ApprovalPolicy<A>, E> {
+        Ok(match self {
+            Self::Unanimous => ApprovalPolicy::Unanimous,
+            Self::Threshold(n) => ApprovalPolicy::Threshold(n),
+            Self::Percentage(p) => ApprovalPolicy::Percentage(p),
+            Self::Weighted { weights, threshold } => {
+                let mut weights = weights
+                    .into_iter()
+                    .map(|(a, w)| Ok((f(a)?, w)))
+                    .collect::<Result<Vec<_>, E>>()?;
+                weights.sort_by(|x, y| x.0.cmp(&y.0));
+                weights.dedup_by(|x, y| x.0 == y.0);
+                ApprovalPolicy::Weighted { weights, threshold }
+            },
+        })
+    }
+}
+
+impl<Account: Ord> ApprovalPolicy<Account> {
+    fn weight_of(&self, member: &Account) -> u32 {
+        match self {
+            Self::Weighted { weights, .. } => {
+                weights.binary_search_by(|x| x.0.cmp(member))
+                    .map(|pos| weights[pos].1)
+                    .unwrap_or(0)
+            },
+            _ => 1,
+        }
+    }
+    
+    fn required(&self, members: usize) -> u32 {
+        let members = members as u32;
+        match self {
+            Self::Unanimous => members,
+            Self::Threshold(n) => *n as u32,
+            Self::Percentage(p) => p.mul_ceil(members).max(1),
+            Self::Weighted { threshold, .. } => *threshold,
+        }
+    }
+    
+    /// Check that policy can be met by the given set of members
+    pub fn valid(&self, members: &BTreeMap<Account, ProposalMemberDecision>) -> bool {
+        match self {
+            Self::Unanimous => true,
+            Self::Threshold(n) => *n > 0 && *n as usize <= members.len(),
+            Self::Percentage(p) => !p.is_zero(),
+            Self::Weighted { weights, threshold } => {
+                let total = weights.iter().try_fold(0u32, |acc, (a, w)| {
+                    if !members.contains_key(a) { return None }
+                    acc.checked_add(*w)
+                });
+                *threshold > 0 && matches!(total, Some(total) if total >= *threshold)
+            },
+        }
+    }
+    
+    /// Calculate approval progress
+    pub fn tally(&self, decisions: &BTreeMap<Account, ProposalMemberDecision>) -> ApprovalTally {
+        let mut tally = ApprovalTally {
+            approved: 0,
+            pending: 0,
+            required: self.required(decisions.len()),
+        };
+        for (member, decision) in decisions {
+            let weight = self.weight_of(member);
+            match decision {
+                ProposalMemberDecision::Approve => {
+                    tally.approved = tally.approved.saturating_add(weight);
+                },
+                ProposalMemberDecision::Pending => {
+                    tally.pending = tally.pending.saturating_add(weight);
+                },
+                ProposalMemberDecision::Reject => {},
+            }
+        }
+        tally
+    }
 }
 
 /// Proposal state
@@ -70,7 +309,54 @@ pub enum ProposalState {
     /// Batch transaction executed successfully
     Done,
     /// Batch transaction execution failed
-    Failed(sp_runtime::DispatchError)
+    Failed(sp_runtime::DispatchError),
+    /// Approved proposal waiting for execution time
+    Scheduled,
+    /// Proposal was not resolved during it's time-to-live
+    Expired,
+    /// Batch transaction executed in the best-effort mode and some of it's items failed
+    PartiallyDone,
+}
+
+impl ProposalState {
+    /// Proposal is resolved and will be removed from the repository
+    pub fn resolved(&self) -> bool {
+        !matches!(self, Self::Pending | Self::Scheduled)
+    }
+}
+
+/// Batch execution mode
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ExecutionMode {
+    /// Execution stops on the first failed item and changes of all items are rolled back
+    #[default]
+    Atomic,
+    /// Every item is executed, changes of the failed items only are rolled back
+    BestEffort,
+    /// Every item is executed, changes of all items are rolled back if any of them failed
+    AllOrNothing,
+}
+
+/// Result of the batch item execution
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BatchItemResult {
+    /// Item executed successfully
+    Done,
+    /// Item execution failed
+    Failed(sp_runtime::DispatchError),
+    /// Item was not executed since the previous one failed in the atomic mode
+    Skipped,
+}
+
+/// Outcome of the batch execution
+pub struct BatchExecOutcome {
+    /// Dispatch result of the whole batch.
+    /// Failed items of the best-effort batch don't make it an error
+    pub result: frame_support::dispatch::DispatchResultWithPostInfo,
+    /// Result of each batch item
+    pub batch_results: BatchResults,
 }
 
 /// A global extrinsic index, formed as the extrinsic index within a block, together with that
@@ -132,10 +418,15 @@ impl<T: Config> DeipProposal<T> {
     
     /// Create proposal object.
     /// Fail if input arguments violates proposal assertions (See [proposal_assertions](./module.proposal_assertions))
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         batch: InputProposalBatch<T>,
         author: T::AccountId,
         external_id: Option<ProposalId>,
+        approval_policy: InputApprovalPolicy<T>,
+        execute_after: Option<T::Moment>,
+        ttl: Option<T::Moment>,
+        execution_mode: ExecutionMode,
         storage_ops: &mut StorageOpsT<T>,
         created_at: T::Moment
     )
@@ -146,13 +437,66 @@ impl<T: Config> DeipProposal<T> {
             !ProposalRepository::<T>::contains_key(&id),
             Error::<T>::AlreadyExist
         );
-        match crate::batch_assertions::assert_proposal::<T, _>(&batch, &id, 2) {
-            Some(crate::batch_assertions::ProposalAssertions::DepthLimit) => {
+        let ttl = ttl.unwrap_or_else(T::Ttl::get);
+        ensure!(
+            !ttl.is_zero() && ttl <= T::MaxTtl::get(),
+            Error::<T>::InvalidTtl
+        );
+        let (batch, decisions) = Self::prepare_batch(batch, &id)?;
+        
+        let approval_policy = approval_policy.try_map_account(deip_account::<T>)?;
+        ensure!(approval_policy.valid(&decisions), Error::<T>::InvalidApprovalPolicy);
+        
+        let proposal = Self {
+            id,
+            batch,
+            decisions,
+            state: ProposalState::Pending,
+            author,
+            created_at,
+            approval_policy,
+            execute_after,
+            ttl,
+            decided_at: BTreeMap::new(),
+            execution_mode,
+            batch_results: Vec::new(),
+        };
+        storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Proposed {
+            author: proposal.author.clone(),
+            batch: proposal.batch.clone(),
+            proposal_id: proposal.id,
+        }));
+        storage_ops.push_op(StorageOps::CreateProposal(proposal));
+        Ok(())
+    }
+    
+    /// Check batch assertions and make members decisions mapping with pending decisions
+    pub(crate) fn prepare_batch(
+        batch: InputProposalBatch<T>,
+        id: &ProposalId,
+    )
+        -> Result<(ProposalBatch<T>, ProposalDecisions<T>), Error<T>>
+    {
+        use crate::batch_assertions::ProposalAssertions;
+        match crate::batch_assertions::assert_proposal::<T, _>(&batch, id) {
+            Some(ProposalAssertions::DepthLimit) => {
                 return Err(Error::<T>::ReachDepthLimit)
             },
-            Some(crate::batch_assertions::ProposalAssertions::SelfReference) => {
+            Some(ProposalAssertions::SelfReference) => {
                 return Err(Error::<T>::SelfReferential)
             },
+            Some(ProposalAssertions::CyclicReference) => {
+                return Err(Error::<T>::CyclicReference)
+            },
+            Some(ProposalAssertions::ItemsLimit) => {
+                return Err(Error::<T>::TooManyBatchItems)
+            },
+            Some(ProposalAssertions::TotalItemsLimit) => {
+                return Err(Error::<T>::TooManyTotalBatchItems)
+            },
+            Some(ProposalAssertions::SizeLimit) => {
+                return Err(Error::<T>::BatchTooLarge)
+            },
             None => (),
         }
         
@@ -160,12 +504,12 @@ impl<T: Config> DeipProposal<T> {
             .into_iter()
             .map(|x| {
                 let BatchItem { account, call } = x;
-                BatchItem {
-                    account: account.into(),
+                Ok(BatchItem {
+                    account: deip_account::<T>(account)?,
                     call
-                }
+                })
             })
-            .collect();
+            .collect::<Result<_, Error<T>>>()?;
         
         use sp_std::iter::FromIterator;
         let decisions = BTreeMap::from_iter(
@@ -174,44 +518,144 @@ impl<T: Config> DeipProposal<T> {
                 ProposalMemberDecision::Pending
             ))
         );
+        Ok((batch, decisions))
+    }
+    
+    /// Cancel pending proposal by it's author
+    pub fn cancel(
+        self,
+        who: &T::AccountId,
+        storage_ops: &mut StorageOpsT<T>
+    )
+        -> Result<(), Error<T>>
+    {
+        ensure!(&self.author == who, Error::<T>::NotAnAuthor);
+        ensure!(matches!(self.state, ProposalState::Pending), Error::<T>::AlreadyResolved);
+        storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Cancelled {
+            author: self.author.clone(),
+            proposal_id: self.id,
+        }));
+        storage_ops.push_op(StorageOps::DeleteProposal(self));
+        Ok(())
+    }
+    
+    /// Replace batch of pending proposal by it's author.
+    /// Decisions of all members are reset to `Pending`.
+    /// Approval policy is kept if new one is not provided
+    pub fn amend(
+        self,
+        who: &T::AccountId,
+        batch: InputProposalBatch<T>,
+        approval_policy: Option<InputApprovalPolicy<T>>,
+        storage_ops: &mut StorageOpsT<T>
+    )
+        -> Result<(), Error<T>>
+    {
+        ensure!(&self.author == who, Error::<T>::NotAnAuthor);
+        ensure!(matches!(self.state, ProposalState::Pending), Error::<T>::AlreadyResolved);
         
-        let proposal = Self {
-            id,
+        let (batch, decisions) = Self::prepare_batch(batch, &self.id)?;
+        
+        let approval_policy = approval_policy
+            .map(|x| x.try_map_account(deip_account::<T>))
+            .transpose()?
+            .unwrap_or_else(|| self.approval_policy.clone());
+        ensure!(approval_policy.valid(&decisions), Error::<T>::InvalidApprovalPolicy);
+        
+        let amended = Self {
             batch,
             decisions,
-            state: ProposalState::Pending,
-            author,
-            created_at
+            approval_policy,
+            decided_at: BTreeMap::new(),
+            ..self.clone()
         };
-        storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Proposed {
-            author: proposal.author.clone(),
-            batch: proposal.batch.clone(),
-            proposal_id: proposal.id,
+        storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Amended {
+            author: amended.author.clone(),
+            batch: amended.batch.clone(),
+            proposal_id: amended.id,
         }));
-        storage_ops.push_op(StorageOps::CreateProposal(proposal));
+        storage_ops.push_op(StorageOps::AmendProposal(self, amended));
         Ok(())
     }
     
-    /// 
+    /// Make decision on proposal being a signatory of the DAO that is a member of it.
+    /// Signatory decisions are accumulated until the DAO threshold is met (or became unreachable)
+    /// then the decision is made on behalf of the DAO
+    #[allow(clippy::too_many_arguments)]
+    pub fn decide_as_dao<BatchExec>(
+        self,
+        signatory: &T::AccountId,
+        dao_key: &T::AccountId,
+        decision: ProposalMemberDecision,
+        batch_exec: BatchExec,
+        storage_ops: &mut StorageOpsT<T>,
+        now: T::Moment,
+    )
+        -> Result<Option<frame_support::dispatch::DispatchResultWithPostInfo>, super::Error<T>>
+        where
+            BatchExec: FnOnce(ProposalBatch<T>, ExecutionMode) -> BatchExecOutcome
+    {
+        let dao_decision = self.decisions.get(dao_key).ok_or(Error::<T>::NotAMember)?;
+        ensure!(matches!(self.state, ProposalState::Pending), Error::<T>::AlreadyResolved);
+        ensure!(matches!(dao_decision, ProposalMemberDecision::Pending), Error::<T>::AlreadyResolved);
+        let authority = T::DaoAuthorities::authority(dao_key).ok_or(Error::<T>::NotADao)?;
+        ensure!(authority.signatories.contains(signatory), Error::<T>::NotADaoSignatory);
+        
+        let mut partial = DaoDecisions::<T>::get(self.id, dao_key);
+        partial.retain(|x, _| authority.signatories.contains(x));
+        match decision {
+            ProposalMemberDecision::Pending => { partial.remove(signatory); },
+            _ => { partial.insert(signatory.clone(), decision); },
+        }
+        storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoMemberDecided {
+            member: signatory.clone(),
+            dao: dao_key.clone(),
+            proposal_id: self.id,
+            decision,
+        }));
+        
+        let required = authority.threshold.max(1) as usize;
+        let count = |d: ProposalMemberDecision| partial.values().filter(|x| **x == d).count();
+        let dao_decision = if count(ProposalMemberDecision::Approve) >= required {
+            ProposalMemberDecision::Approve
+        } else if authority.signatories.len() - count(ProposalMemberDecision::Reject) < required {
+            ProposalMemberDecision::Reject
+        } else {
+            storage_ops.push_op(StorageOps::UpdateDaoDecisions(self.id, dao_key.clone(), partial));
+            return Ok(None)
+        };
+        storage_ops.push_op(StorageOps::UpdateDaoDecisions(self.id, dao_key.clone(), BTreeMap::new()));
+        self.decide(dao_key, dao_decision, batch_exec, storage_ops, now)
+    }
+    
+    /// Make decision on proposal being a member of it.
+    /// Approved proposal is executed immediately or scheduled if it has `execute_after` moment
+    /// that not reached yet.
     pub fn decide<BatchExec>(
         mut self,
         member: &T::AccountId,
         decision: ProposalMemberDecision,
         batch_exec: BatchExec,
-        storage_ops: &mut StorageOpsT<T>
+        storage_ops: &mut StorageOpsT<T>,
+        now: T::Moment
     )
-        -> Result<Option<BatchExec::Output>, super::Error<T>>
+        -> Result<Option<frame_support::dispatch::DispatchResultWithPostInfo>, super::Error<T>>
         where
-            BatchExec: FnOnce(ProposalBatch<T>) -> frame_support::dispatch::DispatchResultWithPostInfo
+            BatchExec: FnOnce(ProposalBatch<T>, ExecutionMode) -> BatchExecOutcome
     {
         let member_decision = self.decisions.get_mut(member).ok_or(Error::<T>::NotAMember)?;
         
         ensure!(matches!(self.state, ProposalState::Pending), Error::<T>::AlreadyResolved);
 
-        match member_decision.decide(decision) {
-            Err(_) => return Err(Error::<T>::AlreadyResolved),
-            Ok(None) => Ok(None),
-            Ok(Some(ProposalMemberDecision::Pending)) => {
+        let transition = member_decision.decide(decision)
+            .map_err(|_| Error::<T>::AlreadyResolved)?;
+        if transition.is_some() {
+            self.decided_at.insert(member.clone(), now);
+        }
+        
+        match transition {
+            None => Ok(None),
+            Some(ProposalMemberDecision::Pending) => {
                 storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::RevokedApproval {
                     member: member.clone(),
                     proposal_id: self.id
@@ -219,28 +663,48 @@ impl<T: Config> DeipProposal<T> {
                 storage_ops.push_op(StorageOps::UpdateProposal(self));
                 Ok(None)
             },
-            Ok(Some(ProposalMemberDecision::Reject)) => {
+            Some(ProposalMemberDecision::Reject) => {
+                let tally = self.approval_policy.tally(&self.decisions);
+                if !tally.unreachable() {
+                    storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Rejected {
+                        member: member.clone(),
+                        proposal_id: self.id
+                    }));
+                    storage_ops.push_op(StorageOps::UpdateProposal(self));
+                    return Ok(None)
+                }
                 self.state = ProposalState::Rejected;
                 storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Resolved {
                     member: member.clone(),
                     proposal_id: self.id,
-                    state: self.state
+                    state: self.state,
+                    tally,
+                    batch_results: Vec::new(),
                 }));
                 storage_ops.push_op(StorageOps::DeleteProposal(self));
                 Ok(None)
             },
-            Ok(Some(ProposalMemberDecision::Approve)) => {
-                if self.ready_to_exec() {
-                    let batch_exec_result = batch_exec(self.batch.clone());
-                    self.state = if let Err(ref err) = batch_exec_result { 
-                        ProposalState::Failed(err.error.clone())
-                    } else {
-                        ProposalState::Done
-                    };
+            Some(ProposalMemberDecision::Approve) => {
+                let tally = self.approval_policy.tally(&self.decisions);
+                if self.ready_to_exec(&tally) {
+                    if let Some(execute_after) = self.execute_after.filter(|x| *x > now) {
+                        self.state = ProposalState::Scheduled;
+                        storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Scheduled {
+                            member: member.clone(),
+                            proposal_id: self.id,
+                            execute_after,
+                            tally,
+                        }));
+                        storage_ops.push_op(StorageOps::ScheduleProposal(self));
+                        return Ok(None)
+                    }
+                    let batch_exec_result = self.executed(batch_exec(self.batch.clone(), self.execution_mode));
                     storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Resolved {
                         member: member.clone(),
                         proposal_id: self.id,
-                        state: self.state
+                        state: self.state,
+                        tally,
+                        batch_results: self.batch_results.clone(),
                     }));
                     storage_ops.push_op(StorageOps::DeleteProposal(self));
                     Ok(Some(batch_exec_result))
@@ -256,21 +720,72 @@ impl<T: Config> DeipProposal<T> {
         }
     }
     
-    fn ready_to_exec(&self) -> bool {
-        let approved = self.decisions.values()
-            .all(|x: &ProposalMemberDecision| {
-                matches!(x, ProposalMemberDecision::Approve)
-            });
-        approved && matches!(self.state, ProposalState::Pending)
+    fn ready_to_exec(&self, tally: &ApprovalTally) -> bool {
+        tally.approved() && matches!(self.state, ProposalState::Pending)
+    }
+    
+    /// Update state with the batch execution outcome
+    fn executed(&mut self, outcome: BatchExecOutcome) -> frame_support::dispatch::DispatchResultWithPostInfo {
+        let BatchExecOutcome { result, batch_results } = outcome;
+        let partially_done = batch_results.iter()
+            .any(|x| matches!(x, BatchItemResult::Failed(_)));
+        self.state = match result {
+            Err(ref err) => ProposalState::Failed(err.error),
+            Ok(_) if partially_done => ProposalState::PartiallyDone,
+            Ok(_) => ProposalState::Done,
+        };
+        self.batch_results = batch_results;
+        result
+    }
+    
+    /// Execute scheduled proposal
+    pub fn execute<BatchExec>(
+        mut self,
+        batch_exec: BatchExec,
+        storage_ops: &mut StorageOpsT<T>,
+        now: T::Moment
+    )
+        -> Result<frame_support::dispatch::DispatchResultWithPostInfo, Error<T>>
+        where
+            BatchExec: FnOnce(ProposalBatch<T>, ExecutionMode) -> BatchExecOutcome
+    {
+        ensure!(matches!(self.state, ProposalState::Scheduled), Error::<T>::NotScheduled);
+        ensure!(
+            matches!(self.execute_after, Some(x) if x <= now),
+            Error::<T>::NotScheduled
+        );
+        let batch_exec_result = self.executed(batch_exec(self.batch.clone(), self.execution_mode));
+        storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Executed {
+            proposal_id: self.id,
+            state: self.state,
+            batch_results: self.batch_results.clone(),
+        }));
+        storage_ops.push_op(StorageOps::DeleteProposal(self));
+        Ok(batch_exec_result)
+    }
+    
+    /// Moment when pending proposal expires
+    pub fn expire_at(&self) -> T::Moment {
+        self.created_at.saturating_add(self.ttl)
+    }
+    
+    /// Slot of the expiry queue that contains this proposal.
+    /// Slot becomes due when it's end moment is reached
+    pub(crate) fn expiry_slot(&self) -> T::Moment {
+        let duration = expiry_slot_duration::<T>();
+        let expire_at = self.expire_at();
+        let slot = expire_at / duration;
+        if (expire_at % duration).is_zero() { slot } else { slot.saturating_add(One::one()) }
     }
     
     pub fn expired(&self, now: T::Moment) -> bool {
-        (self.created_at + T::Ttl::get()) <= now 
+        self.expire_at() <= now
             && matches!(self.state, ProposalState::Pending)
     }
     
-    pub fn expire(self, now: T::Moment, storage_ops: &mut StorageOpsT<T>) -> Result<(), Error<T>>{
+    pub fn expire(mut self, now: T::Moment, storage_ops: &mut StorageOpsT<T>) -> Result<(), Error<T>>{
         ensure!(self.expired(now), Error::<T>::NotExpired);
+        self.state = ProposalState::Expired;
         storage_ops.push_op(StorageOps::DepositEvent(Event::<T>::Expired { proposal_id: self.id }));
         storage_ops.push_op(StorageOps::DeleteProposal(self));
         Ok(())
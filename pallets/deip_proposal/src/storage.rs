@@ -4,9 +4,20 @@ use sp_std::prelude::*;
 
 pub use pallet_deip_toolkit::storage_ops::*;
 
-use crate::proposal::DeipProposal;
+use frame_support::traits::Get;
 
-use super::{Config, Event, ProposalRepository, Pallet, PendingProposals, ProposalIdByAccountId};
+use crate::proposal::{DeipProposal, ArchivedProposal, ProposalId, ProposalDecisions};
+
+use super::{
+    Config, Event, ProposalRepository, Pallet, PendingProposals, ProposalIdByAccountId,
+    ScheduledProposals, NextScheduledSlot, ExpiryQueue,
+    ProposalArchive, ArchivedProposalIdByMember, ArchivedProposalIdByAuthor,
+    ArchiveQueue, ArchiveQueueBounds, DaoDecisions,
+};
+
+/// Max number of the oldest archive records pruned per one archived proposal.
+/// Greater than one to shrink the archive when it's max size decreased
+const MAX_PRUNED_PER_ARCHIVED: u32 = 2;
 
 
 pub type StorageWrite<T> = StorageOpsTransaction<StorageOps<T>>;
@@ -22,6 +33,12 @@ pub enum StorageOps<T: Config> {
     UpdateProposal(DeipProposal<T>),
     /// Delete proposal
     DeleteProposal(DeipProposal<T>),
+    /// Update approved proposal and put it to the execution queue
+    ScheduleProposal(DeipProposal<T>),
+    /// Replace proposal with the amended one: (original, amended)
+    AmendProposal(DeipProposal<T>, DeipProposal<T>),
+    /// Update accumulated decisions of DAO signatories: (proposal, DAO key, decisions)
+    UpdateDaoDecisions(ProposalId, T::AccountId, ProposalDecisions<T>),
 }
 impl<T: Config> StorageOp for StorageOps<T> {
     fn exec(self) {
@@ -39,6 +56,7 @@ impl<T: Config> StorageOp for StorageOps<T> {
 
                 let id = proposal.id;
                 let author = proposal.author.clone();
+                ExpiryQueue::<T>::append(proposal.expiry_slot(), id);
                 <ProposalRepository<T>>::insert(id, proposal);
                 ProposalIdByAccountId::<T>::insert(author, id, ());
             },
@@ -46,6 +64,16 @@ impl<T: Config> StorageOp for StorageOps<T> {
                 <ProposalRepository<T>>::insert(proposal.id, proposal)
             },
             StorageOps::DeleteProposal(proposal) => {
+                ExpiryQueue::<T>::mutate_exists(proposal.expiry_slot(), |queue| {
+                    if let Some(ids) = queue {
+                        ids.retain(|x| *x != proposal.id);
+                        if ids.is_empty() { *queue = None; }
+                    }
+                });
+                if proposal.state.resolved() {
+                    archive_proposal::<T>(proposal.clone());
+                }
+                DaoDecisions::<T>::remove_prefix(proposal.id);
                 let DeipProposal::<T> {
                     id: proposal_id,
                     decisions,
@@ -62,6 +90,93 @@ impl<T: Config> StorageOp for StorageOps<T> {
                 ProposalIdByAccountId::<T>::remove(author, proposal_id);
                 <ProposalRepository<T>>::remove(proposal_id);
             },
+            StorageOps::ScheduleProposal(proposal) => {
+                if let Some(execute_after) = proposal.execute_after {
+                    let slot = crate::proposal::schedule_slot::<T>(execute_after);
+                    ScheduledProposals::<T>::mutate(slot, |queue| {
+                        let item = (execute_after, proposal.id);
+                        let pos = queue.binary_search(&item).unwrap_or_else(|x| x);
+                        queue.insert(pos, item);
+                    });
+                    // cursor may be unset or ahead of the slot until the next block
+                    NextScheduledSlot::<T>::mutate(|cursor| {
+                        if !matches!(cursor, Some(x) if *x <= slot) {
+                            *cursor = Some(slot);
+                        }
+                    });
+                }
+                <ProposalRepository<T>>::insert(proposal.id, proposal)
+            },
+            StorageOps::AmendProposal(original, amended) => {
+                DaoDecisions::<T>::remove_prefix(original.id);
+                for m in original.decisions.keys() {
+                    if amended.decisions.contains_key(m) { continue }
+                    PendingProposals::<T>::mutate(m, |x| {
+                        x.remove(&original.id);
+                    });
+                }
+                for m in amended.decisions.keys() {
+                    PendingProposals::<T>::mutate(m, |x| {
+                        x.insert(amended.id, amended.author.clone());
+                    });
+                }
+                <ProposalRepository<T>>::insert(amended.id, amended);
+            },
+            StorageOps::UpdateDaoDecisions(proposal_id, dao_key, decisions) => {
+                if decisions.is_empty() {
+                    DaoDecisions::<T>::remove(proposal_id, dao_key);
+                } else {
+                    DaoDecisions::<T>::insert(proposal_id, dao_key, decisions);
+                }
+            },
+        }
+    }
+}
+
+/// Put resolved proposal to the archive and prune the oldest records if archive is full
+fn archive_proposal<T: Config>(proposal: DeipProposal<T>) {
+    let max = T::MaxArchivedProposals::get() as u64;
+    if max == 0 {
+        return
+    }
+    let resolved_at = frame_system::Module::<T>::block_number();
+    let archived = ArchivedProposal::<T>::new(proposal, resolved_at);
+    
+    // proposal ID may be reused after resolution
+    remove_archived::<T>(archived.id);
+    
+    for m in archived.decisions.keys() {
+        ArchivedProposalIdByMember::<T>::insert(m, archived.id, ());
+    }
+    ArchivedProposalIdByAuthor::<T>::insert(&archived.author, archived.id, ());
+    
+    let (mut head, tail) = ArchiveQueueBounds::<T>::get();
+    ArchiveQueue::<T>::insert(tail, (archived.id, resolved_at));
+    ProposalArchive::<T>::insert(archived.id, archived);
+    let tail = tail.saturating_add(1);
+    
+    let mut pruned = 0;
+    while tail.saturating_sub(head) > max && pruned < MAX_PRUNED_PER_ARCHIVED {
+        if let Some((id, resolved_at)) = ArchiveQueue::<T>::take(head) {
+            let outdated = matches!(
+                ProposalArchive::<T>::get(id),
+                Some(x) if x.resolved_at == resolved_at
+            );
+            if outdated {
+                remove_archived::<T>(id);
+            }
+        }
+        head = head.saturating_add(1);
+        pruned += 1;
+    }
+    ArchiveQueueBounds::<T>::put((head, tail));
+}
+
+fn remove_archived<T: Config>(id: ProposalId) {
+    if let Some(archived) = ProposalArchive::<T>::take(id) {
+        for m in archived.decisions.keys() {
+            ArchivedProposalIdByMember::<T>::remove(m, id);
         }
+        ArchivedProposalIdByAuthor::<T>::remove(&archived.author, id);
     }
 }
@@ -4,6 +4,7 @@
 
 use crate as pallet_deip_proposal;
 use super::{*, Event as RawEvent, Call as RawCall};
+use crate::proposal::*;
 
 use sp_std::prelude::*;
 
@@ -19,7 +20,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
         // Utility: pallet_utility::{Module, Call, Event},
         // RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Module, Call, Storage},
-        // Timestamp: pallet_timestamp::{Module, Call, Storage, Inherent},
+        Timestamp: pallet_timestamp::{Module, Call, Storage, Inherent},
         // Aura: pallet_aura::{Module, Config<T>},
         // Grandpa: pallet_grandpa::{Module, Call, Storage, Config, Event},
         // Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
@@ -54,7 +55,7 @@ impl frame_system::Config for TestRuntime {
     type Header = sp_runtime::testing::Header;
     type Event = Event;
     type BlockHashCount = BlockHashCount;
-    type DbWeight = ();
+    type DbWeight = frame_support::weights::constants::RocksDbWeight;
     type Version = ();
     type PalletInfo = PalletInfo;
     type AccountData = ();
@@ -64,9 +65,55 @@ impl frame_system::Config for TestRuntime {
     type SS58Prefix = ();
 }
 
+frame_support::parameter_types! {
+    pub const MinimumPeriod: u64 = 1;
+    pub const Ttl: u64 = 100;
+    pub const MaxTtl: u64 = 1000;
+    pub const ExpirySlot: u64 = 10;
+    pub const MaxExpiredPerBlock: u32 = 3;
+    pub const MaxArchivedProposals: u32 = 2;
+    pub const MaxScheduledPerBlock: u32 = 3;
+    pub MaxScheduledWeight: frame_support::weights::Weight = 2 * <() as frame_system::WeightInfo>::remark(0);
+    pub const MaxNestingDepth: u32 = 2;
+    pub const MaxBatchItems: u32 = 4;
+    pub const MaxTotalBatchItems: u32 = 6;
+    pub const MaxBatchEncodedSize: u32 = 256;
+}
+
+impl pallet_timestamp::Config for TestRuntime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
 impl crate::Config for TestRuntime {
     type Event = Event;
     type Call = Call;
+    type DeipAccountId = u64;
+    type Ttl = Ttl;
+    type MaxTtl = MaxTtl;
+    type ExpirySlot = ExpirySlot;
+    type MaxExpiredPerBlock = MaxExpiredPerBlock;
+    type MaxArchivedProposals = MaxArchivedProposals;
+    type MaxScheduledPerBlock = MaxScheduledPerBlock;
+    type MaxScheduledWeight = MaxScheduledWeight;
+    type DaoAuthorities = TestDaoAuthorities;
+    type MaxNestingDepth = MaxNestingDepth;
+    type MaxBatchItems = MaxBatchItems;
+    type MaxTotalBatchItems = MaxTotalBatchItems;
+    type MaxBatchEncodedSize = MaxBatchEncodedSize;
+}
+
+const DAO: u64 = 100;
+
+pub struct TestDaoAuthorities;
+
+impl DaoAuthorityT<u64> for TestDaoAuthorities {
+    fn authority(dao_key: &u64) -> Option<DaoAuthority<u64>> {
+        if *dao_key != DAO { return None }
+        Some(DaoAuthority { signatories: vec![10, 11, 12], threshold: 2 })
+    }
 }
 
 pub struct ExtBuilder;
@@ -83,6 +130,8 @@ fn with_test_ext<R>(t: impl FnOnce() -> R) -> R {
 }
 
 use frame_support::{assert_noop, assert_ok};
+use frame_support::traits::OnInitialize;
+use frame_support::weights::GetDispatchInfo;
 
 fn last_event() -> Event {
     frame_system::Module::<TestRuntime>::events().pop().map(|e| e.event).expect("Event expected")
@@ -114,7 +163,7 @@ fn decide_on_not_exist_proposal() {
 fn create_proposal_emits_event() {
     with_test_ext(|| {
         System::set_block_number(1);
-        assert_ok!(Proposal::propose(Origin::signed(0), Vec::new()));
+        assert_ok!(Proposal::propose(Origin::signed(0), Vec::new(), None, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic));
         match last_event() {
             self::Event::pallet_deip_proposal(
                 RawEvent::Proposed {
@@ -140,22 +189,894 @@ fn assert_nested_proposals_limit() {
                         call: Call::Proposal(RawCall::propose(vec![
                             ProposalBatchItemOf::<TestRuntime> {
                                 account: author,
-                                call: Call::Proposal(RawCall::propose(vec![])),
+                                call: Call::Proposal(RawCall::propose(vec![], None, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic)),
                             }
-                        ])),
+                        ], None, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic)),
                     }
-                ])),
+                ], None, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic)),
             }
         ];
         // System::set_block_number(1);
         let origin = Origin::signed(0);
         assert_noop!(
-            Proposal::propose(origin, batch),
+            Proposal::propose(origin, batch, None, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic),
             Error::<TestRuntime>::ReachDepthLimit
         );
     })
 }
 
+fn nested_propose(batch: Vec<InputProposalBatchItem<TestRuntime>>, id: Option<ProposalId>) -> InputProposalBatchItem<TestRuntime> {
+    InputProposalBatchItem::<TestRuntime> {
+        account: 1,
+        call: Call::Proposal(RawCall::propose(batch, id, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic)),
+    }
+}
+
+fn propose_batch(batch: Vec<InputProposalBatchItem<TestRuntime>>) -> frame_support::dispatch::DispatchResultWithPostInfo {
+    Proposal::propose(Origin::signed(0), batch, None, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic)
+}
+
+#[test]
+fn assert_batch_items_limits() {
+    with_test_ext(|| {
+        assert_noop!(propose_batch(remark_batch(&[1, 2, 3, 4, 5])), Error::<TestRuntime>::TooManyBatchItems);
+        assert_noop!(
+            propose_batch(vec![nested_propose(remark_batch(&[1, 2, 3, 4, 5]), None)]),
+            Error::<TestRuntime>::TooManyBatchItems
+        );
+        let mut batch = remark_batch(&[1, 2]);
+        batch.push(nested_propose(remark_batch(&[1, 2, 3, 4]), None));
+        assert_noop!(propose_batch(batch), Error::<TestRuntime>::TooManyTotalBatchItems);
+        
+        let batch = vec![InputProposalBatchItem::<TestRuntime> {
+            account: 1,
+            call: Call::System(frame_system::Call::remark(vec![0; 256])),
+        }];
+        assert_noop!(propose_batch(batch), Error::<TestRuntime>::BatchTooLarge);
+    })
+}
+
+#[test]
+fn assert_cyclic_reference() {
+    with_test_ext(|| {
+        let nested_id = ProposalId::from_low_u64_be(7);
+        let decide = InputProposalBatchItem::<TestRuntime> {
+            account: 1,
+            call: Call::Proposal(RawCall::decide(nested_id, ProposalMemberDecision::Approve)),
+        };
+        assert_noop!(
+            propose_batch(vec![nested_propose(vec![decide.clone()], Some(nested_id))]),
+            Error::<TestRuntime>::CyclicReference
+        );
+        assert_ok!(propose_batch(vec![nested_propose(vec![decide], None)]));
+    })
+}
+
+fn remark_batch(members: &[u64]) -> Vec<InputProposalBatchItem<TestRuntime>> {
+    members.iter().map(|x| {
+        InputProposalBatchItem::<TestRuntime> {
+            account: *x,
+            call: Call::System(frame_system::Call::remark(vec![])),
+        }
+    }).collect()
+}
+
+fn propose_remarks(members: &[u64], approval_policy: ApprovalPolicy<u64>) -> ProposalId {
+    propose_scheduled_remarks(members, approval_policy, None)
+}
+
+fn propose_scheduled_remarks(
+    members: &[u64],
+    approval_policy: ApprovalPolicy<u64>,
+    execute_after: Option<u64>,
+) -> ProposalId {
+    let id = ProposalId::from_low_u64_be(1);
+    assert_ok!(Proposal::propose(
+        Origin::signed(0),
+        remark_batch(members),
+        Some(id),
+        approval_policy,
+        execute_after,
+        None,
+        ExecutionMode::Atomic
+    ));
+    id
+}
+
+#[test]
+fn threshold_policy_executes_without_all_members() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[1, 2, 3], ApprovalPolicy::Threshold(2));
+        assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::Approved { member: 1, proposal_id: id });
+        assert_ok!(Proposal::decide(Origin::signed(2), id, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::Resolved {
+            member: 2,
+            proposal_id: id,
+            state: ProposalState::Done,
+            tally: ApprovalTally { approved: 2, pending: 1, required: 2 },
+            batch_results: vec![BatchItemResult::Done; 3],
+        });
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_none());
+    })
+}
+
+#[test]
+fn threshold_policy_rejects_when_unreachable() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[1, 2, 3], ApprovalPolicy::Threshold(2));
+        assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Reject));
+        expect_event(RawEvent::Rejected { member: 1, proposal_id: id });
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_some());
+        assert_ok!(Proposal::decide(Origin::signed(2), id, ProposalMemberDecision::Reject));
+        expect_event(RawEvent::Resolved {
+            member: 2,
+            proposal_id: id,
+            state: ProposalState::Rejected,
+            tally: ApprovalTally { approved: 0, pending: 1, required: 2 },
+            batch_results: vec![],
+        });
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_none());
+    })
+}
+
+#[test]
+fn percentage_policy_rounds_up() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[1, 2, 3], ApprovalPolicy::Percentage(sp_runtime::Percent::from_percent(50)));
+        assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::Approved { member: 1, proposal_id: id });
+        assert_ok!(Proposal::decide(Origin::signed(3), id, ProposalMemberDecision::Approve));
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_none());
+    })
+}
+
+#[test]
+fn weighted_policy_ignores_optional_signers() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[1, 2, 3], ApprovalPolicy::Weighted {
+            weights: vec![(1, 2), (2, 1)],
+            threshold: 2,
+        });
+        assert_ok!(Proposal::decide(Origin::signed(3), id, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::Approved { member: 3, proposal_id: id });
+        assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::Resolved {
+            member: 1,
+            proposal_id: id,
+            state: ProposalState::Done,
+            tally: ApprovalTally { approved: 2, pending: 1, required: 2 },
+            batch_results: vec![BatchItemResult::Done; 3],
+        });
+    })
+}
+
+#[test]
+fn invalid_approval_policy() {
+    with_test_ext(|| {
+        let batch = remark_batch(&[1, 2, 3]);
+        assert_noop!(
+            Proposal::propose(Origin::signed(0), batch.clone(), None, ApprovalPolicy::Threshold(4), None, None, ExecutionMode::Atomic),
+            Error::<TestRuntime>::InvalidApprovalPolicy
+        );
+        assert_noop!(
+            Proposal::propose(Origin::signed(0), batch.clone(), None, ApprovalPolicy::Threshold(0), None, None, ExecutionMode::Atomic),
+            Error::<TestRuntime>::InvalidApprovalPolicy
+        );
+        assert_noop!(
+            Proposal::propose(Origin::signed(0), batch, None, ApprovalPolicy::Weighted {
+                weights: vec![(1, 1), (4, 5)],
+                threshold: 2,
+            }, None, None, ExecutionMode::Atomic),
+            Error::<TestRuntime>::InvalidApprovalPolicy
+        );
+    })
+}
+
+#[test]
+fn cancel_proposal_by_author() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[1, 2], ApprovalPolicy::Unanimous);
+        assert_noop!(
+            Proposal::cancel(Origin::signed(1), id),
+            Error::<TestRuntime>::NotAnAuthor
+        );
+        assert_ok!(Proposal::cancel(Origin::signed(0), id));
+        expect_event(RawEvent::Cancelled { author: 0, proposal_id: id });
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_none());
+        assert!(ProposalIdByAccountId::<TestRuntime>::get(0, id).is_none());
+        assert!(Proposal::pending_proposals(1).is_empty());
+        assert!(Proposal::pending_proposals(2).is_empty());
+    })
+}
+
+#[test]
+fn amend_proposal_resets_decisions() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[1, 2], ApprovalPolicy::Unanimous);
+        assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+        assert_noop!(
+            Proposal::amend(Origin::signed(1), id, remark_batch(&[1, 3]), None),
+            Error::<TestRuntime>::NotAnAuthor
+        );
+        assert_ok!(Proposal::amend(Origin::signed(0), id, remark_batch(&[1, 3]), None));
+        let proposal = ProposalRepository::<TestRuntime>::get(id).unwrap();
+        assert!(proposal.decisions.values().all(|x| *x == ProposalMemberDecision::Pending));
+        assert_eq!(proposal.decisions.keys().cloned().collect::<Vec<_>>(), vec![1, 3]);
+        assert!(Proposal::pending_proposals(1).contains_key(&id));
+        assert!(Proposal::pending_proposals(2).is_empty());
+        assert!(Proposal::pending_proposals(3).contains_key(&id));
+        assert_noop!(
+            Proposal::decide(Origin::signed(2), id, ProposalMemberDecision::Approve),
+            Error::<TestRuntime>::NotAMember
+        );
+        assert_noop!(
+            Proposal::amend(Origin::signed(0), id, remark_batch(&[1]), Some(ApprovalPolicy::Threshold(2))),
+            Error::<TestRuntime>::InvalidApprovalPolicy
+        );
+    })
+}
+
+#[test]
+fn approved_proposal_scheduled_until_execution_time() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let id = propose_scheduled_remarks(&[1], ApprovalPolicy::Unanimous, Some(20));
+        assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::Scheduled {
+            member: 1,
+            proposal_id: id,
+            execute_after: 20,
+            tally: ApprovalTally { approved: 1, pending: 0, required: 1 },
+        });
+        assert_eq!(ProposalRepository::<TestRuntime>::get(id).unwrap().state, ProposalState::Scheduled);
+        assert_noop!(
+            Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Reject),
+            Error::<TestRuntime>::AlreadyResolved
+        );
+        
+        Proposal::on_initialize(2);
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_some());
+        
+        Timestamp::set_timestamp(20);
+        Proposal::on_initialize(3);
+        expect_event(RawEvent::Executed {
+            proposal_id: id,
+            state: ProposalState::Done,
+            batch_results: vec![BatchItemResult::Done],
+        });
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_none());
+        assert!(ScheduledProposals::<TestRuntime>::get(2).is_empty());
+        assert!(Proposal::pending_proposals(1).is_empty());
+    })
+}
+
+#[test]
+fn past_execution_time_executes_immediately() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(30);
+        let id = propose_scheduled_remarks(&[1], ApprovalPolicy::Unanimous, Some(20));
+        assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::Resolved {
+            member: 1,
+            proposal_id: id,
+            state: ProposalState::Done,
+            tally: ApprovalTally { approved: 1, pending: 0, required: 1 },
+            batch_results: vec![BatchItemResult::Done],
+        });
+    })
+}
+
+#[test]
+fn scheduled_execution_limited_per_block() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let ids: Vec<ProposalId> = (1..=3).map(|x| {
+            let id = ProposalId::from_low_u64_be(x);
+            assert_ok!(Proposal::propose(
+                Origin::signed(0),
+                remark_batch(&[1]),
+                Some(id),
+                ApprovalPolicy::Unanimous,
+                Some(20),
+                None,
+                ExecutionMode::Atomic
+            ));
+            assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+            id
+        }).collect();
+        Timestamp::set_timestamp(20);
+        Proposal::on_initialize(2);
+        assert_eq!(ScheduledProposals::<TestRuntime>::get(2), vec![(20, ids[2])]);
+        Proposal::on_initialize(3);
+        assert!(ScheduledProposals::<TestRuntime>::get(2).is_empty());
+        assert!(ids.iter().all(|x| ProposalRepository::<TestRuntime>::get(x).is_none()));
+    })
+}
+
+#[test]
+fn scheduled_execution_limited_by_weight() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let ids: Vec<ProposalId> = [&[1, 2][..], &[1, 2, 3], &[1]].iter().zip(1..).map(|(members, x)| {
+            let id = ProposalId::from_low_u64_be(x);
+            assert_ok!(Proposal::propose(
+                Origin::signed(0),
+                remark_batch(members),
+                Some(id),
+                ApprovalPolicy::Unanimous,
+                Some(20),
+                None,
+                ExecutionMode::Atomic
+            ));
+            for m in members.iter() {
+                assert_ok!(Proposal::decide(Origin::signed(*m), id, ProposalMemberDecision::Approve));
+            }
+            id
+        }).collect();
+        Timestamp::set_timestamp(20);
+        Proposal::on_initialize(2);
+        assert!(ProposalRepository::<TestRuntime>::get(ids[0]).is_none());
+        assert_eq!(ScheduledProposals::<TestRuntime>::get(2), vec![(20, ids[1]), (20, ids[2])]);
+        // heavier than the budget but first in the block
+        Proposal::on_initialize(3);
+        assert_eq!(ScheduledProposals::<TestRuntime>::get(2), vec![(20, ids[2])]);
+        Proposal::on_initialize(4);
+        assert!(ScheduledProposals::<TestRuntime>::get(2).is_empty());
+        assert!(ids.iter().all(|x| ProposalRepository::<TestRuntime>::get(x).is_none()));
+    })
+}
+
+#[test]
+fn schedule_cursor_catches_up_elapsed_slots() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let ids: Vec<ProposalId> = [20, 45].iter().zip(1..).map(|(execute_after, x)| {
+            let id = ProposalId::from_low_u64_be(x);
+            assert_ok!(Proposal::propose(
+                Origin::signed(0),
+                remark_batch(&[1]),
+                Some(id),
+                ApprovalPolicy::Unanimous,
+                Some(*execute_after),
+                None,
+                ExecutionMode::Atomic
+            ));
+            assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+            id
+        }).collect();
+        assert_eq!(ScheduledProposals::<TestRuntime>::get(4), vec![(45, ids[1])]);
+        
+        Timestamp::set_timestamp(50);
+        Proposal::on_initialize(2);
+        assert!(ProposalRepository::<TestRuntime>::get(ids[0]).is_none());
+        assert!(ProposalRepository::<TestRuntime>::get(ids[1]).is_some());
+        assert_eq!(NextScheduledSlot::<TestRuntime>::get(), Some(4));
+        
+        Proposal::on_initialize(3);
+        assert!(ProposalRepository::<TestRuntime>::get(ids[1]).is_none());
+        assert!(!ScheduledProposals::<TestRuntime>::contains_key(4));
+        assert_eq!(NextScheduledSlot::<TestRuntime>::get(), Some(5));
+    })
+}
+
+#[test]
+fn scheduled_execution_failure_reported() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let id = propose_remarks(&[1], ApprovalPolicy::Unanimous);
+        ScheduledProposals::<TestRuntime>::insert(2, vec![(20, id)]);
+        
+        Timestamp::set_timestamp(20);
+        Proposal::on_initialize(2);
+        expect_event(RawEvent::ScheduledExecutionFailed {
+            proposal_id: id,
+            error: stored_error(Error::<TestRuntime>::NotScheduled),
+        });
+        assert!(!ScheduledProposals::<TestRuntime>::contains_key(2));
+        assert_eq!(ProposalRepository::<TestRuntime>::get(id).unwrap().state, ProposalState::Pending);
+    })
+}
+
+#[test]
+fn proposals_migrated_to_v2() {
+    use frame_support::traits::OnRuntimeUpgrade;
+    use crate::migration::{DeipProposalV1, Releases};
+    
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(200);
+        let ids: Vec<ProposalId> = [150, 50].iter().zip(1..).map(|(created_at, x)| {
+            let id = ProposalId::from_low_u64_be(x);
+            let old = DeipProposalV1::<TestRuntime> {
+                id,
+                batch: vec![],
+                decisions: vec![(1, ProposalMemberDecision::Approve), (2, ProposalMemberDecision::Pending)]
+                    .into_iter().collect(),
+                state: ProposalState::Pending,
+                author: 0,
+                created_at: *created_at,
+            };
+            frame_support::storage::unhashed::put(
+                &ProposalRepository::<TestRuntime>::hashed_key_for(id),
+                &old
+            );
+            id
+        }).collect();
+        
+        Proposal::on_runtime_upgrade();
+        assert_eq!(StorageVersion::<TestRuntime>::get(), Releases::V2);
+        let proposal = ProposalRepository::<TestRuntime>::get(ids[0]).unwrap();
+        assert_eq!(proposal.approval_policy, ApprovalPolicy::Unanimous);
+        assert_eq!(proposal.execution_mode, ExecutionMode::Atomic);
+        assert_eq!(proposal.execute_after, None);
+        assert_eq!(proposal.ttl, Ttl::get());
+        assert_eq!(proposal.decisions.get(&1), Some(&ProposalMemberDecision::Approve));
+        let slot = proposal.expiry_slot();
+        assert_eq!(ExpiryQueue::<TestRuntime>::get(slot), vec![ids[0]]);
+        
+        let expired = ProposalRepository::<TestRuntime>::get(ids[1]).unwrap();
+        assert!(expired.expired(200));
+        assert_eq!(NextExpirySlot::<TestRuntime>::get(), Some(expired.expiry_slot()));
+        Proposal::on_initialize(2);
+        expect_event(RawEvent::Expired { proposal_id: ids[1] });
+        
+        // runs once
+        Proposal::on_runtime_upgrade();
+        assert_eq!(ProposalRepository::<TestRuntime>::get(ids[0]), Some(proposal));
+        assert_eq!(ExpiryQueue::<TestRuntime>::get(slot), vec![ids[0]]);
+    })
+}
+
+fn propose_remarks_with_ttl(id: u64, members: &[u64], ttl: Option<u64>) -> ProposalId {
+    let id = ProposalId::from_low_u64_be(id);
+    assert_ok!(Proposal::propose(
+        Origin::signed(0),
+        remark_batch(members),
+        Some(id),
+        ApprovalPolicy::Unanimous,
+        None,
+        ttl,
+        ExecutionMode::Atomic
+    ));
+    id
+}
+
+#[test]
+fn invalid_ttl() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            Proposal::propose(Origin::signed(0), remark_batch(&[1]), None, ApprovalPolicy::Unanimous, None, Some(0), ExecutionMode::Atomic),
+            Error::<TestRuntime>::InvalidTtl
+        );
+        assert_noop!(
+            Proposal::propose(Origin::signed(0), remark_batch(&[1]), None, ApprovalPolicy::Unanimous, None, Some(1001), ExecutionMode::Atomic),
+            Error::<TestRuntime>::InvalidTtl
+        );
+    })
+}
+
+#[test]
+fn pending_proposal_expires_from_queue() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let id = propose_remarks_with_ttl(1, &[1], Some(15));
+        assert_eq!(ExpiryQueue::<TestRuntime>::get(3), vec![id]);
+        
+        Timestamp::set_timestamp(29);
+        Proposal::on_initialize(2);
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_some());
+        
+        Timestamp::set_timestamp(30);
+        Proposal::on_initialize(3);
+        expect_event(RawEvent::Expired { proposal_id: id });
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_none());
+        assert!(Proposal::pending_proposals(1).is_empty());
+        assert!(!ExpiryQueue::<TestRuntime>::contains_key(3));
+        assert_eq!(NextExpirySlot::<TestRuntime>::get(), Some(4));
+    })
+}
+
+#[test]
+fn expiry_cursor_starts_from_first_timestamp() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Proposal::on_initialize(1);
+        assert_eq!(NextExpirySlot::<TestRuntime>::get(), None);
+        
+        Timestamp::set_timestamp(1000);
+        Proposal::on_initialize(2);
+        assert_eq!(NextExpirySlot::<TestRuntime>::get(), Some(101));
+    })
+}
+
+#[test]
+fn resolved_proposal_leaves_expiry_queue() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let id = propose_remarks_with_ttl(1, &[1, 2], None);
+        assert_eq!(ExpiryQueue::<TestRuntime>::get(11), vec![id]);
+        assert_ok!(Proposal::cancel(Origin::signed(0), id));
+        assert!(!ExpiryQueue::<TestRuntime>::contains_key(11));
+    })
+}
+
+#[test]
+fn expiration_limited_per_block() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let ids: Vec<ProposalId> = (1..=3)
+            .map(|x| propose_remarks_with_ttl(x, &[1], Some(10)))
+            .collect();
+        
+        Timestamp::set_timestamp(20);
+        Proposal::on_initialize(2);
+        assert_eq!(ExpiryQueue::<TestRuntime>::get(2), vec![ids[2]]);
+        assert!(ProposalRepository::<TestRuntime>::get(ids[2]).is_some());
+        
+        Proposal::on_initialize(3);
+        assert!(!ExpiryQueue::<TestRuntime>::contains_key(2));
+        assert!(ids.iter().all(|x| ProposalRepository::<TestRuntime>::get(x).is_none()));
+    })
+}
+
+fn remark_weight() -> u64 {
+    Call::System(frame_system::Call::remark(vec![])).get_dispatch_info().weight
+}
+
+#[test]
+fn propose_weight_covers_nested_batch() {
+    with_test_ext(|| {
+        let mut batch = remark_batch(&[1, 2]);
+        batch.push(InputProposalBatchItem::<TestRuntime> {
+            account: 3,
+            call: Call::Proposal(RawCall::propose(remark_batch(&[3]), None, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic)),
+        });
+        let call = RawCall::<TestRuntime>::propose(batch, None, ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic);
+        assert_eq!(
+            call.get_dispatch_info().weight,
+            crate::batch_weight::propose_base::<TestRuntime>(3) + 3 * remark_weight()
+        );
+    })
+}
+
+#[test]
+fn decide_refunds_unused_weight() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[1, 2], ApprovalPolicy::Unanimous);
+        let base = crate::batch_weight::decide_base::<TestRuntime>(2);
+        let call = RawCall::<TestRuntime>::decide(id, ProposalMemberDecision::Approve);
+        assert_eq!(call.get_dispatch_info().weight, base + 2 * remark_weight());
+        
+        let post_info = Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve).unwrap();
+        assert_eq!(post_info.actual_weight, Some(base));
+        
+        let post_info = Proposal::decide(Origin::signed(2), id, ProposalMemberDecision::Approve).unwrap();
+        assert_eq!(post_info.actual_weight, Some(base + 2 * remark_weight()));
+    })
+}
+
+#[test]
+fn cancel_weight_covers_members() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[1, 2, 3], ApprovalPolicy::Unanimous);
+        let weight = crate::batch_weight::cancel_base::<TestRuntime>(3);
+        assert!(weight > crate::batch_weight::cancel_base::<TestRuntime>(0));
+        let call = RawCall::<TestRuntime>::cancel(id);
+        assert_eq!(call.get_dispatch_info().weight, weight);
+        
+        let post_info = Proposal::cancel(Origin::signed(0), id).unwrap();
+        assert_eq!(post_info.actual_weight, Some(weight));
+    })
+}
+
+#[test]
+fn dry_run_does_not_commit_batch() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let nested_id = ProposalId::from_low_u64_be(7);
+        let batch = vec![
+            InputProposalBatchItem::<TestRuntime> {
+                account: 1,
+                call: Call::Proposal(RawCall::propose(remark_batch(&[2]), Some(nested_id), ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic)),
+            },
+            InputProposalBatchItem::<TestRuntime> {
+                account: 1,
+                call: Call::Proposal(RawCall::decide(ProposalId::from_low_u64_be(8), ProposalMemberDecision::Approve)),
+            },
+            InputProposalBatchItem::<TestRuntime> {
+                account: 1,
+                call: Call::System(frame_system::Call::remark(vec![])),
+            },
+        ];
+        let items = Proposal::rpc_dry_run(crate::api::DryRunTarget::Batch(batch, ExecutionMode::Atomic)).unwrap();
+        assert_eq!(items.len(), 2);
+        assert!(items[0].result.is_ok());
+        assert_eq!(items[0].events, vec![Event::pallet_deip_proposal(RawEvent::Proposed {
+            author: 1,
+            batch: remark_batch(&[2]),
+            proposal_id: nested_id,
+        })]);
+        assert!(items[1].result.is_err());
+        assert_eq!(
+            items[1].module_error.as_ref().and_then(|x| x.name.clone()),
+            Some(b"NotFound".to_vec())
+        );
+        assert!(ProposalRepository::<TestRuntime>::get(nested_id).is_none());
+        assert!(System::events().is_empty());
+        
+        assert!(Proposal::rpc_dry_run(crate::api::DryRunTarget::Proposal(nested_id)).is_none());
+        
+        let too_many = remark_batch(&[1, 2, 3, 4, 5]);
+        assert!(Proposal::rpc_dry_run(crate::api::DryRunTarget::Batch(too_many, ExecutionMode::Atomic)).is_none());
+        let too_deep = vec![nested_propose(vec![nested_propose(remark_batch(&[1]), None)], None)];
+        assert!(Proposal::rpc_dry_run(crate::api::DryRunTarget::Batch(too_deep, ExecutionMode::Atomic)).is_none());
+    })
+}
+
+#[test]
+fn resolved_proposal_archived_with_decisions() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let id = propose_remarks(&[1, 2], ApprovalPolicy::Unanimous);
+        assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+        System::set_block_number(2);
+        Timestamp::set_timestamp(15);
+        assert_ok!(Proposal::decide(Origin::signed(2), id, ProposalMemberDecision::Approve));
+        
+        let archived = ProposalArchive::<TestRuntime>::get(id).unwrap();
+        assert_eq!(archived.state, ProposalState::Done);
+        assert_eq!(archived.resolved_at, 2);
+        assert_eq!(
+            archived.decisions.into_iter().collect::<Vec<_>>(),
+            vec![
+                (1, ArchivedDecision { decision: ProposalMemberDecision::Approve, decided_at: Some(10) }),
+                (2, ArchivedDecision { decision: ProposalMemberDecision::Approve, decided_at: Some(15) }),
+            ]
+        );
+        assert!(ArchivedProposalIdByMember::<TestRuntime>::contains_key(1, id));
+        assert!(ArchivedProposalIdByMember::<TestRuntime>::contains_key(2, id));
+        assert!(ArchivedProposalIdByAuthor::<TestRuntime>::contains_key(0, id));
+    })
+}
+
+#[test]
+fn expired_proposal_archived_and_cancelled_is_not() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(10);
+        let expired = propose_remarks_with_ttl(1, &[1], Some(10));
+        let cancelled = propose_remarks_with_ttl(2, &[1], Some(10));
+        assert_ok!(Proposal::cancel(Origin::signed(0), cancelled));
+        
+        Timestamp::set_timestamp(20);
+        Proposal::on_initialize(2);
+        let archived = ProposalArchive::<TestRuntime>::get(expired).unwrap();
+        assert_eq!(archived.state, ProposalState::Expired);
+        assert_eq!(
+            archived.decisions.get(&1),
+            Some(&ArchivedDecision { decision: ProposalMemberDecision::Pending, decided_at: None })
+        );
+        assert!(ProposalArchive::<TestRuntime>::get(cancelled).is_none());
+    })
+}
+
+#[test]
+fn archive_prunes_oldest_proposals() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let ids: Vec<ProposalId> = (1..=3).map(|x| {
+            let id = propose_remarks_with_ttl(x, &[1], None);
+            assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Reject));
+            id
+        }).collect();
+        assert!(ProposalArchive::<TestRuntime>::get(ids[0]).is_none());
+        assert!(!ArchivedProposalIdByMember::<TestRuntime>::contains_key(1, ids[0]));
+        assert!(!ArchivedProposalIdByAuthor::<TestRuntime>::contains_key(0, ids[0]));
+        assert_eq!(ProposalArchive::<TestRuntime>::get(ids[1]).unwrap().state, ProposalState::Rejected);
+        assert_eq!(ProposalArchive::<TestRuntime>::get(ids[2]).unwrap().state, ProposalState::Rejected);
+        assert_eq!(ArchiveQueueBounds::<TestRuntime>::get(), (1, 3));
+    })
+}
+
+#[test]
+fn dao_decides_when_authority_threshold_met() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[DAO], ApprovalPolicy::Unanimous);
+        assert_ok!(Proposal::decide_as_dao(Origin::signed(10), id, DAO, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::DaoMemberDecided {
+            member: 10,
+            dao: DAO,
+            proposal_id: id,
+            decision: ProposalMemberDecision::Approve,
+        });
+        assert_eq!(DaoDecisions::<TestRuntime>::get(id, DAO).len(), 1);
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_some());
+        
+        assert_ok!(Proposal::decide_as_dao(Origin::signed(11), id, DAO, ProposalMemberDecision::Approve));
+        expect_event(RawEvent::Resolved {
+            member: DAO,
+            proposal_id: id,
+            state: ProposalState::Done,
+            tally: ApprovalTally { approved: 1, pending: 0, required: 1 },
+            batch_results: vec![BatchItemResult::Done],
+        });
+        assert!(ProposalRepository::<TestRuntime>::get(id).is_none());
+        assert!(DaoDecisions::<TestRuntime>::get(id, DAO).is_empty());
+    })
+}
+
+#[test]
+fn dao_rejects_when_authority_threshold_unreachable() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[DAO, 1], ApprovalPolicy::Unanimous);
+        assert_ok!(Proposal::decide_as_dao(Origin::signed(10), id, DAO, ProposalMemberDecision::Reject));
+        assert_ok!(Proposal::decide_as_dao(Origin::signed(11), id, DAO, ProposalMemberDecision::Approve));
+        assert_ok!(Proposal::decide_as_dao(Origin::signed(11), id, DAO, ProposalMemberDecision::Reject));
+        expect_event(RawEvent::Resolved {
+            member: DAO,
+            proposal_id: id,
+            state: ProposalState::Rejected,
+            tally: ApprovalTally { approved: 0, pending: 1, required: 2 },
+            batch_results: vec![],
+        });
+        assert_eq!(ProposalArchive::<TestRuntime>::get(id).unwrap().state, ProposalState::Rejected);
+    })
+}
+
+#[test]
+fn decide_as_dao_requires_signatory() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = propose_remarks(&[DAO, 1], ApprovalPolicy::Unanimous);
+        assert_noop!(
+            Proposal::decide_as_dao(Origin::signed(1), id, DAO, ProposalMemberDecision::Approve),
+            Error::<TestRuntime>::NotADaoSignatory,
+        );
+        assert_noop!(
+            Proposal::decide_as_dao(Origin::signed(10), id, 1, ProposalMemberDecision::Approve),
+            Error::<TestRuntime>::NotADao,
+        );
+        assert_noop!(
+            Proposal::decide_as_dao(Origin::signed(10), id, 2, ProposalMemberDecision::Approve),
+            Error::<TestRuntime>::NotAMember,
+        );
+    })
+}
+
+/// Batch where the item of member 1 creates a nested proposal and the item of member 2 fails
+fn propose_partially_failing_batch(mode: ExecutionMode, nested_id: ProposalId) -> ProposalId {
+    let id = ProposalId::from_low_u64_be(1);
+    let batch = vec![
+        InputProposalBatchItem::<TestRuntime> {
+            account: 1,
+            call: Call::Proposal(RawCall::propose(remark_batch(&[3]), Some(nested_id), ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic)),
+        },
+        InputProposalBatchItem::<TestRuntime> {
+            account: 2,
+            call: Call::Proposal(RawCall::decide(ProposalId::from_low_u64_be(8), ProposalMemberDecision::Approve)),
+        },
+    ];
+    assert_ok!(Proposal::propose(Origin::signed(0), batch, Some(id), ApprovalPolicy::Unanimous, None, None, mode));
+    assert_ok!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve));
+    id
+}
+
+/// Module error as it's stored (error message is not encoded)
+fn stored_error(error: Error<TestRuntime>) -> sp_runtime::DispatchError {
+    let error: sp_runtime::DispatchError = error.into();
+    codec::Decode::decode(&mut &codec::Encode::encode(&error)[..]).unwrap()
+}
+
+fn not_found_error() -> sp_runtime::DispatchError {
+    stored_error(Error::<TestRuntime>::NotFound)
+}
+
+#[test]
+fn best_effort_batch_keeps_succeeded_items() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let nested_id = ProposalId::from_low_u64_be(7);
+        let id = propose_partially_failing_batch(ExecutionMode::BestEffort, nested_id);
+        assert_ok!(Proposal::decide(Origin::signed(2), id, ProposalMemberDecision::Approve));
+        let batch_results = vec![BatchItemResult::Done, BatchItemResult::Failed(not_found_error())];
+        expect_event(RawEvent::Resolved {
+            member: 2,
+            proposal_id: id,
+            state: ProposalState::PartiallyDone,
+            tally: ApprovalTally { approved: 2, pending: 0, required: 2 },
+            batch_results: batch_results.clone(),
+        });
+        assert!(ProposalRepository::<TestRuntime>::get(nested_id).is_some());
+        assert_eq!(ProposalArchive::<TestRuntime>::get(id).unwrap().batch_results, batch_results);
+    })
+}
+
+#[test]
+fn all_or_nothing_batch_reports_every_item() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let nested_id = ProposalId::from_low_u64_be(7);
+        let id = propose_partially_failing_batch(ExecutionMode::AllOrNothing, nested_id);
+        assert!(Proposal::decide(Origin::signed(2), id, ProposalMemberDecision::Approve).is_err());
+        expect_event(RawEvent::Resolved {
+            member: 2,
+            proposal_id: id,
+            state: ProposalState::Failed(not_found_error()),
+            tally: ApprovalTally { approved: 2, pending: 0, required: 2 },
+            batch_results: vec![BatchItemResult::Done, BatchItemResult::Failed(not_found_error())],
+        });
+        assert!(ProposalRepository::<TestRuntime>::get(nested_id).is_none());
+    })
+}
+
+#[test]
+fn atomic_batch_skips_items_after_failed_one() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = ProposalId::from_low_u64_be(1);
+        let mut batch = remark_batch(&[1]);
+        batch.insert(0, InputProposalBatchItem::<TestRuntime> {
+            account: 1,
+            call: Call::Proposal(RawCall::decide(ProposalId::from_low_u64_be(8), ProposalMemberDecision::Approve)),
+        });
+        assert_ok!(Proposal::propose(Origin::signed(0), batch, Some(id), ApprovalPolicy::Unanimous, None, None, ExecutionMode::Atomic));
+        assert!(Proposal::decide(Origin::signed(1), id, ProposalMemberDecision::Approve).is_err());
+        assert_eq!(
+            ProposalArchive::<TestRuntime>::get(id).unwrap().batch_results,
+            vec![BatchItemResult::Failed(not_found_error()), BatchItemResult::Skipped]
+        );
+    })
+}
+
+#[test]
+fn dry_run_proposal_in_its_execution_mode() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let id = ProposalId::from_low_u64_be(1);
+        let mut batch = remark_batch(&[1]);
+        batch.insert(0, InputProposalBatchItem::<TestRuntime> {
+            account: 1,
+            call: Call::Proposal(RawCall::decide(ProposalId::from_low_u64_be(8), ProposalMemberDecision::Approve)),
+        });
+        let atomic = Proposal::rpc_dry_run(crate::api::DryRunTarget::Batch(batch.clone(), ExecutionMode::Atomic));
+        assert_eq!(atomic.unwrap().len(), 1);
+        
+        assert_ok!(Proposal::propose(Origin::signed(0), batch, Some(id), ApprovalPolicy::Unanimous, None, None, ExecutionMode::BestEffort));
+        let items = Proposal::rpc_dry_run(crate::api::DryRunTarget::Proposal(id)).unwrap();
+        assert_eq!(items.len(), 2);
+        assert!(items[0].result.is_err());
+        assert_eq!(
+            items[0].module_error.as_ref().and_then(|x| x.name.clone()),
+            Some(b"NotFound".to_vec())
+        );
+        assert!(items[1].result.is_ok());
+        assert_eq!(items[1].weight, remark_weight());
+        assert_eq!(ProposalRepository::<TestRuntime>::get(id).unwrap().state, ProposalState::Pending);
+    })
+}
+
 // #[test]
 // fn create_proposal {
 //     with_test_ext(|| {
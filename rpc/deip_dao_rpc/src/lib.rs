@@ -12,17 +12,21 @@ use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 
 pub use pallet_deip_dao::api::DeipDaoRuntimeApi;
-use pallet_deip_dao::api::{GetMultiResult, GetResult};
+use pallet_deip_dao::api::{
+    GetBudgetListResult, GetBudgetResult, GetInvitationListByDaoResult,
+    GetInvitationListByMemberResult, GetMetadataHistoryResult, GetMultiResult, GetResult,
+};
+use pallet_deip_dao::budget::BudgetId;
 use pallet_deip_dao::dao::{Dao, DaoId};
 
 use frame_support::Blake2_128Concat;
 
-use common_rpc::{FutureResult, HashOf, ListResult, StorageMap};
+use common_rpc::{get_list_by_index, FutureResult, HashOf, ListResult, StorageMap};
 
 mod types;
 
 #[rpc]
-pub trait DeipDaoRpcApi<BlockHash, AccountId> {
+pub trait DeipDaoRpcApi<BlockHash, AccountId, AssetId, Balance, BlockNumber, Moment> {
     #[rpc(name = "deipDao_get")]
     fn get(&self, at: Option<BlockHash>, id: DaoId) -> RpcResult<GetResult<AccountId>>;
 
@@ -40,6 +44,54 @@ pub trait DeipDaoRpcApi<BlockHash, AccountId> {
         count: u32,
         start_id: Option<DaoId>,
     ) -> FutureResult<Vec<ListResult<DaoId, Dao<AccountId, DaoId>>>>;
+
+    #[rpc(name = "deipDao_listByMember")]
+    fn list_by_member(
+        &self,
+        at: Option<BlockHash>,
+        member: AccountId,
+        count: u32,
+        start_id: Option<DaoId>,
+    ) -> FutureResult<Vec<ListResult<DaoId, Dao<AccountId, DaoId>>>>;
+
+    #[rpc(name = "deipDao_getInvitationListByDao")]
+    fn get_invitation_list_by_dao(
+        &self,
+        at: Option<BlockHash>,
+        id: DaoId,
+    ) -> RpcResult<GetInvitationListByDaoResult<AccountId, BlockNumber>>;
+
+    #[rpc(name = "deipDao_getInvitationListByMember")]
+    fn get_invitation_list_by_member(
+        &self,
+        at: Option<BlockHash>,
+        member: AccountId,
+    ) -> RpcResult<GetInvitationListByMemberResult<BlockNumber>>;
+
+    #[rpc(name = "deipDao_getBudget")]
+    fn get_budget(
+        &self,
+        at: Option<BlockHash>,
+        id: DaoId,
+        budget_id: BudgetId,
+    ) -> RpcResult<GetBudgetResult<AccountId, AssetId, Balance, BlockNumber>>;
+
+    #[rpc(name = "deipDao_getBudgetList")]
+    fn get_budget_list(
+        &self,
+        at: Option<BlockHash>,
+        id: DaoId,
+    ) -> RpcResult<GetBudgetListResult<AccountId, AssetId, Balance, BlockNumber>>;
+
+    #[rpc(name = "deipDao_getMetadataHistory")]
+    fn get_metadata_history(
+        &self,
+        at: Option<BlockHash>,
+        id: DaoId,
+    ) -> RpcResult<GetMetadataHistoryResult<AccountId, BlockNumber, Moment>>;
+
+    #[rpc(name = "deipDao_getIdByHandle")]
+    fn get_id_by_handle(&self, at: Option<BlockHash>, handle: String) -> RpcResult<Option<DaoId>>;
 }
 
 pub struct DeipDaoRpcApiObj<C, State, Block> {
@@ -58,16 +110,21 @@ impl<C, State, Block> DeipDaoRpcApiObj<C, State, Block> {
     }
 }
 
-impl<C, State, Block, AccountId> DeipDaoRpcApi<HashOf<Block>, AccountId>
+impl<C, State, Block, AccountId, AssetId, Balance, BlockNumber, Moment>
+    DeipDaoRpcApi<HashOf<Block>, AccountId, AssetId, Balance, BlockNumber, Moment>
     for DeipDaoRpcApiObj<C, State, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block>,
-    C::Api: DeipDaoRuntimeApi<Block, AccountId>,
+    C::Api: DeipDaoRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber, Moment>,
     State: sc_rpc_api::state::StateApi<HashOf<Block>>,
     AccountId: 'static + Codec + std::marker::Send,
+    AssetId: Codec,
+    Balance: Codec,
+    BlockNumber: Codec,
+    Moment: Codec,
 {
     fn get(
         &self,
@@ -115,4 +172,126 @@ where
             start_id.map(types::DaoKeyValue::new),
         )
     }
+
+    fn list_by_member(
+        &self,
+        at: Option<HashOf<Block>>,
+        member: AccountId,
+        count: u32,
+        start_id: Option<DaoId>,
+    ) -> FutureResult<Vec<ListResult<DaoId, Dao<AccountId, DaoId>>>> {
+        get_list_by_index::<Blake2_128Concat, Blake2_128Concat, _, _, _, _>(
+            &self.state,
+            at,
+            b"DeipDao",
+            b"DaoIdByMember",
+            b"DaoRepository",
+            count,
+            &member,
+            start_id.map(types::DaoKeyValue::new),
+        )
+    }
+
+    fn get_invitation_list_by_dao(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        id: DaoId,
+    ) -> RpcResult<GetInvitationListByDaoResult<AccountId, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_invitation_list_by_dao(&at, id);
+        runtime_api_result.map_err(|e| {
+            common_rpc::to_rpc_error(
+                common_rpc::Error::DaoApiGetInvitationListFailed,
+                Some(format!("{:?}", e)),
+            )
+        })
+    }
+
+    fn get_invitation_list_by_member(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        member: AccountId,
+    ) -> RpcResult<GetInvitationListByMemberResult<BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_invitation_list_by_member(&at, member);
+        runtime_api_result.map_err(|e| {
+            common_rpc::to_rpc_error(
+                common_rpc::Error::DaoApiGetInvitationListFailed,
+                Some(format!("{:?}", e)),
+            )
+        })
+    }
+
+    fn get_budget(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        id: DaoId,
+        budget_id: BudgetId,
+    ) -> RpcResult<GetBudgetResult<AccountId, AssetId, Balance, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_budget(&at, id, budget_id);
+        runtime_api_result.map_err(|e| {
+            common_rpc::to_rpc_error(
+                common_rpc::Error::DaoApiGetBudgetFailed,
+                Some(format!("{:?}", e)),
+            )
+        })
+    }
+
+    fn get_budget_list(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        id: DaoId,
+    ) -> RpcResult<GetBudgetListResult<AccountId, AssetId, Balance, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_budget_list(&at, id);
+        runtime_api_result.map_err(|e| {
+            common_rpc::to_rpc_error(
+                common_rpc::Error::DaoApiGetBudgetListFailed,
+                Some(format!("{:?}", e)),
+            )
+        })
+    }
+
+    fn get_metadata_history(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        id: DaoId,
+    ) -> RpcResult<GetMetadataHistoryResult<AccountId, BlockNumber, Moment>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_metadata_history(&at, id);
+        runtime_api_result.map_err(|e| {
+            common_rpc::to_rpc_error(
+                common_rpc::Error::DaoApiGetMetadataHistoryFailed,
+                Some(format!("{:?}", e)),
+            )
+        })
+    }
+
+    fn get_id_by_handle(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        handle: String,
+    ) -> RpcResult<Option<DaoId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_id_by_handle(&at, handle.into_bytes());
+        runtime_api_result.map_err(|e| {
+            common_rpc::to_rpc_error(
+                common_rpc::Error::DaoApiGetIdByHandleFailed,
+                Some(format!("{:?}", e)),
+            )
+        })
+    }
 }
@@ -0,0 +1,87 @@
+use jsonrpc_core::Result as RpcResult;
+use jsonrpc_derive::rpc;
+
+use std::sync::Arc;
+use std::vec::Vec;
+
+use codec::{Codec, Decode};
+
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+pub use pallet_deip_proposal::api::DeipProposalRuntimeApi;
+
+use common_rpc::HashOf;
+
+use crate::types::{self, DryRunItem};
+
+#[rpc]
+pub trait DeipProposalDryRunApi<BlockHash> {
+    #[rpc(name = "deipProposal_dryRun")]
+    fn dry_run(
+        &self,
+        at: Option<BlockHash>,
+        target: types::DryRunTarget,
+    ) -> RpcResult<Option<Vec<DryRunItem>>>;
+}
+
+pub struct DeipProposalDryRunApiObj<C, Block, AccountId, CallT, Event> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<(Block, AccountId, CallT, Event)>,
+}
+
+impl<C, Block, AccountId, CallT, Event> DeipProposalDryRunApiObj<C, Block, AccountId, CallT, Event> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, CallT, Event> DeipProposalDryRunApi<HashOf<Block>>
+    for DeipProposalDryRunApiObj<C, Block, AccountId, CallT, Event>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: DeipProposalRuntimeApi<Block, AccountId, CallT, Event>,
+    AccountId: 'static + Codec + Send + Sync,
+    CallT: 'static + Codec + Send + Sync,
+    Event: 'static + Codec + Send + Sync,
+{
+    fn dry_run(
+        &self,
+        at: Option<HashOf<Block>>,
+        target: types::DryRunTarget,
+    ) -> RpcResult<Option<Vec<DryRunItem>>> {
+        let target = match target {
+            types::DryRunTarget::Batch(batch, mode) => {
+                let batch = Decode::decode(&mut &batch[..]).map_err(|e| {
+                    common_rpc::to_rpc_error(
+                        common_rpc::Error::ProposalBatchDecodeFailed,
+                        Some(format!("{:?}", e)),
+                    )
+                })?;
+                pallet_deip_proposal::api::DryRunTarget::Batch(batch, mode)
+            }
+            types::DryRunTarget::Proposal(id) => pallet_deip_proposal::api::DryRunTarget::Proposal(id),
+        };
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.dry_run(&at, target);
+        runtime_api_result
+            .map(|x| x.map(|items| items.into_iter().map(Into::into).collect()))
+            .map_err(|e| {
+                common_rpc::to_rpc_error(
+                    common_rpc::Error::ProposalApiDryRunFailed,
+                    Some(format!("{:?}", e)),
+                )
+            })
+    }
+}
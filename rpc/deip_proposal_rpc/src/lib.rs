@@ -13,11 +13,13 @@ use frame_support::Blake2_128Concat;
 use common_rpc::{get_list_by_index, FutureResult, HashOf, ListResult, StorageMap};
 
 mod types;
+mod dry_run;
 
-pub use types::Call;
+pub use types::{Call, DryRunItem, DryRunTarget};
+pub use dry_run::{DeipProposalDryRunApi, DeipProposalDryRunApiObj, DeipProposalRuntimeApi};
 
 #[rpc]
-pub trait DeipProposalRpcApi<BlockHash, AccountId, Moment, CallT>
+pub trait DeipProposalRpcApi<BlockHash, AccountId, Moment, BlockNumber, CallT>
 where
     AccountId: Ord,
 {
@@ -44,6 +46,35 @@ where
         count: u32,
         start_id: Option<ProposalId>,
     ) -> FutureResult<Vec<ListResult<ProposalId, types::DeipProposal<AccountId, Moment, CallT>>>>;
+
+    #[rpc(name = "deipProposal_getArchived")]
+    fn get_archived(
+        &self,
+        at: Option<BlockHash>,
+        id: ProposalId,
+    ) -> FutureResult<Option<types::ArchivedProposal<AccountId, Moment, BlockNumber, CallT>>>;
+
+    #[rpc(name = "deipProposal_getArchivedListByMember")]
+    fn get_archived_list_by_member(
+        &self,
+        at: Option<BlockHash>,
+        member: AccountId,
+        count: u32,
+        start_id: Option<ProposalId>,
+    ) -> FutureResult<
+        Vec<ListResult<ProposalId, types::ArchivedProposal<AccountId, Moment, BlockNumber, CallT>>>,
+    >;
+
+    #[rpc(name = "deipProposal_getArchivedListByAuthor")]
+    fn get_archived_list_by_author(
+        &self,
+        at: Option<BlockHash>,
+        author: AccountId,
+        count: u32,
+        start_id: Option<ProposalId>,
+    ) -> FutureResult<
+        Vec<ListResult<ProposalId, types::ArchivedProposal<AccountId, Moment, BlockNumber, CallT>>>,
+    >;
 }
 
 pub struct DeipProposalRpcApiObj<State, Block> {
@@ -60,14 +91,15 @@ impl<State, Block> DeipProposalRpcApiObj<State, Block> {
     }
 }
 
-impl<State, Block, AccountId, Moment, Call>
-    DeipProposalRpcApi<HashOf<Block>, AccountId, Moment, Call>
+impl<State, Block, AccountId, Moment, BlockNumber, Call>
+    DeipProposalRpcApi<HashOf<Block>, AccountId, Moment, BlockNumber, Call>
     for DeipProposalRpcApiObj<State, Block>
 where
     Block: BlockT,
     State: sc_rpc_api::state::StateApi<HashOf<Block>>,
     AccountId: 'static + Codec + Send + Ord,
     Moment: 'static + Codec + Send,
+    BlockNumber: 'static + Codec + Send,
     Call: 'static + Codec + Send,
 {
     fn get(
@@ -120,4 +152,60 @@ where
             start_id.map(types::ProposalKeyValue::new),
         )
     }
+
+    fn get_archived(
+        &self,
+        at: Option<HashOf<Block>>,
+        id: ProposalId,
+    ) -> FutureResult<Option<types::ArchivedProposal<AccountId, Moment, BlockNumber, Call>>> {
+        StorageMap::<Blake2_128Concat>::get_value(
+            &self.state,
+            at,
+            b"DeipProposal",
+            b"ProposalArchive",
+            &id,
+        )
+    }
+
+    fn get_archived_list_by_member(
+        &self,
+        at: Option<HashOf<Block>>,
+        key: AccountId,
+        count: u32,
+        start_id: Option<ProposalId>,
+    ) -> FutureResult<
+        Vec<ListResult<ProposalId, types::ArchivedProposal<AccountId, Moment, BlockNumber, Call>>>,
+    > {
+        get_list_by_index::<Blake2_128Concat, Blake2_128Concat, _, _, _, _>(
+            &self.state,
+            at,
+            b"DeipProposal",
+            b"ArchivedProposalIdByMember",
+            b"ProposalArchive",
+            count,
+            &key,
+            start_id.map(types::ArchivedProposalKeyValue::new),
+        )
+    }
+
+    fn get_archived_list_by_author(
+        &self,
+        at: Option<HashOf<Block>>,
+        key: AccountId,
+        count: u32,
+        start_id: Option<ProposalId>,
+    ) -> FutureResult<
+        Vec<ListResult<ProposalId, types::ArchivedProposal<AccountId, Moment, BlockNumber, Call>>>,
+    > {
+        get_list_by_index::<Blake2_128Concat, Blake2_128Concat, _, _, _, _>(
+            &self.state,
+            at,
+            b"DeipProposal",
+            b"ArchivedProposalIdByAuthor",
+            b"ProposalArchive",
+            count,
+            &key,
+            start_id.map(types::ArchivedProposalKeyValue::new),
+        )
+    }
 }
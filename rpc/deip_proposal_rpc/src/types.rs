@@ -1,11 +1,19 @@
-use codec::Decode;
+use codec::{Decode, Encode};
 use common_rpc::{Error, GetError, KeyValueInfo};
+use frame_support::weights::{Pays, Weight};
 use frame_support::Parameter;
 use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
 use sp_runtime::traits::Member;
+use sp_runtime::DispatchError;
 use sp_std::collections::btree_map::BTreeMap;
 
-use pallet_deip_proposal::proposal::{BatchItem, ProposalMemberDecision, ProposalState};
+use pallet_deip_proposal::api;
+
+use pallet_deip_proposal::proposal::{
+    ApprovalPolicy, ArchivedDecision, BatchItem, BatchResults, ExecutionMode,
+    ProposalMemberDecision, ProposalState,
+};
 
 pub struct ProposalIdError;
 impl GetError for ProposalIdError {
@@ -36,6 +44,12 @@ pub struct DeipProposal<AccountId: Ord, Moment, CallT> {
     pub state: ProposalState,
     pub author: AccountId,
     pub created_at: Moment,
+    pub approval_policy: ApprovalPolicy<AccountId>,
+    pub execute_after: Option<Moment>,
+    pub ttl: Moment,
+    pub decided_at: BTreeMap<AccountId, Moment>,
+    pub execution_mode: ExecutionMode,
+    pub batch_results: BatchResults,
 }
 
 impl<AccountId: Ord, Moment, CallT> GetError for DeipProposal<AccountId, Moment, CallT> {
@@ -73,3 +87,95 @@ where
         &self.id
     }
 }
+
+/// copied from DeipProposal pallet since the original is generic over T: Config
+#[derive(Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedProposal<AccountId: Ord, Moment, BlockNumber, CallT> {
+    pub id: super::ProposalId,
+    pub batch: Vec<BatchItem<AccountId, CallT>>,
+    pub decisions: BTreeMap<AccountId, ArchivedDecision<Moment>>,
+    pub state: ProposalState,
+    pub author: AccountId,
+    pub created_at: Moment,
+    pub resolved_at: BlockNumber,
+    pub batch_results: BatchResults,
+}
+
+impl<AccountId: Ord, Moment, BlockNumber, CallT> GetError
+    for ArchivedProposal<AccountId, Moment, BlockNumber, CallT>
+{
+    fn get_error() -> Error {
+        Error::ArchivedProposalDecodeFailed
+    }
+}
+
+pub struct ArchivedProposalKeyValue<AccountId, Moment, BlockNumber, Call> {
+    pub id: super::ProposalId,
+    _m: std::marker::PhantomData<(AccountId, Moment, BlockNumber, Call)>,
+}
+
+impl<AccountId, Moment, BlockNumber, Call> ArchivedProposalKeyValue<AccountId, Moment, BlockNumber, Call> {
+    pub fn new(id: super::ProposalId) -> Self {
+        Self {
+            id,
+            _m: Default::default(),
+        }
+    }
+}
+
+impl<AccountId, Moment, BlockNumber, Call> KeyValueInfo
+    for ArchivedProposalKeyValue<AccountId, Moment, BlockNumber, Call>
+where
+    AccountId: 'static + Decode + Send + Ord,
+    Moment: 'static + Decode + Send,
+    BlockNumber: 'static + Decode + Send,
+    Call: 'static + Decode + Send,
+{
+    type Key = super::ProposalId;
+    type KeyError = ProposalIdError;
+    type Value = ArchivedProposal<AccountId, Moment, BlockNumber, Call>;
+    type ValueError = ArchivedProposal<AccountId, Moment, BlockNumber, Call>;
+
+    fn key(&self) -> &Self::Key {
+        &self.id
+    }
+}
+
+/// Target of `deipProposal_dryRun`: SCALE-encoded input batch with it's execution mode
+/// or an existing proposal
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DryRunTarget {
+    Batch(Bytes, ExecutionMode),
+    Proposal(super::ProposalId),
+}
+
+/// copied from DeipProposal pallet api since events and post dispatch info are not serializable
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunItem {
+    pub result: Result<(), DispatchError>,
+    pub actual_weight: Option<Weight>,
+    pub pays_fee: bool,
+    pub module_error: Option<api::DryRunModuleError>,
+    pub events: Vec<Bytes>,
+    pub weight: Weight,
+}
+
+impl<Event: Encode> From<api::DryRunItem<Event>> for DryRunItem {
+    fn from(item: api::DryRunItem<Event>) -> Self {
+        let (result, post_info) = match item.result {
+            Ok(post_info) => (Ok(()), post_info),
+            Err(e) => (Err(e.error), e.post_info),
+        };
+        Self {
+            result,
+            actual_weight: post_info.actual_weight,
+            pays_fee: post_info.pays_fee == Pays::Yes,
+            module_error: item.module_error,
+            events: item.events.iter().map(|x| x.encode().into()).collect(),
+            weight: item.weight,
+        }
+    }
+}
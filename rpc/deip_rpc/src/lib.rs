@@ -16,6 +16,9 @@ use frame_support::{Blake2_128Concat, Identity, Twox64Concat};
 
 mod types;
 
+#[cfg(test)]
+mod tests;
+
 #[rpc]
 pub trait DeipStorageApi<BlockHash, AccountId, Moment, AssetId, AssetBalance, Hash> {
     #[rpc(name = "deip_getProjectList")]
@@ -42,6 +45,24 @@ pub trait DeipStorageApi<BlockHash, AccountId, Moment, AssetId, AssetBalance, Ha
         start_id: Option<ProjectId>,
     ) -> FutureResult<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>>;
 
+    #[rpc(name = "deip_getProjectListByDomain")]
+    fn get_project_list_by_domain(
+        &self,
+        at: Option<BlockHash>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ProjectId>,
+    ) -> FutureResult<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>>;
+
+    #[rpc(name = "deip_getProjectListByDomainTree")]
+    fn get_project_list_by_domain_tree(
+        &self,
+        at: Option<BlockHash>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ProjectId>,
+    ) -> Result<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>>;
+
     #[rpc(name = "deip_getProjectContentList")]
     fn get_project_content_list(
         &self,
@@ -59,6 +80,15 @@ pub trait DeipStorageApi<BlockHash, AccountId, Moment, AssetId, AssetBalance, Ha
         start_id: Option<ProjectContentId>,
     ) -> FutureResult<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>>;
 
+    #[rpc(name = "deip_getProjectContentListByAuthor")]
+    fn get_project_content_list_by_author(
+        &self,
+        at: Option<BlockHash>,
+        author: AccountId,
+        count: u32,
+        start_id: Option<ProjectContentId>,
+    ) -> FutureResult<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>>;
+
     #[rpc(name = "deip_getProjectContent")]
     fn get_project_content(
         &self,
@@ -66,6 +96,13 @@ pub trait DeipStorageApi<BlockHash, AccountId, Moment, AssetId, AssetBalance, Ha
         id: ProjectContentId,
     ) -> Result<Option<ProjectContent<Hash, AccountId>>>;
 
+    #[rpc(name = "deip_getProjectContentRevisions")]
+    fn get_project_content_revisions(
+        &self,
+        at: Option<BlockHash>,
+        id: ProjectContentId,
+    ) -> Result<Vec<ProjectContent<Hash, AccountId>>>;
+
     #[rpc(name = "deip_getDomainList")]
     fn get_domains(
         &self,
@@ -74,6 +111,15 @@ pub trait DeipStorageApi<BlockHash, AccountId, Moment, AssetId, AssetBalance, Ha
         start_id: Option<DomainId>,
     ) -> FutureResult<Vec<ListResult<DomainId, Domain>>>;
 
+    #[rpc(name = "deip_getDomainListByParent")]
+    fn get_domain_list_by_parent(
+        &self,
+        at: Option<BlockHash>,
+        parent_id: DomainId,
+        count: u32,
+        start_id: Option<DomainId>,
+    ) -> FutureResult<Vec<ListResult<DomainId, Domain>>>;
+
     #[rpc(name = "deip_getDomain")]
     fn get_domain(&self, at: Option<BlockHash>, domain_id: DomainId) -> Result<Option<Domain>>;
 
@@ -123,6 +169,24 @@ pub trait DeipStorageApi<BlockHash, AccountId, Moment, AssetId, AssetBalance, Ha
         start_id: Option<ReviewId>,
     ) -> FutureResult<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>>;
 
+    #[rpc(name = "deip_getReviewListByDomain")]
+    fn get_review_list_by_domain(
+        &self,
+        at: Option<BlockHash>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ReviewId>,
+    ) -> FutureResult<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>>;
+
+    #[rpc(name = "deip_getReviewListByDomainTree")]
+    fn get_review_list_by_domain_tree(
+        &self,
+        at: Option<BlockHash>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ReviewId>,
+    ) -> Result<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>>;
+
     #[rpc(name = "deip_getReview")]
     fn get_review(
         &self,
@@ -290,6 +354,47 @@ where
         )
     }
 
+    fn get_project_list_by_domain(
+        &self,
+        at: Option<HashOf<Block>>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ProjectId>,
+    ) -> FutureResult<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>> {
+        get_list_by_index::<Blake2_128Concat, Identity, _, _, _, _>(
+            &self.state,
+            at,
+            b"Deip",
+            b"ProjectIdByDomainId",
+            b"ProjectMap",
+            count,
+            &domain_id,
+            start_id.map(types::ProjectKeyValue::new),
+        )
+    }
+
+    fn get_project_list_by_domain_tree(
+        &self,
+        at: Option<HashOf<Block>>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ProjectId>,
+    ) -> Result<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_project_list_by_domain_tree(&at, &domain_id, count, start_id);
+        runtime_api_result
+            .map(|list| {
+                list.into_iter()
+                    .map(|(id, value)| ListResult { key: id.into(), value })
+                    .collect()
+            })
+            .map_err(|e| {
+                to_rpc_error(Error::ProjectApiGetListByDomainTreeFailed, Some(format!("{:?}", e)))
+            })
+    }
+
     fn get_domains(
         &self,
         at: Option<HashOf<Block>>,
@@ -306,6 +411,25 @@ where
         )
     }
 
+    fn get_domain_list_by_parent(
+        &self,
+        at: Option<HashOf<Block>>,
+        key: DomainId,
+        count: u32,
+        start_id: Option<DomainId>,
+    ) -> FutureResult<Vec<ListResult<DomainId, Domain>>> {
+        get_list_by_index::<Blake2_128Concat, Blake2_128Concat, _, _, _, _>(
+            &self.state,
+            at,
+            b"Deip",
+            b"DomainIdByParentId",
+            b"Domains",
+            count,
+            &key,
+            start_id.map(types::DomainKeyValue::new),
+        )
+    }
+
     fn get_domain(
         &self,
         at: Option<<Block as BlockT>::Hash>,
@@ -354,6 +478,25 @@ where
         )
     }
 
+    fn get_project_content_list_by_author(
+        &self,
+        at: Option<HashOf<Block>>,
+        key: AccountId,
+        count: u32,
+        start_id: Option<ProjectContentId>,
+    ) -> FutureResult<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>> {
+        get_list_by_index::<Blake2_128Concat, Identity, _, _, _, _>(
+            &self.state,
+            at,
+            b"Deip",
+            b"ContentIdByAuthorId",
+            b"ProjectContentMap",
+            count,
+            &key,
+            start_id.map(types::ProjectContentKeyValue::new),
+        )
+    }
+
     fn get_project_content(
         &self,
         at: Option<HashOf<Block>>,
@@ -367,6 +510,20 @@ where
             .map_err(|e| to_rpc_error(Error::ProjectContentApiGetFailed, Some(format!("{:?}", e))))
     }
 
+    fn get_project_content_revisions(
+        &self,
+        at: Option<HashOf<Block>>,
+        id: ProjectContentId,
+    ) -> Result<Vec<ProjectContent<Hash, AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_project_content_revisions(&at, &id);
+        runtime_api_result.map_err(|e| {
+            to_rpc_error(Error::ProjectContentApiGetRevisionsFailed, Some(format!("{:?}", e)))
+        })
+    }
+
     fn get_nda_list(
         &self,
         at: Option<HashOf<Block>>,
@@ -468,6 +625,47 @@ where
         )
     }
 
+    fn get_review_list_by_domain(
+        &self,
+        at: Option<HashOf<Block>>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ReviewId>,
+    ) -> FutureResult<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>> {
+        get_list_by_index::<Blake2_128Concat, Identity, _, _, _, _>(
+            &self.state,
+            at,
+            b"Deip",
+            b"ReviewIdByDomainId",
+            b"ReviewMap",
+            count,
+            &domain_id,
+            start_id.map(types::ReviewKeyValue::new),
+        )
+    }
+
+    fn get_review_list_by_domain_tree(
+        &self,
+        at: Option<HashOf<Block>>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ReviewId>,
+    ) -> Result<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_review_list_by_domain_tree(&at, &domain_id, count, start_id);
+        runtime_api_result
+            .map(|list| {
+                list.into_iter()
+                    .map(|(id, value)| ListResult { key: id.into(), value })
+                    .collect()
+            })
+            .map_err(|e| {
+                to_rpc_error(Error::ReviewApiGetListByDomainTreeFailed, Some(format!("{:?}", e)))
+            })
+    }
+
     fn get_review(
         &self,
         at: Option<HashOf<Block>>,
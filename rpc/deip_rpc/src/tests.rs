@@ -0,0 +1,246 @@
+use super::*;
+
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use codec::Encode;
+use frame_support::{StorageHasher, Twox128};
+use jsonrpc_core::futures::{future, Future};
+use jsonrpc_pubsub::{typed::Subscriber, SubscriptionId};
+use sc_rpc_api::state::{error::FutureResult as StateResult, ReadProof, StateApi};
+use sp_blockchain::{BlockStatus, Info};
+use sp_core::{storage::{StorageChangeSet, StorageData, StorageKey}, Bytes, H256};
+use sp_runtime::{testing::{Block as TestBlock, ExtrinsicWrapper}, traits::NumberFor};
+use sp_version::RuntimeVersion;
+use pallet_deip::api::DeipApi;
+
+type Block = TestBlock<ExtrinsicWrapper<()>>;
+type TestReview = Review<H256, u64>;
+
+/// State of the best block kept in memory
+#[derive(Default)]
+struct MockState(BTreeMap<Vec<u8>, Vec<u8>>);
+
+impl MockState {
+    fn insert(&mut self, storage: &[u8], key: Vec<u8>, value: Vec<u8>) {
+        let mut full_key = Twox128::hash(b"Deip").to_vec();
+        full_key.extend_from_slice(&Twox128::hash(storage));
+        full_key.extend(key);
+        self.0.insert(full_key, value);
+    }
+
+    fn insert_review(&mut self, review_id: ReviewId, domains: Vec<DomainId>) {
+        // same layout as the fields of `Review`
+        let review = (review_id, 1u64, H256::repeat_byte(1), domains.clone(), 1u32, b"50.00 %".to_vec(), H256::zero());
+        self.insert(b"ReviewMap", Identity::hash(&review_id.encode()), review.encode());
+        for domain in domains {
+            let mut key = Blake2_128Concat::hash(&domain.encode());
+            key.extend(Identity::hash(&review_id.encode()));
+            self.insert(b"ReviewIdByDomainId", key, ().encode());
+        }
+    }
+}
+
+impl StateApi<H256> for MockState {
+    type Metadata = sc_rpc_api::Metadata;
+
+    fn call(&self, _: String, _: Bytes, _: Option<H256>) -> StateResult<Bytes> {
+        unimplemented!()
+    }
+
+    fn storage_keys(&self, _: StorageKey, _: Option<H256>) -> StateResult<Vec<StorageKey>> {
+        unimplemented!()
+    }
+
+    fn storage_pairs(&self, _: StorageKey, _: Option<H256>) -> StateResult<Vec<(StorageKey, StorageData)>> {
+        unimplemented!()
+    }
+
+    fn storage_keys_paged(
+        &self,
+        prefix: Option<StorageKey>,
+        count: u32,
+        start_key: Option<StorageKey>,
+        _: Option<H256>,
+    ) -> StateResult<Vec<StorageKey>> {
+        let prefix = prefix.map(|x| x.0).unwrap_or_default();
+        let start = match start_key {
+            Some(key) => Bound::Excluded(key.0),
+            None => Bound::Included(prefix.clone()),
+        };
+        let keys = self.0.range((start, Bound::Unbounded))
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(&prefix))
+            .take(count as usize)
+            .map(|key| StorageKey(key.clone()))
+            .collect();
+        Box::new(future::ok(keys))
+    }
+
+    fn storage(&self, key: StorageKey, _: Option<H256>) -> StateResult<Option<StorageData>> {
+        Box::new(future::ok(self.0.get(&key.0).cloned().map(StorageData)))
+    }
+
+    fn storage_hash(&self, _: StorageKey, _: Option<H256>) -> StateResult<Option<H256>> {
+        unimplemented!()
+    }
+
+    fn storage_size(&self, _: StorageKey, _: Option<H256>) -> StateResult<Option<u64>> {
+        unimplemented!()
+    }
+
+    fn metadata(&self, _: Option<H256>) -> StateResult<Bytes> {
+        unimplemented!()
+    }
+
+    fn runtime_version(&self, _: Option<H256>) -> StateResult<RuntimeVersion> {
+        unimplemented!()
+    }
+
+    fn query_storage(&self, _: Vec<StorageKey>, _: H256, _: Option<H256>) -> StateResult<Vec<StorageChangeSet<H256>>> {
+        unimplemented!()
+    }
+
+    fn query_storage_at(&self, _: Vec<StorageKey>, _: Option<H256>) -> StateResult<Vec<StorageChangeSet<H256>>> {
+        unimplemented!()
+    }
+
+    fn read_proof(&self, _: Vec<StorageKey>, _: Option<H256>) -> StateResult<ReadProof<H256>> {
+        unimplemented!()
+    }
+
+    fn subscribe_runtime_version(&self, _: Self::Metadata, _: Subscriber<RuntimeVersion>) {
+        unimplemented!()
+    }
+
+    fn unsubscribe_runtime_version(&self, _: Option<Self::Metadata>, _: SubscriptionId) -> Result<bool> {
+        unimplemented!()
+    }
+
+    fn subscribe_storage(
+        &self,
+        _: Self::Metadata,
+        _: Subscriber<StorageChangeSet<H256>>,
+        _: Option<Vec<StorageKey>>,
+    ) {
+        unimplemented!()
+    }
+
+    fn unsubscribe_storage(&self, _: Option<Self::Metadata>, _: SubscriptionId) -> Result<bool> {
+        unimplemented!()
+    }
+}
+
+/// Storage lists are read from the state, runtime api isn't called
+#[derive(Clone)]
+struct MockApi;
+
+sp_api::mock_impl_runtime_apis! {
+    impl DeipApi<Block, u64, u64, u32, u64, H256> for MockApi {
+        fn get_project(_: &ProjectId) -> Option<Project<H256, u64>> {
+            unimplemented!()
+        }
+
+        fn get_domain(_: &DomainId) -> Option<Domain> {
+            unimplemented!()
+        }
+
+        fn get_project_list_by_domain_tree(_: &DomainId, _: u32, _: Option<ProjectId>) -> Vec<(ProjectId, Project<H256, u64>)> {
+            unimplemented!()
+        }
+
+        fn get_review_list_by_domain_tree(_: &DomainId, _: u32, _: Option<ReviewId>) -> Vec<(ReviewId, Review<H256, u64>)> {
+            unimplemented!()
+        }
+
+        fn get_project_content(_: &ProjectContentId) -> Option<ProjectContent<H256, u64>> {
+            unimplemented!()
+        }
+
+        fn get_project_content_revisions(_: &ProjectContentId) -> Vec<ProjectContent<H256, u64>> {
+            unimplemented!()
+        }
+
+        fn get_nda(_: &NdaId) -> Option<Nda<H256, u64, u64>> {
+            unimplemented!()
+        }
+
+        fn get_review(_: &ReviewId) -> Option<Review<H256, u64>> {
+            unimplemented!()
+        }
+
+        fn get_investment_opportunity(_: &InvestmentId) -> Option<SimpleCrowdfunding<u64, u32, u64>> {
+            unimplemented!()
+        }
+
+        fn get_contract_agreement(_: &ContractAgreementId) -> Option<contract::Agreement<u64, H256, u64, DeipAsset<u32, u64>>> {
+            unimplemented!()
+        }
+    }
+}
+
+struct MockClient;
+
+impl ProvideRuntimeApi<Block> for MockClient {
+    type Api = MockApi;
+
+    fn runtime_api(&self) -> sp_api::ApiRef<'_, Self::Api> {
+        MockApi.into()
+    }
+}
+
+impl HeaderBackend<Block> for MockClient {
+    fn header(&self, _: BlockId<Block>) -> sp_blockchain::Result<Option<<Block as BlockT>::Header>> {
+        unimplemented!()
+    }
+
+    fn info(&self) -> Info<Block> {
+        unimplemented!()
+    }
+
+    fn status(&self, _: BlockId<Block>) -> sp_blockchain::Result<BlockStatus> {
+        unimplemented!()
+    }
+
+    fn number(&self, _: H256) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+        unimplemented!()
+    }
+
+    fn hash(&self, _: NumberFor<Block>) -> sp_blockchain::Result<Option<H256>> {
+        unimplemented!()
+    }
+}
+
+fn review_list_by_domain(
+    state: MockState,
+    domain_id: DomainId,
+    count: u32,
+    start_id: Option<ReviewId>,
+) -> Vec<ReviewId> {
+    let rpc = DeipStorage::<_, _, Block>::new(Arc::new(MockClient), state);
+    let list = DeipStorageApi::<H256, u64, u64, u32, u64, H256>::get_review_list_by_domain(
+        &rpc, None, domain_id, count, start_id,
+    ).wait().unwrap();
+    list.into_iter().map(|x: ListResult<ReviewId, TestReview>| x.key.key).collect()
+}
+
+#[test]
+fn review_list_by_domain_follows_index() {
+    let domain = DomainId::repeat_byte(1);
+    let other_domain = DomainId::repeat_byte(2);
+    let reviews: Vec<ReviewId> = (1..=3).map(ReviewId::repeat_byte).collect();
+
+    let state = || {
+        let mut state = MockState::default();
+        state.insert_review(reviews[0], vec![domain]);
+        state.insert_review(reviews[1], vec![other_domain]);
+        state.insert_review(reviews[2], vec![other_domain, domain]);
+        state
+    };
+
+    assert_eq!(review_list_by_domain(state(), domain, 10, None), vec![reviews[0], reviews[2]]);
+    assert_eq!(review_list_by_domain(state(), other_domain, 10, None), vec![reviews[1], reviews[2]]);
+    assert_eq!(review_list_by_domain(state(), domain, 1, None), vec![reviews[0]]);
+    assert_eq!(review_list_by_domain(state(), domain, 10, Some(reviews[0])), vec![reviews[2]]);
+    assert!(review_list_by_domain(state(), DomainId::repeat_byte(3), 10, None).is_empty());
+}
@@ -1,10 +1,12 @@
 use sp_std::prelude::*;
+use sp_std::convert::TryFrom;
 
 use frame_support::pallet_prelude::*;
 
 use sp_core::crypto::AccountId32;
 
 use pallet_deip_dao::dao::DaoId;
+use pallet_deip_proposal::proposal::{DaoAuthority, DaoAuthorityT};
 
 #[cfg(feature = "std")]
 use serde::{Serialize, Deserialize};
@@ -17,13 +19,42 @@ pub enum DeipAccountId<Native> {
     Dao(DaoId),
 }
 
-impl Into<AccountId32> for DeipAccountId<AccountId32> {
-    fn into(self) -> AccountId32 {
-        match self {
-            Self::Native(native) => { native },
-            Self::Dao(name) => {
-                pallet_deip_dao::dao_key::<AccountId32>(&name)
+/// DAO ID converts to the DAO key unless the DAO is dissolved
+impl TryFrom<DeipAccountId<AccountId32>> for AccountId32 {
+    type Error = DeipAccountIdError;
+
+    fn try_from(source: DeipAccountId<AccountId32>) -> Result<Self, Self::Error> {
+        match source {
+            DeipAccountId::Native(native) => { Ok(native) },
+            DeipAccountId::Dao(name) => {
+                if crate::DeipDao::is_dissolved(&name) {
+                    return Err(DeipAccountIdError::DaoDissolved)
+                }
+                Ok(pallet_deip_dao::dao_key::<AccountId32>(&name))
             },
         }
     }
 }
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DeipAccountIdError {
+    DaoDissolved,
+}
+
+/// Authorities of DAOs that make decisions on proposals.
+/// DAO with voting governance has no authority to decide alone
+pub struct DeipDaoAuthorities;
+
+impl DaoAuthorityT<AccountId32> for DeipDaoAuthorities {
+    fn authority(dao_key: &AccountId32) -> Option<DaoAuthority<AccountId32>> {
+        let dao = crate::DeipDao::lookup_dao(dao_key).and_then(crate::DeipDao::get_dao)?;
+        if crate::DeipDao::get_governance(dao.id()).is_some() {
+            return None
+        }
+        let authority = dao.authority();
+        Some(DaoAuthority {
+            signatories: authority.signatories().to_vec(),
+            threshold: authority.threshold(),
+        })
+    }
+}
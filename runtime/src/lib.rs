@@ -346,16 +346,31 @@ impl pallet_deip::traits::DeipAssetSystem<AccountId> for Runtime {
     }
 }
 
+parameter_types! {
+    pub const ContentAuthorsConfirmationPeriod: u64 = 14 * DAYS as u64 * MILLISECS_PER_BLOCK;
+}
+
 impl pallet_deip::Config for Runtime {
     type Event = Event;
     type DeipAccountId = deip_account::DeipAccountId<Self::AccountId>;
     type Currency = Balances;
     type AssetSystem = Self;
+    type DomainOrigin = frame_system::EnsureRoot<AccountId>;
+    type ContentAuthorsConfirmationPeriod = ContentAuthorsConfirmationPeriod;
 }
 
 parameter_types! {
     pub const ProposalTtl: u64 = 7 * DAYS as u64 * MILLISECS_PER_BLOCK;
-    pub const ProposalExpirePeriod: BlockNumber = HOURS;
+    pub const ProposalMaxTtl: u64 = 30 * DAYS as u64 * MILLISECS_PER_BLOCK;
+    pub const ProposalExpirySlot: u64 = HOURS as u64 * MILLISECS_PER_BLOCK;
+    pub const ProposalMaxExpiredPerBlock: u32 = 10;
+    pub const ProposalMaxArchived: u32 = 100_000;
+    pub const ProposalMaxScheduledPerBlock: u32 = 10;
+    pub ProposalMaxScheduledWeight: Weight = Perbill::from_percent(25) * BlockWeights::get().max_block;
+    pub const ProposalMaxNestingDepth: u32 = 2;
+    pub const ProposalMaxBatchItems: u32 = 50;
+    pub const ProposalMaxTotalBatchItems: u32 = 200;
+    pub const ProposalMaxBatchEncodedSize: u32 = 64 * 1024;
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
@@ -370,12 +385,117 @@ impl pallet_deip_proposal::pallet::Config for Runtime {
     type Call = Call;
     type DeipAccountId = deip_account::DeipAccountId<Self::AccountId>;
     type Ttl = ProposalTtl;
-    type ExpirePeriod = ProposalExpirePeriod;
+    type MaxTtl = ProposalMaxTtl;
+    type ExpirySlot = ProposalExpirySlot;
+    type MaxExpiredPerBlock = ProposalMaxExpiredPerBlock;
+    type DaoAuthorities = deip_account::DeipDaoAuthorities;
+    type MaxArchivedProposals = ProposalMaxArchived;
+    type MaxScheduledPerBlock = ProposalMaxScheduledPerBlock;
+    type MaxScheduledWeight = ProposalMaxScheduledWeight;
+    type MaxNestingDepth = ProposalMaxNestingDepth;
+    type MaxBatchItems = ProposalMaxBatchItems;
+    type MaxTotalBatchItems = ProposalMaxTotalBatchItems;
+    type MaxBatchEncodedSize = ProposalMaxBatchEncodedSize;
+}
+
+parameter_types! {
+    pub const DaoMaxNestingDepth: u32 = 3;
+    pub const DaoHandleRenameCooldown: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_deip_dao::Config for Runtime {
     type Event = Event;
     type Call = Call;
+    type CallPredicates = ();
+    type AssetId = AssetId;
+    type Balance = Balance;
+    type SpendingCalls = DaoSpendingCalls;
+    type TokenBalances = DaoTokenBalances;
+    type SweepAssets = DaoSweepAssets;
+    type DeipAccountId = deip_account::DeipAccountId<Self::AccountId>;
+    type MaxNestingDepth = DaoMaxNestingDepth;
+    type HandleRenameCooldown = DaoHandleRenameCooldown;
+}
+
+/// Transfers whole native and DEIP assets balances of dissolved DAOs
+pub struct DaoSweepAssets;
+
+impl pallet_deip_dao::budget::SweepAssets<AccountId, AssetId> for DaoSweepAssets {
+    fn sweep(
+        asset: &pallet_deip_dao::budget::BudgetAsset<AssetId>,
+        from: &AccountId,
+        to: &AccountId,
+    ) -> frame_support::dispatch::DispatchResult {
+        use pallet_deip_dao::budget::BudgetAsset;
+        use frame_support::traits::{Currency, ExistenceRequirement};
+        use sp_runtime::traits::{Dispatchable, Zero};
+
+        match asset {
+            BudgetAsset::Native => {
+                let amount = Balances::free_balance(from);
+                if amount.is_zero() { return Ok(()) }
+                <Balances as Currency<AccountId>>::transfer(from, to, amount, ExistenceRequirement::AllowDeath)
+            },
+            BudgetAsset::Asset(id) => {
+                let amount = DeipAssets::account_balance(from, id);
+                if amount.is_zero() { return Ok(()) }
+                let target = deip_account::DeipAccountId::Native(to.clone());
+                Call::DeipAssets(pallet_deip_assets::Call::transfer(*id, target, amount))
+                    .dispatch(Origin::signed(from.clone()))
+                    .map(|_| ())
+                    .map_err(|e| e.error)
+            },
+        }
+    }
+
+    fn holdings(who: &AccountId, max_assets: u32) -> Option<Vec<pallet_deip_dao::budget::BudgetAsset<AssetId>>> {
+        use pallet_deip_dao::budget::BudgetAsset;
+        use sp_runtime::traits::Zero;
+
+        let native = Some(BudgetAsset::Native).filter(|_| !Balances::free_balance(who).is_zero());
+        let assets = DeipAssets::account_assets(who, max_assets)?;
+        Some(native.into_iter().chain(assets.into_iter().map(BudgetAsset::Asset)).collect())
+    }
+}
+
+/// Balances of DEIP assets used for weighted voting in DAOs
+pub struct DaoTokenBalances;
+
+impl pallet_deip_dao::governance::TokenBalances<AccountId, AssetId, Balance> for DaoTokenBalances {
+    fn balance(asset: &AssetId, who: &AccountId) -> Balance {
+        DeipAssets::account_balance(who, asset).into()
+    }
+
+    fn total_supply(asset: &AssetId) -> Balance {
+        DeipAssets::total_supply(asset).into()
+    }
+}
+
+/// Extracts native currency and DEIP assets transfers from calls dispatched on behalf of DAO
+pub struct DaoSpendingCalls;
+
+impl pallet_deip_dao::budget::SpendingCalls<Call, AssetId, Balance> for DaoSpendingCalls {
+    fn spendings(call: &Call) -> Option<Vec<pallet_deip_dao::budget::Spending<AssetId, Balance>>> {
+        use pallet_deip_dao::budget::{Spending, BudgetAsset};
+
+        match call {
+            Call::Balances(pallet_balances::Call::transfer(_, value))
+            | Call::Balances(pallet_balances::Call::transfer_keep_alive(_, value)) => {
+                Some(vec![Spending { asset: BudgetAsset::Native, amount: *value }])
+            },
+            Call::DeipAssets(pallet_deip_assets::Call::transfer(id, _, amount)) => {
+                Some(vec![Spending { asset: BudgetAsset::Asset(*id), amount: (*amount).into() }])
+            },
+            Call::Utility(pallet_utility::Call::batch(calls))
+            | Call::Utility(pallet_utility::Call::batch_all(calls)) => {
+                calls.iter()
+                    .map(Self::spendings)
+                    .collect::<Option<Vec<_>>>()
+                    .map(|x| x.concat())
+            },
+            _ => None,
+        }
+    }
 }
 
 impl pallet_utility::Config for Runtime {
@@ -463,7 +583,7 @@ construct_runtime!(
         // Include the custom logic from the template pallet in the runtime.
         TemplateModule: pallet_template::{Module, Call, Storage, Event<T>},
         Deip: pallet_deip::{Module, Call, Storage, Event<T>, Config, ValidateUnsigned},
-        DeipProposal: pallet_deip_proposal::{Module, Call, Storage, Event<T>, Config, ValidateUnsigned},
+        DeipProposal: pallet_deip_proposal::{Module, Call, Storage, Event<T>, Config},
         DeipDao: pallet_deip_dao::{Module, Call, Storage, Event<T>, Config},
         Multisig: pallet_multisig::{Module, Call, Storage, Event<T>},
         Assets: pallet_assets::{Module, Storage, Event<T>},
@@ -567,13 +687,55 @@ impl_runtime_apis! {
         }
     }
     
-    impl pallet_deip_dao::api::DeipDaoRuntimeApi<Block, AccountId> for Runtime {
+    impl pallet_deip_dao::api::DeipDaoRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber, Moment> for Runtime {
         fn get(name: pallet_deip_dao::dao::DaoId) -> pallet_deip_dao::api::GetResult<AccountId> {
             DeipDao::rpc_get(name)
         }
         fn get_multi(names: Vec<pallet_deip_dao::dao::DaoId>) -> pallet_deip_dao::api::GetMultiResult<AccountId> {
             DeipDao::rpc_get_multi(names)
         }
+        fn get_list_by_member(member: AccountId) -> pallet_deip_dao::api::GetListByMemberResult<AccountId> {
+            DeipDao::rpc_get_list_by_member(member)
+        }
+        fn get_invitation_list_by_dao(
+            name: pallet_deip_dao::dao::DaoId,
+        ) -> pallet_deip_dao::api::GetInvitationListByDaoResult<AccountId, BlockNumber> {
+            DeipDao::rpc_get_invitation_list_by_dao(name)
+        }
+        fn get_invitation_list_by_member(
+            member: AccountId,
+        ) -> pallet_deip_dao::api::GetInvitationListByMemberResult<BlockNumber> {
+            DeipDao::rpc_get_invitation_list_by_member(member)
+        }
+        fn get_budget(
+            name: pallet_deip_dao::dao::DaoId,
+            budget_id: pallet_deip_dao::budget::BudgetId,
+        ) -> pallet_deip_dao::api::GetBudgetResult<AccountId, AssetId, Balance, BlockNumber> {
+            DeipDao::rpc_get_budget(name, budget_id)
+        }
+        fn get_budget_list(
+            name: pallet_deip_dao::dao::DaoId,
+        ) -> pallet_deip_dao::api::GetBudgetListResult<AccountId, AssetId, Balance, BlockNumber> {
+            DeipDao::rpc_get_budget_list(name)
+        }
+        fn get_metadata_history(
+            name: pallet_deip_dao::dao::DaoId,
+        ) -> pallet_deip_dao::api::GetMetadataHistoryResult<AccountId, BlockNumber, Moment> {
+            DeipDao::rpc_get_metadata_history(name)
+        }
+        fn get_id_by_handle(
+            handle: pallet_deip_dao::handle::DaoHandle,
+        ) -> Option<pallet_deip_dao::dao::DaoId> {
+            DeipDao::rpc_get_id_by_handle(handle)
+        }
+    }
+
+    impl pallet_deip_proposal::api::DeipProposalRuntimeApi<Block, deip_account::DeipAccountId<AccountId>, Call, Event> for Runtime {
+        fn dry_run(
+            target: pallet_deip_proposal::api::DryRunTarget<deip_account::DeipAccountId<AccountId>, Call>
+        ) -> pallet_deip_proposal::api::DryRunResult<Event> {
+            DeipProposal::rpc_dry_run(target)
+        }
     }
 
     impl pallet_deip::api::DeipApi<Block, AccountId, Moment, AssetId, AssetBalance, Hash> for Runtime {
@@ -585,10 +747,30 @@ impl_runtime_apis! {
             Deip::get_project_content(id)
         }
 
+        fn get_project_content_revisions(id: &ProjectContentId) -> Vec<ProjectContentOf<crate::Runtime>> {
+            Deip::get_project_content_revisions(id)
+        }
+
         fn get_domain(domain_id: &DomainId) -> Option<Domain> {
             Deip::get_domain(domain_id)
         }
 
+        fn get_project_list_by_domain_tree(
+            domain_id: &DomainId,
+            count: u32,
+            start_id: Option<ProjectId>,
+        ) -> Vec<(ProjectId, ProjectOf<crate::Runtime>)> {
+            Deip::get_project_list_by_domain_tree(domain_id, count, start_id)
+        }
+
+        fn get_review_list_by_domain_tree(
+            domain_id: &DomainId,
+            count: u32,
+            start_id: Option<ReviewId>,
+        ) -> Vec<(ReviewId, ReviewOf<crate::Runtime>)> {
+            Deip::get_review_list_by_domain_tree(domain_id, count, start_id)
+        }
+
         fn get_nda(nda_id: &NdaId) -> Option<NdaOf<crate::Runtime>> {
             Deip::get_nda(nda_id)
         }